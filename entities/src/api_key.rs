@@ -1,6 +1,9 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use utoipa::ToSchema;
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct Username(String);
@@ -10,7 +13,6 @@ impl Username {
         Self(name.into())
     }
 
-    #[allow(dead_code)]
     pub fn inner(&self) -> String {
         self.0.clone()
     }
@@ -31,7 +33,7 @@ impl Debug for ApiKey {
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Default)]
 pub struct ApiKeys(HashMap<ApiKey, Username>);
 
 impl ApiKeys {
@@ -50,3 +52,121 @@ impl From<HashMap<ApiKey, Username>> for ApiKeys {
         Self(value)
     }
 }
+
+/// Secrets used to sign webhooks sent to the users of the API keys from the config, one per user
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Default)]
+pub struct WebhookSecrets(HashMap<Username, String>);
+
+impl WebhookSecrets {
+    pub fn get_secret(&self, username: &Username) -> Option<&str> {
+        self.0.get(username).map(String::as_str)
+    }
+}
+
+impl Debug for WebhookSecrets {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.keys().map(|user| (user, "********")))
+            .finish()
+    }
+}
+
+impl From<HashMap<Username, String>> for WebhookSecrets {
+    fn from(value: HashMap<Username, String>) -> Self {
+        Self(value)
+    }
+}
+
+/// Operation an API key is allowed to perform
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash, Debug, ToSchema)]
+pub enum ApiKeyScope {
+    #[serde(rename = "assets:create")]
+    AssetsCreate,
+    #[serde(rename = "assets:update")]
+    AssetsUpdate,
+    #[serde(rename = "assets:mint")]
+    AssetsMint,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl ApiKeyScope {
+    pub const ALL: [ApiKeyScope; 4] = [
+        ApiKeyScope::AssetsCreate,
+        ApiKeyScope::AssetsUpdate,
+        ApiKeyScope::AssetsMint,
+        ApiKeyScope::Admin,
+    ];
+
+    /// Scopes of the non-admin keys from the config
+    pub const ASSETS: [ApiKeyScope; 3] = [
+        ApiKeyScope::AssetsCreate,
+        ApiKeyScope::AssetsUpdate,
+        ApiKeyScope::AssetsMint,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::AssetsCreate => "assets:create",
+            ApiKeyScope::AssetsUpdate => "assets:update",
+            ApiKeyScope::AssetsMint => "assets:mint",
+            ApiKeyScope::Admin => "admin",
+        }
+    }
+}
+
+impl Display for ApiKeyScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ApiKeyScope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| format!("Unknown API key scope '{s}'"))
+    }
+}
+
+/// API key issued via the admin endpoints, only the hash of the key itself is stored
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiKeyInfo {
+    pub id: i64,
+    pub username: Username,
+    pub label: String,
+    pub scopes: Vec<ApiKeyScope>,
+    /// Restricted key is only allowed to modify the assets whose authority it created
+    pub restricted: bool,
+    pub create_timestamp: NaiveDateTime,
+    pub expiry_timestamp: Option<NaiveDateTime>,
+    pub revocation_timestamp: Option<NaiveDateTime>,
+}
+
+impl ApiKeyInfo {
+    /// The key is accepted until it is revoked or expired
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        self.revocation_timestamp.is_none() && self.expiry_timestamp.is_none_or(|expiry| expiry > now)
+    }
+}
+
+/// User the provided API key belongs to, along with what the key is allowed to do
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApiKeyOwner {
+    pub username: Username,
+    /// Keys from the config have all the scopes
+    pub scopes: Vec<ApiKeyScope>,
+    /// ID of the stored key, if the key is restricted to the assets whose authority it created
+    pub restricted_key_id: Option<i64>,
+    /// ID of the stored key, `None` for the keys from the config
+    pub stored_key_id: Option<i64>,
+}
+
+impl ApiKeyOwner {
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
@@ -1,12 +1,13 @@
 use std::{cmp::Ordering, collections::BTreeMap, path::Path};
 
-use crate::l2::L2Asset;
+use crate::l2::{L2Asset, L2Collection};
 use jsonpath_lib::JsonPathError;
 use mime_guess::Mime;
 use schemars::JsonSchema;
 use serde_json::{json, Value};
 use tracing::warn;
 use url::Url;
+use utoipa::ToSchema;
 
 use {
     serde::{Deserialize, Serialize},
@@ -19,15 +20,54 @@ pub struct AssetExtended {
     pub asset: L2Asset,
     pub metadata_uri: String,
     pub royalty_basis_points: u16,
+    pub collection: Option<CollectionExtended>,
 }
 
 impl AssetExtended {
     pub fn new(asset: L2Asset, metadata_uri: String) -> Self {
-        Self { royalty_basis_points: asset.royalty_basis_points, asset, metadata_uri }
+        Self {
+            royalty_basis_points: asset.royalty_basis_points,
+            asset,
+            metadata_uri,
+            collection: None,
+        }
+    }
+
+    pub fn with_collection(mut self, collection: Option<CollectionExtended>) -> Self {
+        self.collection = collection;
+        self
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Eq)]
+/// L2 collection the asset belongs to, together with the collection NFT metadata JSON
+pub struct CollectionExtended {
+    pub collection: L2Collection,
+    pub metadata: Value,
+}
+
+impl CollectionExtended {
+    /// Asset is a verified member of the collection only if it has the same authority as the collection,
+    /// joining a collection requires the API key to own the collection authority
+    pub fn is_verified_member(&self, asset: &L2Asset) -> bool {
+        asset.collection == Some(self.collection.pubkey) && self.collection.authority == asset.authority
+    }
+
+    fn collection_metadata(&self) -> MetadataMap {
+        let mut meta = MetadataMap::new();
+
+        meta.set_item("name", Value::String(self.collection.name.clone()));
+        meta.set_item("symbol", Value::String("".to_string())); // for core collections symbol is empty
+        for field in ["image", "description", "external_url"] {
+            if let Some(value) = self.metadata.get(field) {
+                meta.set_item(field, value.clone());
+            }
+        }
+
+        meta
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, ToSchema, Eq)]
 pub enum AssetMintStatus {
     #[serde(rename = "l2")]
     L2,
@@ -36,6 +76,8 @@ pub enum AssetMintStatus {
     #[serde(rename = "l1_solana")]
     #[allow(non_camel_case_types)]
     L1_SOLANA,
+    #[serde(rename = "burnt")]
+    Burnt,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -286,8 +328,9 @@ pub struct Asset {
     pub metadata_owner: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rent_epoch: Option<u64>,
+    /// Kept as JSON, since the upstream DAS API serves plugins of minted assets as a map keyed by plugin name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plugins: Option<PluginSchemaV1>,
+    pub plugins: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unknown_plugins: Option<Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -454,7 +497,7 @@ impl From<(AssetExtended, Value)> for Asset {
         let l2_asset = asset.asset;
         let mut meta: MetadataMap = MetadataMap::new();
 
-        meta.set_item("name", Value::String(l2_asset.name));
+        meta.set_item("name", Value::String(l2_asset.name.clone()));
         meta.set_item("symbol", Value::String("".to_string())); // for core assets symbol is empty
 
         let mut selector_fn = jsonpath_lib::selector(&metadata);
@@ -489,11 +532,12 @@ impl From<(AssetExtended, Value)> for Asset {
             authorities: Some(vec![Authority { address: l2_asset.authority.clone(), scopes: vec![Scope::Full] }]),
             compression: Some(Compression::default()),
             grouping: l2_asset.collection.map(|c| {
+                let collection = asset.collection.as_ref().filter(|ex| ex.collection.pubkey == c);
                 vec![Group {
                     group_key: COLLECTION_GROUP_KEY.to_string(),
                     group_value: Some(crate::l2::pubkey_to_string(c)),
-                    verified: Some(true),      // todo: is it?
-                    collection_metadata: None, //todo: figure out the collection flow with Spell
+                    verified: Some(collection.is_some_and(|collection| collection.is_verified_member(&l2_asset))),
+                    collection_metadata: collection.map(CollectionExtended::collection_metadata),
                 }]
             }),
             royalty: Some(Royalty {
@@ -515,17 +559,17 @@ impl From<(AssetExtended, Value)> for Asset {
             uses: None, // for core assets
             supply: None,
             mutable: true,
-            burnt: false,
+            burnt: l2_asset.burnt,
             lamports: None,
             executable: None,
             metadata_owner: None,
             rent_epoch: None,
-            plugins: Some(PluginSchemaV1 {
+            plugins: Some(json!(PluginSchemaV1 {
                 index: 0,
                 offset: 0, //todo - this is purely onchain, change to constant, when we mint some of these assets
                 authority: PluginAuthority::UpdateAuthority,
                 data: Royalties { basis_points: asset.royalty_basis_points, creators, rule_set: RuleSet::None },
-            }),
+            })),
             unknown_plugins: None,
             mpl_core_info: None,
             external_plugins: None,
@@ -589,9 +633,22 @@ mod test {
                 update_timestamp: NaiveDateTime::parse_from_str("2015-02-18 23:16:09", "%Y-%m-%d %H:%M:%S").unwrap(),
                 bip44_account_num: 1,
                 bip44_address_num: 1,
+                burnt: false,
             },
             metadata_uri: "http://link/to/metadata.json".to_string(),
             royalty_basis_points: 0,
+            collection: Some(CollectionExtended {
+                collection: L2Collection {
+                    pubkey: collection,
+                    name: "collection1".to_string(),
+                    authority: authority.clone(),
+                    create_timestamp: NaiveDateTime::default(),
+                    update_timestamp: NaiveDateTime::default(),
+                    bip44_account_num: 1,
+                    bip44_address_num: 2,
+                },
+                metadata: json!({ "name": "collection1", "image": "http://host/collection.png" }),
+            }),
         };
 
         let json_value: Value = serde_json::from_str(&json).unwrap();
@@ -616,6 +673,10 @@ mod test {
             dto.grouping.as_ref().unwrap()[0].clone().group_value.unwrap(),
             bs58::encode(collection).into_string()
         );
+        assert_eq!(dto.grouping.as_ref().unwrap()[0].verified, Some(true));
+        let collection_metadata = dto.grouping.as_ref().unwrap()[0].collection_metadata.clone().unwrap();
+        assert_eq!(collection_metadata.get_item("name").unwrap(), "collection1");
+        assert_eq!(collection_metadata.get_item("image").unwrap(), "http://host/collection.png");
         assert_eq!(dto.content.as_ref().unwrap().metadata.get_item("description").unwrap(), "test description");
         assert_eq!(
             dto.content
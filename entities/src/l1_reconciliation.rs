@@ -0,0 +1,156 @@
+use crate::dto::AssetMintStatus;
+use crate::l2::{L2Asset, PublicKey};
+use serde::{Deserialize, Serialize};
+
+/// mpl-core asset account, as it is stored on L1
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L1AssetAccount {
+    pub pubkey: PublicKey,
+    pub owner: PublicKey,
+    pub name: String,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum L1DriftKind {
+    /// Asset is marked as minted, but its account doesn't exist on L1
+    MissingOnChain,
+    OwnerMismatch,
+    NameMismatch,
+    UriMismatch,
+    /// Asset account exists on L1, but the asset is still marked as L2
+    MintedButL2,
+}
+
+/// Discrepancy between an asset stored in DB and its mpl-core account on L1
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L1Drift {
+    pub asset_pubkey: PublicKey,
+    pub kind: L1DriftKind,
+
+    /// Value stored in DB, if the drift is about a field
+    pub db_value: Option<String>,
+
+    /// Value stored on L1, if the drift is about a field
+    pub chain_value: Option<String>,
+}
+
+impl L1Drift {
+    fn new(asset_pubkey: PublicKey, kind: L1DriftKind) -> Self {
+        Self { asset_pubkey, kind, db_value: None, chain_value: None }
+    }
+
+    fn mismatch(asset_pubkey: PublicKey, kind: L1DriftKind, db_value: &str, chain_value: &str) -> Self {
+        Self {
+            asset_pubkey,
+            kind,
+            db_value: Some(db_value.to_string()),
+            chain_value: Some(chain_value.to_string()),
+        }
+    }
+}
+
+/// Compares the asset stored in DB with its mpl-core account on L1.
+///
+/// ## Args:
+/// * `asset` - asset stored in DB
+/// * `status` - mint status of the asset
+/// * `expected_uri` - metadata URI the asset is expected to have on L1
+/// * `account` - asset account fetched from L1, `None` if it doesn't exist
+pub fn detect_l1_drifts(
+    asset: &L2Asset,
+    status: &AssetMintStatus,
+    expected_uri: &str,
+    account: Option<&L1AssetAccount>,
+) -> Vec<L1Drift> {
+    match (status, account) {
+        (AssetMintStatus::Burnt, _) => vec![],
+        (AssetMintStatus::L2, None) => vec![],
+        (AssetMintStatus::L2, Some(_)) => vec![L1Drift::new(asset.pubkey, L1DriftKind::MintedButL2)],
+        // The mint transaction may have not landed yet
+        (AssetMintStatus::Minting, None) => vec![],
+        (AssetMintStatus::L1_SOLANA, None) => vec![L1Drift::new(asset.pubkey, L1DriftKind::MissingOnChain)],
+        (AssetMintStatus::Minting | AssetMintStatus::L1_SOLANA, Some(account)) => {
+            let mut drifts = Vec::new();
+
+            let chain_owner = bs58::encode(account.owner).into_string();
+            if asset.owner != chain_owner {
+                drifts.push(L1Drift::mismatch(asset.pubkey, L1DriftKind::OwnerMismatch, &asset.owner, &chain_owner));
+            }
+            if asset.name != account.name {
+                drifts.push(L1Drift::mismatch(asset.pubkey, L1DriftKind::NameMismatch, &asset.name, &account.name));
+            }
+            if expected_uri != account.uri {
+                drifts.push(L1Drift::mismatch(asset.pubkey, L1DriftKind::UriMismatch, expected_uri, &account.uri));
+            }
+
+            drifts
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn asset(owner: PublicKey) -> L2Asset {
+        L2Asset {
+            pubkey: [1; 32],
+            name: "name1".to_string(),
+            owner: bs58::encode(owner).into_string(),
+            creator: "creator".to_string(),
+            collection: None,
+            authority: "authority".to_string(),
+            royalty_basis_points: 0,
+            create_timestamp: chrono::Utc::now().naive_utc(),
+            update_timestamp: chrono::Utc::now().naive_utc(),
+            bip44_account_num: 0,
+            bip44_address_num: 0,
+            burnt: false,
+        }
+    }
+
+    #[test]
+    fn test_detect_l1_drifts() {
+        let uri = "http://spell/asset/1/metadata.json";
+        let asset = asset([2; 32]);
+        let account =
+            L1AssetAccount { pubkey: [1; 32], owner: [2; 32], name: "name1".to_string(), uri: uri.to_string() };
+
+        assert!(detect_l1_drifts(&asset, &AssetMintStatus::L1_SOLANA, uri, Some(&account)).is_empty());
+        assert!(detect_l1_drifts(&asset, &AssetMintStatus::Minting, uri, Some(&account)).is_empty());
+        assert!(detect_l1_drifts(&asset, &AssetMintStatus::Minting, uri, None).is_empty());
+        assert!(detect_l1_drifts(&asset, &AssetMintStatus::L2, uri, None).is_empty());
+        assert!(detect_l1_drifts(&asset, &AssetMintStatus::Burnt, uri, Some(&account)).is_empty());
+
+        assert_eq!(
+            detect_l1_drifts(&asset, &AssetMintStatus::L1_SOLANA, uri, None),
+            vec![L1Drift::new(asset.pubkey, L1DriftKind::MissingOnChain)]
+        );
+        assert_eq!(
+            detect_l1_drifts(&asset, &AssetMintStatus::L2, uri, Some(&account)),
+            vec![L1Drift::new(asset.pubkey, L1DriftKind::MintedButL2)]
+        );
+
+        let drifted = L1AssetAccount {
+            owner: [3; 32],
+            name: "name2".to_string(),
+            uri: "http://other".to_string(),
+            ..account
+        };
+        assert_eq!(
+            detect_l1_drifts(&asset, &AssetMintStatus::L1_SOLANA, uri, Some(&drifted)),
+            vec![
+                L1Drift::mismatch(
+                    asset.pubkey,
+                    L1DriftKind::OwnerMismatch,
+                    &asset.owner,
+                    &bs58::encode([3; 32]).into_string()
+                ),
+                L1Drift::mismatch(asset.pubkey, L1DriftKind::NameMismatch, "name1", "name2"),
+                L1Drift::mismatch(asset.pubkey, L1DriftKind::UriMismatch, uri, "http://other"),
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::dto::AssetMintStatus;
 use serde::{Deserialize, Serialize};
 
 pub type PublicKey = [u8; 32];
@@ -41,6 +42,110 @@ pub struct L2Asset {
     /// Number that had been used as change in PIB44 derivation,
     /// to generate the asset pubkey
     pub bip44_address_num: u32,
+
+    /// Burnt asset is not deleted, but is kept for audit purposes
+    pub burnt: bool,
+}
+
+/// Represents L2 collection, i.e. a group of L2 assets.
+/// An asset is a verified member of the collection if both have the same authority.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L2Collection {
+    /// Collection uniqueue identifier
+    pub pubkey: PublicKey,
+
+    /// Collection name
+    pub name: String,
+
+    /// Pubkey of user who created the collection.
+    pub authority: String,
+
+    /// The timestamp of the L2 collection creation
+    pub create_timestamp: chrono::NaiveDateTime,
+
+    /// The timestamp of the L2 collection update
+    pub update_timestamp: chrono::NaiveDateTime,
+
+    /// Number that had been used as account in PIB44 derivation,
+    /// to generate the collection pubkey
+    pub bip44_account_num: u32,
+
+    /// Number that had been used as change in PIB44 derivation,
+    /// to generate the collection pubkey
+    pub bip44_address_num: u32,
+}
+
+/// Represents a change of L2 asset owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L2AssetTransfer {
+    pub asset_pubkey: PublicKey,
+
+    /// Owner of the asset before the transfer
+    pub from: String,
+
+    /// Owner of the asset after the transfer
+    pub to: String,
+
+    pub timestamp: chrono::NaiveDateTime,
+
+    /// Name of the API key user who has performed the transfer
+    pub username: String,
+}
+
+/// Job that awaits confirmation of the L1 mint transaction,
+/// and then finalizes or rolls back the mint of the asset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintConfirmationJob {
+    pub asset_pubkey: PublicKey,
+
+    /// Signature of the mint transaction
+    pub signature: Vec<u8>,
+
+    /// Recent blockhash of the mint transaction, the transaction cannot land once it has expired.
+    /// Unknown for the mints that had been sent before the jobs were introduced.
+    pub recent_blockhash: Option<[u8; 32]>,
+
+    /// Block height after which the transaction cannot land, unknown if `recent_blockhash` is unknown
+    pub last_valid_block_height: Option<u64>,
+
+    /// Number of confirmation checks done so far
+    pub attempts: u32,
+}
+
+/// Whether an asset has to meet all the search conditions, or any of them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SearchConditionType {
+    #[default]
+    All,
+    Any,
+}
+
+/// Conditions of the asset search, the conditions that are not set are ignored
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetSearchQuery {
+    pub condition_type: SearchConditionType,
+    pub owner: Option<String>,
+    pub creator: Option<String>,
+    pub authority: Option<String>,
+    pub collection: Option<PublicKey>,
+
+    /// Case insensitive substring of the asset name
+    pub name: Option<String>,
+    pub burnt: Option<bool>,
+    pub state: Option<AssetMintStatus>,
+}
+
+impl AssetSearchQuery {
+    /// Checks whether no conditions are set, i.e. every asset matches the query
+    pub fn is_empty(&self) -> bool {
+        self.owner.is_none()
+            && self.creator.is_none()
+            && self.authority.is_none()
+            && self.collection.is_none()
+            && self.name.is_none()
+            && self.burnt.is_none()
+            && self.state.is_none()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -49,7 +154,7 @@ pub struct AssetSorting {
     pub sort_direction: AssetSortDirection,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum AssetSortBy {
     #[default]
     Created,
@@ -60,7 +165,7 @@ impl ToString for AssetSortBy {
     fn to_string(&self) -> String {
         match self {
             AssetSortBy::Created => "asset_create_timestamp",
-            AssetSortBy::Updated => "asset_update_timestamp",
+            AssetSortBy::Updated => "asset_last_update_timestamp",
         }
         .to_string()
     }
@@ -83,6 +188,31 @@ impl ToString for AssetSortDirection {
     }
 }
 
+/// Page of assets to fetch, either by its number or by cursors
+#[derive(Clone, Debug, Default)]
+pub struct AssetPagination {
+    pub limit: u32,
+    /// Number of the page, starting from 1. Cursors are ignored if it is set
+    pub page: Option<u32>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl AssetPagination {
+    /// Number of assets preceding the page
+    pub fn offset(&self) -> Option<u64> {
+        self.page
+            .map(|page| u64::from(page.saturating_sub(1)) * u64::from(self.limit))
+    }
+}
+
+/// Page of items together with the total number of items matching the query
+#[derive(Clone, Debug, Default)]
+pub struct AssetPage<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
 pub fn pubkey_to_string(pubkey: PublicKey) -> String {
     bs58::encode(pubkey).into_string()
 }
@@ -1,4 +1,6 @@
 pub mod api_key;
+pub mod l1_reconciliation;
 pub mod l2;
+pub mod webhook;
 
 pub mod dto;
@@ -0,0 +1,43 @@
+use crate::dto::AssetMintStatus;
+use serde::{Deserialize, Serialize};
+
+/// Callback URL provided by the client along with the mint transaction,
+/// that is notified once the mint is finalized or rolled back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintCallback {
+    pub url: String,
+
+    /// Name of the API key user who has requested the mint,
+    /// the webhook is signed with the secret of this user if the API key is from the config.
+    pub username: String,
+
+    /// ID of the issued API key that has requested the mint, the webhook is signed with the secret of this key.
+    pub api_key_id: Option<i64>,
+}
+
+/// Payload of the webhook sent to the mint callback URL
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MintStatusWebhook {
+    pub asset_pubkey: String,
+
+    /// `l1_solana` if the mint has been finalized, `l2` if it has been rolled back
+    pub status: AssetMintStatus,
+
+    /// Signature of the mint transaction
+    pub signature: String,
+}
+
+/// Webhook waiting for delivery
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub webhook_id: i64,
+    pub url: String,
+    pub payload: String,
+    pub username: String,
+
+    /// Hash of the issued API key that has registered the callback, the webhook secret is derived from it
+    pub api_key_hash: Option<String>,
+
+    /// Number of failed delivery attempts so far
+    pub attempts: u32,
+}
@@ -1,5 +1,8 @@
-// use entities::api_key::ApiKeys;
+use async_trait::async_trait;
+use entities::api_key::ApiKeyOwner;
 
-// pub trait ApiKeysProvider {
-//     fn get_api_keys(&self) -> ApiKeys;
-// }
+#[async_trait]
+pub trait ApiKeysProvider {
+    /// Resolves the user the API key belongs to, `None` if the key is unknown, expired or revoked.
+    async fn get_api_key_owner(&self, api_key: &str) -> anyhow::Result<Option<ApiKeyOwner>>;
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use entities::api_key::{ApiKeyInfo, ApiKeyScope, Username};
+
+/// Storage of the API keys issued via the admin endpoints
+#[async_trait]
+pub trait ApiKeyStorage {
+    async fn save_api_key(
+        &self,
+        key_hash: &str,
+        username: &Username,
+        label: &str,
+        scopes: &[ApiKeyScope],
+        restricted: bool,
+        expiry_timestamp: Option<NaiveDateTime>,
+    ) -> anyhow::Result<ApiKeyInfo>;
+
+    async fn find_api_key(&self, key_hash: &str) -> anyhow::Result<Option<ApiKeyInfo>>;
+
+    async fn list_api_keys(&self) -> anyhow::Result<Vec<ApiKeyInfo>>;
+
+    /// Revokes the key, keeping it in the storage for the audit.
+    /// Returns `None` if there is no key with the given id.
+    async fn revoke_api_key(&self, id: i64) -> anyhow::Result<Option<ApiKeyInfo>>;
+
+    /// Assigns the asset authority to the key, unless it already belongs to another key
+    /// or there are assets or collections with this authority not created by the key.
+    /// Returns `true` if the authority belongs to the given key.
+    async fn claim_authority(&self, api_key_id: i64, authority: &str) -> anyhow::Result<bool>;
+
+    /// Returns ID of the key the asset authority belongs to.
+    async fn find_authority_owner(&self, authority: &str) -> anyhow::Result<Option<i64>>;
+}
@@ -1,5 +1,8 @@
 use entities::dto::AssetMintStatus;
-use entities::l2::{AssetSorting, L2Asset, PublicKey};
+use entities::l2::{
+    AssetPage, AssetPagination, AssetSearchQuery, AssetSorting, L2Asset, L2AssetTransfer, L2Collection, PublicKey,
+};
+use entities::webhook::MintCallback;
 use serde::{Deserialize, Serialize};
 use solana_sdk::signature::Signature;
 use solana_sdk::transaction::Transaction;
@@ -9,6 +12,26 @@ use thiserror::Error;
 pub struct L2AssetInfo {
     pub asset: L2Asset,
     pub metadata: Option<String>,
+    /// L2 collection the asset belongs to, `None` if there is no such L2 collection
+    pub collection: Option<L2CollectionInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct L2CollectionInfo {
+    pub collection: L2Collection,
+    pub metadata: Option<String>,
+}
+
+/// Asset of a batch creation, see [`AssetService::create_asset`] for the meaning of the fields
+#[derive(Debug, Clone)]
+pub struct NewL2Asset {
+    pub metadata_json: String,
+    pub owner: String,
+    pub creator: String,
+    pub authority: String,
+    pub name: String,
+    pub royalty_basis_points: u16,
+    pub collection: Option<PublicKey>,
 }
 
 #[async_trait::async_trait]
@@ -44,12 +67,24 @@ pub trait AssetService {
         collection: Option<PublicKey>,
     ) -> anyhow::Result<L2AssetInfo>;
 
+    /// Creates L2 assets in bulk, same as [`AssetService::create_asset`] does for a single asset.
+    /// The derivation values of all the assets are reserved at once, the metadata is uploaded concurrently
+    /// and the assets are saved with a single insert.
+    /// Returns the result of every asset in the order of `assets`, an asset with invalid metadata
+    /// or failed metadata upload doesn't prevent the others from being created.
+    async fn create_assets_batch(&self, assets: Vec<NewL2Asset>) -> anyhow::Result<Vec<anyhow::Result<L2AssetInfo>>>;
+
     /// Updates existing L2 asset.
+    /// The owner isn't updatable, it's changed only by [`AssetService::transfer_asset`].
+    ///
+    /// ## Args:
+    /// * `authorized_authority` - authority the caller has been authorized for, if any,
+    ///   the asset is updated only if it still has this authority at the moment of the update
     async fn update_asset(
         &self,
         asset_pubkey: PublicKey,
+        authorized_authority: Option<&str>,
         metadata_json: Option<&str>,
-        owner: Option<String>,
         creator: Option<String>,
         authority: Option<String>,
         name: Option<&str>,
@@ -68,10 +103,93 @@ pub trait AssetService {
     /// * `asset_pubkeys` - public keys that has been generate on L2 asset creation
     async fn fetch_assets(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<L2AssetInfo>>;
 
+    /// Returns those of `asset_pubkeys` that belong to assets minted on L1,
+    /// which are not returned by [`AssetService::fetch_asset`] and [`AssetService::fetch_assets`].
+    async fn fetch_minted_asset_pubkeys(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<PublicKey>>;
+
+    /// Burns existing L2 asset.
+    /// The asset is not deleted, but moved to the `BURNT` state and kept for audit purposes.
+    ///
+    /// Returns `None` if there is no asset with given pubkey.
+    /// Burn is refused with [`AssetBurnError`] if the asset is being minted, already minted on L1 or already burnt.
+    ///
+    /// ## Args:
+    /// * `asset_pubkey` - public key that has been generate on L2 asset creation
+    async fn burn_asset(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<L2AssetInfo>>;
+
+    /// Create a new L2 collection.
+    /// Similar to assets, collection ID is a public key derived from our keypair.
+    ///
+    /// ## Args:
+    /// * `metadata_json` - NTF metadata file of the collection generated by client
+    /// * `name` - collection name
+    /// * `authority` - pubkey of a user who is creating the collection,
+    ///     only assets with the same authority are verified members of the collection
+    async fn create_collection(
+        &self,
+        metadata_json: &str,
+        name: &str,
+        authority: &str,
+    ) -> anyhow::Result<L2CollectionInfo>;
+
+    /// Updates existing L2 collection.
+    async fn update_collection(
+        &self,
+        collection_pubkey: PublicKey,
+        metadata_json: Option<&str>,
+        name: Option<&str>,
+        authority: Option<String>,
+    ) -> anyhow::Result<Option<L2CollectionInfo>>;
+
+    /// Fetches existing L2 collection.
+    async fn fetch_collection(&self, collection_pubkey: PublicKey) -> anyhow::Result<Option<L2CollectionInfo>>;
+
+    /// Transfers L2 asset to a new owner and records the transfer into the asset ownership history.
+    ///
+    /// Returns `None` if there is no asset with given pubkey.
+    /// Transfer is refused with [`AssetTransferError`] if the current owner of the asset is not `from`,
+    /// which makes concurrent transfers of the same asset safe.
+    ///
+    /// ## Args:
+    /// * `asset_pubkey` - public key that has been generate on L2 asset creation
+    /// * `from` - expected current owner of the asset
+    /// * `to` - new owner of the asset
+    /// * `username` - name of the API key user who is performing the transfer
+    async fn transfer_asset(
+        &self,
+        asset_pubkey: PublicKey,
+        from: &str,
+        to: &str,
+        username: &str,
+    ) -> anyhow::Result<Option<L2AssetInfo>>;
+
+    /// Fetches ownership history of L2 asset, from the oldest transfer to the newest one.
+    async fn fetch_transfers(&self, asset_pubkey: PublicKey) -> anyhow::Result<Vec<L2AssetTransfer>>;
+
     /// Fetch NFT metadata JSON for given asset
     async fn fetch_metadata(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<String>>;
 
-    /// Fetches existing L2 assets by owner.
+    /// Persists the binary file (image, video, etc.) of existing L2 asset in our storage
+    /// and makes the asset NFT metadata JSON point to it (`image` and `properties.files`),
+    /// so the asset is fully hosted by us.
+    ///
+    /// Returns `None` if there is no asset with given pubkey.
+    ///
+    /// ## Args:
+    /// * `asset_pubkey` - public key that has been generate on L2 asset creation
+    /// * `bytes` - content of the file
+    /// * `mime` - MIME type of the file
+    async fn upload_binary(
+        &self,
+        asset_pubkey: PublicKey,
+        bytes: Vec<u8>,
+        mime: &str,
+    ) -> anyhow::Result<Option<L2AssetInfo>>;
+
+    /// Fetch binary file of given asset together with its MIME type
+    async fn fetch_binary(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<(Vec<u8>, String)>>;
+
+    /// Fetches existing L2 assets by owner, including the assets minted on L1.
     /// This specification was used for implementation:
     ///
     /// https://docs.extrnode.com/das_api/get_assets_by_owner
@@ -79,28 +197,100 @@ pub trait AssetService {
     /// ## Args:
     /// * `owner_pubkey` - public key of asset owner
     /// * `sorting` - sorting params for response
-    /// * `limit` - limit of returning assets
+    /// * `pagination` - page of assets to return, either by its number or by cursors
+    ///
+    /// Returns the page together with the total number of assets the owner has.
     async fn fetch_assets_by_owner(
         &self,
         owner_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2AssetInfo>>;
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>>;
 
     async fn fetch_assets_by_creator(
         &self,
         creator_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2AssetInfo>>;
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>>;
+
+    async fn fetch_assets_by_authority(
+        &self,
+        authority_pubkey: &str,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>>;
+
+    async fn fetch_assets_by_collection(
+        &self,
+        collection_pubkey: &PublicKey,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>>;
+
+    /// Searches assets matching the query, including the assets minted on L1.
+    /// Sorting and pagination are the same as for [`AssetService::fetch_assets_by_owner`].
+    async fn search_assets(
+        &self,
+        query: &AssetSearchQuery,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>>;
+
+    /// Creates unsigned L1 mint transaction for the L2 asset, that has to be signed by the `payer`
+    /// before it is sent back for execution, where it's signed by the asset keypair.
+    /// ## Args:
+    /// * `asset_pubkey` - pubkey of the L2 asset to mint
+    /// * `payer` - Solana account that pays for the mint
+    ///
+    /// Returns `None` if the asset is not found.
+    async fn create_mint_transaction(
+        &self,
+        asset_pubkey: PublicKey,
+        payer: PublicKey,
+    ) -> anyhow::Result<Option<Transaction>>;
 
-    /// Execute asset L1 mint transaction received from the client.
     async fn get_mint_status(&self, public_key: PublicKey) -> anyhow::Result<(AssetMintStatus, Option<Signature>)>;
-    async fn execute_asset_l1_mint(&self, tx: Transaction, exec_sync: bool) -> anyhow::Result<()>;
+
+    /// Execute asset L1 mint transaction received from the client.
+    /// Fails with [`L1MintError::NotConfirmed`] if the transaction has been sent, but sending or confirming it failed,
+    /// in which case the asset stays locked until the mint is finalized or rolled back by the confirmation workers.
+    /// ## Args:
+    /// * `tx` - mint transaction partially signed by the client
+    /// * `exec_sync` - whether to wait for the transaction to confirm
+    /// * `callback` - callback to be notified once the mint is finalized or rolled back
+    async fn execute_asset_l1_mint(
+        &self,
+        tx: Transaction,
+        exec_sync: bool,
+        callback: Option<MintCallback>,
+    ) -> anyhow::Result<()>;
+}
+
+#[derive(Error, Debug)]
+pub enum AssetBurnError {
+    #[error("Asset is being minted on L1")]
+    Minting,
+    #[error("Asset is already minted on L1")]
+    MintedOnL1,
+    #[error("Asset is burnt")]
+    Burnt,
+}
+
+#[derive(Error, Debug)]
+pub enum AssetUpdateError {
+    #[error("Asset authority has been changed, actual='{0}'")]
+    AuthorityChanged(String),
+    #[error("Only L2 assets can be updated")]
+    NotL2Asset,
+}
+
+#[derive(Error, Debug)]
+pub enum AssetTransferError {
+    #[error("Wrong owner, actual='{0}'")]
+    WrongOwner(String),
+    #[error("Only L2 assets can be transferred")]
+    NotL2Asset,
 }
 
 #[derive(Error, Debug)]
@@ -121,4 +311,8 @@ pub enum L1MintError {
     WrongOwner,
     #[error("Wrong collection")]
     WrongCollection,
+    #[error("Not a valid Solana pubkey: '{0}'")]
+    NotSolanaPubkey(String),
+    #[error("Mint transaction '{0}' has been sent, but not confirmed yet, the mint status is updated once it is")]
+    NotConfirmed(String),
 }
@@ -11,5 +11,5 @@ pub trait AssetMetadataStorage {
 #[async_trait]
 pub trait BlobStorage {
     async fn put_binary(&self, pubkey: &PublicKey, bytes: Vec<u8>, mime: &str) -> anyhow::Result<()>;
-    async fn get_binary(&self, pubkey: &PublicKey) -> anyhow::Result<(Vec<u8>, String)>;
+    async fn get_binary(&self, pubkey: &PublicKey) -> anyhow::Result<Option<(Vec<u8>, String)>>;
 }
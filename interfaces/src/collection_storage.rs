@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use entities::l2::{L2Collection, PublicKey};
+
+/// Storage interfaces for L2 collections managing
+#[async_trait]
+pub trait CollectionStorage {
+    async fn save_collection(&self, collection: &L2Collection) -> anyhow::Result<()>;
+    async fn find_collection(&self, pubkey: &PublicKey) -> anyhow::Result<Option<L2Collection>>;
+    async fn find_collections_batch(&self, pubkeys: &[PublicKey]) -> anyhow::Result<Vec<L2Collection>>;
+}
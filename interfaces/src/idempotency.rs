@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use entities::api_key::Username;
+use thiserror::Error;
+
+/// Response of the request made with an idempotency key, replayed on the retries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotentResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyKeyReservation {
+    /// The key is new, the request has to be processed
+    Reserved,
+    /// The key is already used, the response is not set while the first request is still in progress
+    Existing { request_hash: String, response: Option<IdempotentResponse> },
+}
+
+#[derive(Error, Debug)]
+pub enum IdempotencyError {
+    #[error("Idempotency key must be from 1 to {0} characters long")]
+    Malformed(usize),
+    #[error("Idempotency key requires an API key")]
+    MissingApiKey,
+    #[error("Idempotency key is already used for a different request")]
+    KeyReused,
+    #[error("Request with the idempotency key is still in progress")]
+    InProgress,
+}
+
+/// Storage of the idempotency keys of the users along with the responses to the requests made with them
+#[async_trait]
+pub trait IdempotencyStorage {
+    /// Reserves the key of the user for the request, the keys created before `expired_before` are dropped first,
+    /// along with the reservations without a response created before `abandoned_before`,
+    /// as their requests are not going to complete.
+    /// Returns the request hash and the response of the earlier request, if the key is already used.
+    async fn reserve_idempotency_key(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        request_hash: &str,
+        expired_before: NaiveDateTime,
+        abandoned_before: NaiveDateTime,
+    ) -> anyhow::Result<IdempotencyKeyReservation>;
+
+    async fn save_idempotent_response(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        response: &IdempotentResponse,
+    ) -> anyhow::Result<()>;
+
+    /// Drops the reservation of the failed request, so it can be retried with the same key
+    async fn release_idempotency_key(&self, username: &Username, idempotency_key: &str) -> anyhow::Result<()>;
+}
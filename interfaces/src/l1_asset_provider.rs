@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use entities::dto::Asset;
+use entities::l2::PublicKey;
+
+/// Source of the DAS view of assets that have been minted on L1,
+/// e.g. an upstream DAS API that indexes Solana.
+#[async_trait]
+pub trait L1AssetProvider {
+    /// Fetches assets by their pubkeys.
+    /// Returns an entry for every requested pubkey, in the same order,
+    /// `None` means the asset is unknown to the source.
+    async fn get_assets(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<Option<Asset>>>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use entities::dto::AssetMintStatus;
+use entities::l1_reconciliation::L1Drift;
+use entities::l2::{L2Asset, PublicKey};
+
+/// Storage interfaces for the reconciliation of assets with their L1 state
+#[async_trait]
+pub trait L1ReconciliationStorage {
+    /// Returns up to `limit` assets that are not burnt, along with their mint statuses,
+    /// ordered by pubkey and starting right after `after`, if it is specified.
+    async fn find_assets_to_reconcile(
+        &self,
+        after: Option<PublicKey>,
+        limit: u32,
+    ) -> anyhow::Result<Vec<(L2Asset, AssetMintStatus)>>;
+
+    /// Replaces the previously detected drifts of the `checked` assets with `drifts`.
+    async fn save_l1_drifts(&self, checked: &[PublicKey], drifts: &[L1Drift]) -> anyhow::Result<()>;
+
+    /// Returns the drifts detected for the asset during the last reconciliation.
+    async fn find_l1_drifts(&self, asset_pubkey: &PublicKey) -> anyhow::Result<Vec<L1Drift>>;
+}
@@ -1,11 +1,23 @@
+use entities::l1_reconciliation::L1AssetAccount;
 use entities::l2::PublicKey;
 use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash;
 use solana_sdk::signature::{Keypair, Signature};
 use thiserror::Error;
 
 use solana_sdk::transaction::Transaction;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Mint transaction that has been signed by the asset keypair and is ready to be sent to Solana
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedMintTransaction {
+    pub signature: Signature,
+    pub recent_blockhash: Hash,
+    /// The transaction cannot land once the block height exceeds this value
+    pub last_valid_block_height: u64,
+}
+
+/// Data of mpl-core CreateV1 instruction that mints an asset on L1
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParsedMintIxInfo {
     pub asset_pubkey: PublicKey,
     pub authority: Option<PublicKey>,
@@ -24,24 +36,36 @@ pub trait L1Service {
     /// * `tx` - transaction created on the client side
     fn parse_mint_transaction(&self, tx: &Transaction) -> anyhow::Result<ParsedMintIxInfo>;
 
-    /// Accepts a transaction that contains single mpl-core CreateV1Builder instruction
-    /// created on the client side and partially signed by the client,
-    /// and executes it on Solana.
-    /// The asset specified in the instruction should be an L2 asset that is not minted yet.
-    /// The payer for the transaction should be specified by the client.
+    /// Creates a transaction that contains single mpl-core CreateV1Builder instruction built from `mint_info`
+    /// and sets a recent blockhash to it.
+    /// The returned transaction is ready to be signed by the payer (and the authority, if it differs from the payer),
+    /// it's signed by the asset keypair only by [`L1Service::execute_mint_transaction`].
+    /// ## Args:
+    /// * `mint_info` - data of the mint instruction
+    async fn create_mint_transaction(&self, mint_info: &ParsedMintIxInfo) -> anyhow::Result<Transaction>;
+
+    /// Signs a transaction that contains single mpl-core CreateV1Builder instruction
+    /// created on the client side and partially signed by the client, by the asset keypair.
+    /// Fails if the transaction isn't fully signed afterwards, i.e. nothing is sent to Solana on errors.
     /// ## Args:
     /// * `tx` - transaction created on the client side
     /// * `asset_keypair` - keypair for the asset specified in the transaction,
     ///    i.e. asset ID is a pubkey of this keypair.
     ///    (We use bip44 to derive this keypair from our master keypair,
     ///    when we initially an L2 asset)
-    /// * `exec_sync` - whether we want to wait for the transaction to confirm (can take up to 2 mins of mainnet)
-    async fn execute_mint_transaction(
+    async fn sign_mint_transaction(
         &self,
-        tx: Transaction,
+        tx: &mut Transaction,
         asset_keypair: &Keypair,
-        exec_sync: bool,
-    ) -> anyhow::Result<Signature>;
+    ) -> anyhow::Result<SignedMintTransaction>;
+
+    /// Executes the mint transaction signed by [`L1Service::sign_mint_transaction`] on Solana.
+    /// The asset specified in the instruction should be an L2 asset that is not minted yet.
+    /// An error doesn't mean the transaction hasn't landed, e.g. the confirmation may time out after it was sent.
+    /// ## Args:
+    /// * `tx` - fully signed mint transaction
+    /// * `exec_sync` - whether we want to wait for the transaction to confirm (can take up to 2 mins of mainnet)
+    async fn execute_mint_transaction(&self, tx: &Transaction, exec_sync: bool) -> anyhow::Result<()>;
 
     /// Sends a request to Solana to retrieve the transaction processing status.
     ///
@@ -53,6 +77,26 @@ pub trait L1Service {
     /// The combination of `Ok(true) | Err(_)` means that the minting of the asset was either rejected
     ///     or is still being processed.
     async fn is_asset_minted(&self, tx_signature: &Signature) -> anyhow::Result<bool>;
+
+    /// Sends a request to Solana to retrieve the transaction processing status.
+    /// Unlike [`L1Service::is_asset_minted`], distinguishes a transaction that is not found from a failed RPC call:
+    /// * `Ok(None)` - if the transaction is not found
+    /// * `Ok(Some(true))` - if the minting process has been successfully completed
+    /// * `Ok(Some(false))` - if the minting process was declined
+    async fn get_mint_transaction_status(&self, tx_signature: &Signature) -> anyhow::Result<Option<bool>>;
+
+    /// Returns the current block height.
+    async fn get_block_height(&self) -> anyhow::Result<u64>;
+
+    /// Checks whether the blockhash is still valid, i.e. a transaction with this recent blockhash can still land.
+    async fn is_blockhash_valid(&self, blockhash: &Hash) -> anyhow::Result<bool>;
+
+    /// Fetches mpl-core asset accounts from Solana.
+    /// Returns an entry for every requested pubkey, in the same order,
+    /// `None` means there is no mpl-core asset account with such pubkey.
+    /// ## Args:
+    /// * `asset_pubkeys` - pubkeys of the assets, up to 100 per call
+    async fn get_asset_accounts(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<Option<L1AssetAccount>>>;
 }
 
 #[derive(Error, Debug)]
@@ -1,29 +1,77 @@
 use async_trait::async_trait;
 use entities::dto::AssetMintStatus;
-use entities::l2::{AssetSorting, L2Asset, PublicKey};
+use entities::l2::{AssetPage, AssetPagination, AssetSearchQuery, AssetSorting, L2Asset, L2AssetTransfer, PublicKey};
 
 /// Storage interfaces for L2 assets managing
 #[async_trait]
 pub trait L2Storage {
     async fn save(&self, asset: &L2Asset) -> anyhow::Result<()>;
+    /// Inserts the newly created assets at once.
+    async fn save_batch(&self, assets: &[L2Asset]) -> anyhow::Result<()>;
     async fn find(&self, pubkey: &PublicKey) -> anyhow::Result<Option<L2Asset>>;
     async fn find_batch(&self, pubkeys: &[PublicKey]) -> anyhow::Result<Vec<L2Asset>>;
+    /// Returns those of `pubkeys` that belong to assets minted on L1.
+    async fn find_minted(&self, pubkeys: &[PublicKey]) -> anyhow::Result<Vec<PublicKey>>;
+    /// Unlike [`L2Storage::find`], returns assets minted on L1 too,
+    /// with the data they had at the moment of the mint.
+    /// The page is fetched either by its number, using `OFFSET`, or by cursors.
     async fn find_by_owner(
         &self,
         owner_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2Asset>>;
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>;
+    /// Same as [`L2Storage::find_by_owner`], returns assets minted on L1 too.
     async fn find_by_creator(
         &self,
         creator_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2Asset>>;
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>;
+    /// Same as [`L2Storage::find_by_owner`], returns assets minted on L1 too.
+    async fn find_by_authority(
+        &self,
+        authority_pubkey: &str,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>;
+    /// Same as [`L2Storage::find_by_owner`], returns assets minted on L1 too.
+    async fn find_by_collection(
+        &self,
+        collection_pubkey: &PublicKey,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>;
+    /// Finds assets matching the query, including assets minted on L1,
+    /// with the same sorting and pagination as [`L2Storage::find_by_owner`].
+    async fn search(
+        &self,
+        query: &AssetSearchQuery,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>;
+
+    /// Changes owner of L2 asset and records the transfer into the ownership history.
+    /// The owner is changed only if the asset is in the `L2` state and its current owner is `from`,
+    /// should guarantee atomic compare-and-swap of the owner.
+    ///
+    /// Returns the updated asset, or `None` if the owner hasn't been changed.
+    async fn transfer(
+        &self,
+        pubkey: &PublicKey,
+        from: &str,
+        to: &str,
+        username: &str,
+    ) -> anyhow::Result<Option<L2Asset>>;
+    /// Updates L2 asset, only if the asset is in the `L2` state and its current authority is `authority`,
+    /// should guarantee atomic compare-and-swap, so the update is applied to the asset it was authorized for.
+    /// The owner isn't updated, it's changed only by [`L2Storage::transfer`].
+    ///
+    /// Returns `false` if the asset hasn't been updated.
+    async fn update(&self, asset: &L2Asset, authority: &str) -> anyhow::Result<bool>;
+
+    /// Returns ownership history of the asset, from the oldest transfer to the newest one.
+    async fn find_transfers(&self, pubkey: &PublicKey) -> anyhow::Result<Vec<L2AssetTransfer>>;
 
     /// Should guarantee atomic status update.
     async fn lock_asset_before_minting(&self, pubkey: &PublicKey) -> anyhow::Result<bool>;
@@ -31,6 +79,11 @@ pub trait L2Storage {
     async fn add_l1_asset(&self, pubkey: &PublicKey, tx_signature: &[u8]) -> anyhow::Result<()>;
     async fn finalize_mint(&self, pubkey: &PublicKey) -> anyhow::Result<()>;
     async fn mint_didnt_happen(&self, pubkey: &PublicKey) -> anyhow::Result<()>;
+    /// Moves L2 asset to the `BURNT` state, the asset record itself is kept.
+    /// Only assets in the `L2` state are burnt, should guarantee atomic status update.
+    ///
+    /// Returns the state the asset had before the call, or `None` if there is no such asset.
+    async fn burn(&self, pubkey: &PublicKey) -> anyhow::Result<Option<AssetMintStatus>>;
     async fn get_mint_status_and_signature(
         &self,
         pubkey: &PublicKey,
@@ -52,6 +105,8 @@ pub struct DerivationValues {
 pub trait Bip44DerivationSequence {
     /// Returns next value of account and address
     async fn next_account_and_address(&self) -> anyhow::Result<DerivationValues>;
+    /// Reserves `count` next values of account and address in a single call to the sequence
+    async fn next_accounts_and_addresses(&self, count: usize) -> anyhow::Result<Vec<DerivationValues>>;
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -1,4 +1,14 @@
+pub mod api_key_provider;
+pub mod api_key_storage;
 pub mod asset_service;
 pub mod asset_storage;
+pub mod collection_storage;
+pub mod idempotency;
+pub mod l1_asset_provider;
+pub mod l1_reconciliation_storage;
 pub mod l1_service;
 pub mod l2_storage;
+pub mod mint_job_storage;
+pub mod rate_limit;
+pub mod wallet_signature;
+pub mod webhook_storage;
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use entities::l2::{MintConfirmationJob, PublicKey};
+use entities::webhook::MintCallback;
+use std::time::Duration;
+
+/// Storage interfaces for the queue of jobs awaiting confirmation of L1 mints
+#[async_trait]
+pub trait MintJobStorage {
+    /// Enqueues the job, replacing the previous job for the asset, if any.
+    async fn add_mint_job(&self, job: &MintConfirmationJob) -> anyhow::Result<()>;
+
+    /// Records the sent mint transaction of the asset, saves the mint callback, if any, and enqueues the job.
+    /// Should guarantee all of it happens atomically, so the asset is never left `MINTING` without a job.
+    async fn start_mint_job(&self, job: &MintConfirmationJob, callback: Option<&MintCallback>) -> anyhow::Result<()>;
+
+    /// Enqueues jobs for the assets in `MINTING` state that have no job,
+    /// i.e. for the mints that had been sent before the jobs were introduced.
+    async fn add_missing_mint_jobs(&self) -> anyhow::Result<u64>;
+
+    /// Picks up to `limit` jobs that are due, and postpones their next attempt by `lease`,
+    /// so every job is processed by a single worker at a time.
+    async fn claim_due_mint_jobs(&self, limit: u32, lease: Duration) -> anyhow::Result<Vec<MintConfirmationJob>>;
+
+    /// Records one more confirmation check of the mint that is still pending.
+    async fn reschedule_mint_job(&self, asset_pubkey: &PublicKey, retry_after: Duration) -> anyhow::Result<()>;
+
+    /// Finalizes the mint if `minted`, rolls it back otherwise, removes the job,
+    /// and schedules `webhook_payload` delivery to the mint callback, if there is one.
+    /// Should guarantee all of it happens atomically.
+    async fn complete_mint_job(
+        &self,
+        asset_pubkey: &PublicKey,
+        minted: bool,
+        webhook_payload: &str,
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use entities::api_key::Username;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Operation limited by a daily quota of the API key user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotaOperation {
+    AssetCreation,
+    Mint,
+}
+
+impl QuotaOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuotaOperation::AssetCreation => "asset_creation",
+            QuotaOperation::Mint => "mint",
+        }
+    }
+}
+
+impl Display for QuotaOperation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Operations counted against the daily quota of the user, the failed ones are refunded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumedQuota {
+    pub username: Username,
+    pub operation: QuotaOperation,
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Error, Debug)]
+pub enum RateLimitError {
+    #[error("Too many requests")]
+    TooManyRequests { retry_after: Duration },
+    #[error("Daily quota of '{limit}' for '{operation}' is exhausted")]
+    QuotaExceeded { operation: QuotaOperation, limit: u32, retry_after: Duration },
+}
+
+impl RateLimitError {
+    /// Delay after which the request may succeed
+    pub fn retry_after(&self) -> Duration {
+        match self {
+            RateLimitError::TooManyRequests { retry_after } | RateLimitError::QuotaExceeded { retry_after, .. } => {
+                *retry_after
+            }
+        }
+    }
+}
+
+/// Storage of the daily quota usage of the API key users
+#[async_trait]
+pub trait QuotaStorage {
+    /// Counts `count` operations performed by the user on the given date at once,
+    /// unless the count would exceed the limit, then none of them is counted.
+    /// Returns `false` if the quota is exhausted.
+    async fn try_consume_daily_quota(
+        &self,
+        username: &Username,
+        operation: QuotaOperation,
+        date: NaiveDate,
+        count: u32,
+        limit: u32,
+    ) -> anyhow::Result<bool>;
+
+    /// Uncounts `count` operations of the user on the given date, that have failed after being counted
+    async fn refund_daily_quota(
+        &self,
+        username: &Username,
+        operation: QuotaOperation,
+        date: NaiveDate,
+        count: u32,
+    ) -> anyhow::Result<()>;
+}
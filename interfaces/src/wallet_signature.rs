@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use thiserror::Error;
+
+/// Signature of an L2 mutation by the wallet of the asset authority (or owner for transfers)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletSignature {
+    /// Base58 encoded ed25519 signature of `util::wallet_signature::wallet_signature_message`
+    pub signature: String,
+    pub nonce: String,
+    /// Unix timestamp in seconds, after which the signature is rejected
+    pub expiry: i64,
+}
+
+#[derive(Error, Debug)]
+pub enum WalletSignatureError {
+    #[error("Request must be signed by the '{0}' wallet")]
+    Missing(String),
+    #[error("Malformed wallet signature: {0}")]
+    Malformed(String),
+    #[error("Signer '{0}' is not a Solana public key")]
+    InvalidSigner(String),
+    #[error("Wallet signature is expired")]
+    Expired,
+    #[error("Wallet signature expiry cannot be more than '{0}' seconds ahead")]
+    ExpiryTooFar(u64),
+    #[error("Invalid wallet signature")]
+    InvalidSignature,
+    #[error("Wallet signature nonce is already used")]
+    ReplayedNonce,
+}
+
+/// Storage of the nonces of the wallet signatures, used to reject replayed requests
+#[async_trait]
+pub trait SignatureNonceStorage {
+    /// Saves the nonce of the signer until the signature expires.
+    /// Returns `false` if the nonce is already used by the signer.
+    async fn save_signature_nonce(&self, signer: &str, nonce: &str, expiry: NaiveDateTime) -> anyhow::Result<bool>;
+}
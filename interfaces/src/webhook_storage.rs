@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use entities::l2::PublicKey;
+use entities::webhook::{MintCallback, WebhookDelivery};
+use std::time::Duration;
+
+/// Storage interfaces for webhooks that notify clients about mint results
+#[async_trait]
+pub trait WebhookStorage {
+    /// Registers the callback to be notified once the mint of the asset is finalized or rolled back,
+    /// replacing the previously registered one.
+    async fn save_mint_callback(&self, asset_pubkey: &PublicKey, callback: &MintCallback) -> anyhow::Result<()>;
+
+    /// Schedules delivery of the `payload` to the callback registered for the asset, if there is one.
+    /// The callback is removed, i.e. it is notified only once.
+    async fn schedule_mint_webhook(&self, asset_pubkey: &PublicKey, payload: &str) -> anyhow::Result<()>;
+
+    /// Picks up to `limit` webhooks that are due for delivery, and postpones their next attempt by `lease`,
+    /// so they are not picked by other workers while being delivered.
+    async fn claim_due_webhooks(&self, limit: u32, lease: Duration) -> anyhow::Result<Vec<WebhookDelivery>>;
+
+    async fn mark_webhook_delivered(&self, webhook_id: i64) -> anyhow::Result<()>;
+
+    /// Records failed delivery attempt.
+    /// ## Args:
+    /// * `retry_after` - delay before the next attempt, `None` if the delivery should be given up
+    async fn mark_webhook_failed(
+        &self,
+        webhook_id: i64,
+        error: &str,
+        retry_after: Option<Duration>,
+    ) -> anyhow::Result<()>;
+}
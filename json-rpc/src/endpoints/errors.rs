@@ -4,7 +4,7 @@ use thiserror::Error;
 
 const STANDARD_ERROR_CODE: i64 = -32000;
 
-#[derive(Error, Debug, PartialOrd, PartialEq)]
+#[derive(Error, Debug, Clone, PartialOrd, PartialEq)]
 pub enum DasApiError {
     #[error("No data found.")]
     NoDataFoundError,
@@ -18,6 +18,12 @@ pub enum DasApiError {
     LimitTooBig(u32),
     #[error("Page number is too big. Up to '{0}' pages are supported with this kind of pagination. Please use a different pagination(before/after/cursor).")]
     PageTooBig(u32),
+    #[error("Upstream DAS Error")]
+    UpstreamDasError,
+    #[error("Grouping '{0}' is not supported. Only 'collection' grouping is supported.")]
+    UnsupportedGrouping(String),
+    #[error("Cursor Validation Err: {0} is invalid")]
+    CursorValidationError(String),
 }
 
 impl From<DasApiError> for JsonRpcError {
@@ -44,6 +50,21 @@ impl From<DasApiError> for JsonRpcError {
                 message: format!("Requested limit number is too big. Up to '{max_limit}' limit is supported."),
                 data: None,
             },
+            DasApiError::UpstreamDasError => Self {
+                code: ErrorCode::ServerError(STANDARD_ERROR_CODE),
+                message: "Failed to fetch the asset minted on L1 from upstream DAS".to_string(),
+                data: None,
+            },
+            DasApiError::UnsupportedGrouping(key) => Self {
+                code: ErrorCode::ServerError(STANDARD_ERROR_CODE),
+                message: format!("Grouping '{key}' is not supported. Only 'collection' grouping is supported."),
+                data: None,
+            },
+            DasApiError::CursorValidationError(cursor) => Self {
+                code: ErrorCode::ServerError(STANDARD_ERROR_CODE),
+                message: format!("Cursor Validation Error: {cursor} is invalid or was made for a different sorting"),
+                data: None,
+            },
             DasApiError::PageTooBig(max_limit) => Self {
                 code: ErrorCode::ServerError(STANDARD_ERROR_CODE),
                 message: format!(
@@ -1,152 +1,269 @@
 use crate::endpoints::errors::DasApiError;
 use crate::endpoints::types::{
-    AssetList, GetAsset, GetAssetBatch, GetAssetsByCreator, GetAssetsByOwner, JsonRpcResponse,
+    AssetBatchItem, AssetList, BatchItemError, GetAsset, GetAssetBatch, GetAssetsByAuthority, GetAssetsByCreator,
+    GetAssetsByGroup, GetAssetsByOwner, JsonRpcResponse,
 };
-use crate::endpoints::{DEFAULT_LIMIT_FOR_PAGE, DEFAULT_MAX_PAGE_LIMIT};
+use crate::endpoints::{COLLECTION_GROUP_KEY, DEFAULT_LIMIT_FOR_PAGE, DEFAULT_MAX_PAGE_LIMIT};
 use crate::setup::app_context::ArcedAppCtx;
 use entities::dto::{Asset, AssetExtended};
-use entities::l2::PublicKey;
+use entities::l2::{AssetPage, AssetPagination, AssetSortBy, AssetSorting, PublicKey};
 use interfaces::asset_service::L2AssetInfo;
 use serde_json::json;
+use service::converter::to_collection_extended;
 use std::collections::HashMap;
-use util::base64_encode_decode::encode_timestamp_and_asset_pubkey;
+use tracing::error;
+use util::base64_encode_decode::{decode_asset_cursor, encode_asset_cursor};
 use util::publickey::PublicKeyExt;
 
 pub async fn get_asset(req_params: GetAsset, ctx: ArcedAppCtx) -> JsonRpcResponse {
     let id =
         PublicKey::from_bs58(&req_params.id).ok_or(DasApiError::PubkeyValidationError(req_params.id.to_owned()))?;
 
-    let (asset, metadata) = ctx
+    let Some(L2AssetInfo { asset, metadata, collection }) = ctx
         .asset_service
         .fetch_asset(id)
         .await
         .map_err(|_| DasApiError::DatabaseError)?
-        .ok_or(DasApiError::NoDataFoundError)
-        .map(|l2_info| (l2_info.asset, l2_info.metadata))?;
+    else {
+        let minted_asset = fetch_minted_assets(&[id], &ctx).await?.remove(&id).flatten();
+        return Ok(minted_asset.ok_or(DasApiError::NoDataFoundError)?.into_json());
+    };
 
     let asset_extended_and_metadata = (
-        AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&req_params.id)),
+        AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&req_params.id))
+            .with_collection(collection.map(to_collection_extended)),
         serde_json::to_value(metadata).map_err(|_| DasApiError::JsonMetadataParsing)?,
     );
 
     Ok(Asset::from(asset_extended_and_metadata).into_json())
 }
 
+/// Returns the assets in the order of the requested ids, `null` for the unknown ones.
+/// Ids that cannot be served, e.g. invalid pubkeys, get a [`BatchItemError`] instead of failing the whole batch.
 pub async fn get_asset_batch(req_params: GetAssetBatch, ctx: ArcedAppCtx) -> JsonRpcResponse {
-    let ids = req_params
+    let pubkeys = req_params
         .ids
         .iter()
         .map(|id| PublicKey::from_bs58(id).ok_or(DasApiError::PubkeyValidationError(id.to_string())))
-        .collect::<Result<Vec<PublicKey>, _>>()?;
+        .collect::<Vec<Result<PublicKey, DasApiError>>>();
+    let valid_pubkeys = pubkeys
+        .iter()
+        .filter_map(|pubkey| pubkey.as_ref().ok())
+        .copied()
+        .collect::<Vec<PublicKey>>();
 
     let id_to_l2_asset = ctx
         .asset_service
-        .fetch_assets(&ids)
+        .fetch_assets(&valid_pubkeys)
         .await
-        .map_err(|_| DasApiError::DatabaseError)?
-        .into_iter()
-        .map(|l2_asset| (l2_asset.asset.pubkey.to_string(), l2_asset))
-        .collect::<HashMap<String, L2AssetInfo>>();
+        .map(|l2_assets| {
+            l2_assets
+                .into_iter()
+                .map(|l2_asset| (l2_asset.asset.pubkey, l2_asset))
+                .collect::<HashMap<PublicKey, L2AssetInfo>>()
+        })
+        .map_err(|_| DasApiError::DatabaseError);
+
+    let not_l2_pubkeys = match &id_to_l2_asset {
+        Ok(id_to_l2_asset) => valid_pubkeys
+            .iter()
+            .filter(|pubkey| !id_to_l2_asset.contains_key(*pubkey))
+            .copied()
+            .collect::<Vec<PublicKey>>(),
+        Err(_) => vec![],
+    };
+    let mut id_to_minted_asset = fetch_minted_assets(&not_l2_pubkeys, &ctx).await;
 
     let mut res = Vec::with_capacity(req_params.ids.len());
 
-    for id in req_params.ids {
-        match id_to_l2_asset
-            .get(&id)
-            .cloned()
-            .map(|l2_asset| (l2_asset.asset, l2_asset.metadata))
-        {
-            Some((asset, metadata)) => {
-                let asset_extended_and_metadata = (
-                    AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&id)),
-                    serde_json::to_value(metadata).map_err(|_| DasApiError::JsonMetadataParsing)?,
-                );
-
-                res.push(Asset::from(asset_extended_and_metadata).into_json())
-            }
-            None => res.push(Asset::empty_json()),
-        }
+    for (id, pubkey) in req_params.ids.into_iter().zip(pubkeys) {
+        let item =
+            pubkey.and_then(|pubkey| match id_to_l2_asset.as_ref().map_err(|e| e.clone())?.get(&pubkey).cloned() {
+                Some(L2AssetInfo { asset, metadata, collection }) => {
+                    let asset_extended_and_metadata = (
+                        AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&id))
+                            .with_collection(collection.map(to_collection_extended)),
+                        serde_json::to_value(metadata).map_err(|_| DasApiError::JsonMetadataParsing)?,
+                    );
+
+                    Ok(Some(AssetBatchItem::Asset(Box::new(Asset::from(asset_extended_and_metadata)))))
+                }
+                None => match id_to_minted_asset
+                    .as_mut()
+                    .map_err(|e| e.clone())?
+                    .remove(&pubkey)
+                    .flatten()
+                {
+                    Some(minted_asset) => Ok(Some(AssetBatchItem::Asset(Box::new(minted_asset)))),
+                    None => Ok(None),
+                },
+            });
+
+        res.push(item.unwrap_or_else(|e| Some(AssetBatchItem::Error(BatchItemError { id, error: e.to_string() }))));
     }
 
     Ok(json!(res))
 }
 
+/// Returns the assets owned by the address according to the L2 ownership.
+/// Assets minted on L1 are served only while the address still owns them on L1, which makes the page shorter,
+/// and the assets transferred to the address on L1 are not returned at all, as they are not indexed on L2.
 pub async fn get_asset_by_owner(req_params: GetAssetsByOwner, ctx: ArcedAppCtx) -> JsonRpcResponse {
-    let sorting = req_params.sort_by.map(Into::into).unwrap_or_default();
+    let sorting: AssetSorting = req_params.sort_by.map(Into::into).unwrap_or_default();
     let limit = verify_limit(req_params.limit)?;
-    let before = req_params.before;
-    let after = req_params.after;
+    let before = verify_cursor(req_params.before, &sorting.sort_by)?;
+    let after = verify_cursor(req_params.after, &sorting.sort_by)?;
     let page = verify_page(req_params.page)?;
-    let cursor = req_params.cursor;
+    let cursor = verify_cursor(req_params.cursor, &sorting.sort_by)?;
 
     let is_cursor_enabled = before.is_none() && after.is_none() && page.is_none();
-    let after = is_cursor_enabled.then_some(cursor).unwrap_or(after);
+    let after = if is_cursor_enabled { cursor } else { after };
+    let pagination = AssetPagination { limit, page, before, after };
 
-    let l2_assets = ctx
+    let l2_assets_page = ctx
         .asset_service
-        .fetch_assets_by_owner(&req_params.owner_address, &sorting, limit, before.as_deref(), after.as_deref())
+        .fetch_assets_by_owner(&req_params.owner_address, &sorting, &pagination)
         .await
         .map_err(|_| DasApiError::DatabaseError)?;
 
-    Ok(json!(prepare_response(l2_assets, is_cursor_enabled, page, ctx.clone(), limit)?))
+    // Assets minted on L1 might have been transferred to someone else since the mint
+    let owner = Some(req_params.owner_address.as_str());
+
+    Ok(json!(
+        prepare_response(l2_assets_page, &sorting.sort_by, owner, is_cursor_enabled, page, ctx.clone(), limit).await?
+    ))
 }
 
 pub async fn get_asset_by_creator(req_params: GetAssetsByCreator, ctx: ArcedAppCtx) -> JsonRpcResponse {
-    let sorting = req_params.sort_by.map(Into::into).unwrap_or_default();
+    let sorting: AssetSorting = req_params.sort_by.map(Into::into).unwrap_or_default();
+    let limit = verify_limit(req_params.limit)?;
+    let before = verify_cursor(req_params.before, &sorting.sort_by)?;
+    let after = verify_cursor(req_params.after, &sorting.sort_by)?;
+    let page = verify_page(req_params.page)?;
+    let cursor = verify_cursor(req_params.cursor, &sorting.sort_by)?;
+
+    let is_cursor_enabled = before.is_none() && after.is_none() && page.is_none();
+    let after = if is_cursor_enabled { cursor } else { after };
+    let pagination = AssetPagination { limit, page, before, after };
+
+    let l2_assets_page = ctx
+        .asset_service
+        .fetch_assets_by_creator(&req_params.creator_address, &sorting, &pagination)
+        .await
+        .map_err(|_| DasApiError::DatabaseError)?;
+
+    Ok(json!(
+        prepare_response(l2_assets_page, &sorting.sort_by, None, is_cursor_enabled, page, ctx.clone(), limit).await?
+    ))
+}
+
+pub async fn get_asset_by_authority(req_params: GetAssetsByAuthority, ctx: ArcedAppCtx) -> JsonRpcResponse {
+    let sorting: AssetSorting = req_params.sort_by.map(Into::into).unwrap_or_default();
+    let limit = verify_limit(req_params.limit)?;
+    let before = verify_cursor(req_params.before, &sorting.sort_by)?;
+    let after = verify_cursor(req_params.after, &sorting.sort_by)?;
+    let page = verify_page(req_params.page)?;
+    let cursor = verify_cursor(req_params.cursor, &sorting.sort_by)?;
+
+    let is_cursor_enabled = before.is_none() && after.is_none() && page.is_none();
+    let after = if is_cursor_enabled { cursor } else { after };
+    let pagination = AssetPagination { limit, page, before, after };
+
+    let l2_assets_page = ctx
+        .asset_service
+        .fetch_assets_by_authority(&req_params.authority_address, &sorting, &pagination)
+        .await
+        .map_err(|_| DasApiError::DatabaseError)?;
+
+    Ok(json!(
+        prepare_response(l2_assets_page, &sorting.sort_by, None, is_cursor_enabled, page, ctx.clone(), limit).await?
+    ))
+}
+
+pub async fn get_asset_by_group(req_params: GetAssetsByGroup, ctx: ArcedAppCtx) -> JsonRpcResponse {
+    if req_params.group_key != COLLECTION_GROUP_KEY {
+        return Err(DasApiError::UnsupportedGrouping(req_params.group_key).into());
+    }
+    let collection = PublicKey::from_bs58(&req_params.group_value)
+        .ok_or(DasApiError::PubkeyValidationError(req_params.group_value.to_owned()))?;
+
+    let sorting: AssetSorting = req_params.sort_by.map(Into::into).unwrap_or_default();
     let limit = verify_limit(req_params.limit)?;
-    let before = req_params.before;
-    let after = req_params.after;
+    let before = verify_cursor(req_params.before, &sorting.sort_by)?;
+    let after = verify_cursor(req_params.after, &sorting.sort_by)?;
     let page = verify_page(req_params.page)?;
-    let cursor = req_params.cursor;
+    let cursor = verify_cursor(req_params.cursor, &sorting.sort_by)?;
 
     let is_cursor_enabled = before.is_none() && after.is_none() && page.is_none();
-    let after = is_cursor_enabled.then_some(cursor).unwrap_or(after);
+    let after = if is_cursor_enabled { cursor } else { after };
+    let pagination = AssetPagination { limit, page, before, after };
 
-    let l2_assets = ctx
+    let l2_assets_page = ctx
         .asset_service
-        .fetch_assets_by_creator(&req_params.creator_address, &sorting, limit, before.as_deref(), after.as_deref())
+        .fetch_assets_by_collection(&collection, &sorting, &pagination)
         .await
         .map_err(|_| DasApiError::DatabaseError)?;
 
-    Ok(json!(prepare_response(l2_assets, is_cursor_enabled, page, ctx.clone(), limit)?))
+    Ok(json!(
+        prepare_response(l2_assets_page, &sorting.sort_by, None, is_cursor_enabled, page, ctx.clone(), limit).await?
+    ))
 }
 
-fn prepare_response(
-    l2_assets: Vec<L2AssetInfo>,
+/// Makes DAS response out of the page of assets, where assets minted on L1 are replaced by their L1 view.
+/// Minted assets are omitted if they are unknown to the upstream DAS API, or are not owned by `owner` anymore.
+/// `total` is the number of all the assets matching the request, not only the ones on the page,
+/// less the minted assets omitted from the page. Omitted assets on the other pages are still counted.
+pub(crate) async fn prepare_response(
+    l2_assets_page: AssetPage<L2AssetInfo>,
+    sort_by: &AssetSortBy,
+    owner: Option<&str>,
     is_cursor_enabled: bool,
     page: Option<u32>,
     ctx: ArcedAppCtx,
     limit: u32,
 ) -> Result<AssetList, DasApiError> {
+    let l2_assets = l2_assets_page.items;
+    // Cursors carry the timestamp the assets are sorted by
+    let to_cursor = |asset_info: &L2AssetInfo| {
+        let timestamp = match sort_by {
+            AssetSortBy::Created => asset_info.asset.create_timestamp,
+            AssetSortBy::Updated => asset_info.asset.update_timestamp,
+        };
+        encode_asset_cursor(sort_by, timestamp, asset_info.asset.pubkey)
+    };
+
     let (before, after, cursor, page) = if is_cursor_enabled {
-        (
-            None,
-            None,
-            l2_assets.last().map(|asset_info| {
-                encode_timestamp_and_asset_pubkey(asset_info.asset.create_timestamp, asset_info.asset.pubkey)
-            }),
-            None,
-        )
+        (None, None, l2_assets.last().map(to_cursor), None)
     } else if let Some(page) = page {
         (None, None, None, Some(page))
     } else {
-        (
-            l2_assets.first().map(|asset_info| {
-                encode_timestamp_and_asset_pubkey(asset_info.asset.create_timestamp, asset_info.asset.pubkey)
-            }),
-            l2_assets.last().map(|asset_info| {
-                encode_timestamp_and_asset_pubkey(asset_info.asset.create_timestamp, asset_info.asset.pubkey)
-            }),
-            None,
-            None,
-        )
+        (l2_assets.first().map(to_cursor), l2_assets.last().map(to_cursor), None, None)
     };
 
+    let pubkeys = l2_assets
+        .iter()
+        .map(|asset_info| asset_info.asset.pubkey)
+        .collect::<Vec<_>>();
+    let mut pubkey_to_minted_asset = fetch_minted_assets(&pubkeys, &ctx).await?;
+
     let mut das_assets = Vec::with_capacity(l2_assets.len());
-    for (asset, metadata) in l2_assets.into_iter().map(|asset| (asset.asset, asset.metadata)) {
+    let mut omitted = 0;
+    for (asset, metadata, collection) in l2_assets
+        .into_iter()
+        .map(|asset| (asset.asset, asset.metadata, asset.collection))
+    {
+        if let Some(minted_asset) = pubkey_to_minted_asset.remove(&asset.pubkey) {
+            match minted_asset.filter(|minted_asset| owner.is_none_or(|owner| minted_asset.ownership.owner == owner)) {
+                Some(minted_asset) => das_assets.push(minted_asset),
+                None => omitted += 1,
+            }
+            continue;
+        }
+
         let asset_pubkey = asset.pubkey.to_string();
         let asset_extended_and_metadata = (
-            AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&asset_pubkey)),
+            AssetExtended::new(asset, ctx.metadata_uri_base.get_metadata_uri_for_key(&asset_pubkey))
+                .with_collection(collection.map(to_collection_extended)),
             serde_json::to_value(metadata).map_err(|_| DasApiError::JsonMetadataParsing)?,
         );
 
@@ -154,7 +271,7 @@ fn prepare_response(
     }
 
     Ok(AssetList {
-        total: das_assets.len() as u32,
+        total: (l2_assets_page.total as u32).saturating_sub(omitted),
         limit,
         page,
         before,
@@ -165,7 +282,36 @@ fn prepare_response(
     })
 }
 
-fn verify_limit(limit: Option<u32>) -> Result<u32, DasApiError> {
+/// Fetches the assets minted on L1 from the upstream DAS API.
+/// Returns an entry for every asset minted on L1 among `asset_pubkeys`,
+/// which is `None` if the asset is unknown to the upstream DAS API, or the upstream is not configured.
+async fn fetch_minted_assets(
+    asset_pubkeys: &[PublicKey],
+    ctx: &ArcedAppCtx,
+) -> Result<HashMap<PublicKey, Option<Asset>>, DasApiError> {
+    if asset_pubkeys.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let minted_pubkeys = ctx
+        .asset_service
+        .fetch_minted_asset_pubkeys(asset_pubkeys)
+        .await
+        .map_err(|_| DasApiError::DatabaseError)?;
+
+    let minted_assets = match &ctx.l1_asset_provider {
+        Some(l1_asset_provider) if !minted_pubkeys.is_empty() => l1_asset_provider
+            .get_assets(&minted_pubkeys)
+            .await
+            .inspect_err(|e| error!("Failed to fetch minted assets from upstream DAS: {e:#}"))
+            .map_err(|_| DasApiError::UpstreamDasError)?,
+        _ => vec![None; minted_pubkeys.len()],
+    };
+
+    Ok(minted_pubkeys.into_iter().zip(minted_assets).collect())
+}
+
+pub(crate) fn verify_limit(limit: Option<u32>) -> Result<u32, DasApiError> {
     match limit {
         Some(limit) => (limit < DEFAULT_LIMIT_FOR_PAGE)
             .then_some(limit)
@@ -174,7 +320,19 @@ fn verify_limit(limit: Option<u32>) -> Result<u32, DasApiError> {
     }
 }
 
-fn verify_page(limit: Option<u32>) -> Result<Option<u32>, DasApiError> {
+/// Checks that the cursor was made for the requested sorting, so it points to the right position in the list.
+pub(crate) fn verify_cursor(cursor: Option<String>, sort_by: &AssetSortBy) -> Result<Option<String>, DasApiError> {
+    let Some(cursor) = cursor else {
+        return Ok(None);
+    };
+
+    match decode_asset_cursor(&cursor) {
+        Ok((cursor_sort_by, _, _)) if cursor_sort_by == *sort_by => Ok(Some(cursor)),
+        _ => Err(DasApiError::CursorValidationError(cursor)),
+    }
+}
+
+pub(crate) fn verify_page(limit: Option<u32>) -> Result<Option<u32>, DasApiError> {
     match limit {
         Some(limit) => {
             if limit < DEFAULT_MAX_PAGE_LIMIT {
@@ -1,7 +1,11 @@
 pub mod errors;
 pub mod get_asset;
 pub mod health_check;
+pub mod search_assets;
 pub mod types;
 
 pub const DEFAULT_LIMIT_FOR_PAGE: u32 = 1000;
 pub const DEFAULT_MAX_PAGE_LIMIT: u32 = 50;
+
+/// The only DAS grouping key supported, since L2 assets can be grouped by collection only
+pub const COLLECTION_GROUP_KEY: &str = "collection";
@@ -0,0 +1,64 @@
+use crate::endpoints::errors::DasApiError;
+use crate::endpoints::get_asset::{prepare_response, verify_cursor, verify_limit, verify_page};
+use crate::endpoints::types::{JsonRpcResponse, SearchAssets, SearchConditionType};
+use crate::endpoints::COLLECTION_GROUP_KEY;
+use crate::setup::app_context::ArcedAppCtx;
+use entities::l2::{
+    AssetPagination, AssetSearchQuery, AssetSorting, PublicKey, SearchConditionType as L2SearchConditionType,
+};
+use serde_json::json;
+use util::publickey::PublicKeyExt;
+
+pub async fn search_assets(req_params: SearchAssets, ctx: ArcedAppCtx) -> JsonRpcResponse {
+    let sorting: AssetSorting = req_params.sort_by.clone().map(Into::into).unwrap_or_default();
+    let limit = verify_limit(req_params.limit)?;
+    let page = verify_page(req_params.page)?;
+    let query = to_search_query(&req_params)?;
+    let before = verify_cursor(req_params.before, &sorting.sort_by)?;
+    let after = verify_cursor(req_params.after, &sorting.sort_by)?;
+    let cursor = verify_cursor(req_params.cursor, &sorting.sort_by)?;
+
+    let is_cursor_enabled = before.is_none() && after.is_none() && page.is_none();
+    let after = if is_cursor_enabled { cursor } else { after };
+    let pagination = AssetPagination { limit, page, before, after };
+
+    let l2_assets_page = ctx
+        .asset_service
+        .search_assets(&query, &sorting, &pagination)
+        .await
+        .map_err(|_| DasApiError::DatabaseError)?;
+
+    // Assets minted on L1 might have been transferred to someone else since the mint
+    let owner = req_params
+        .owner_address
+        .as_deref()
+        .filter(|_| query.condition_type == L2SearchConditionType::All);
+
+    Ok(json!(
+        prepare_response(l2_assets_page, &sorting.sort_by, owner, is_cursor_enabled, page, ctx.clone(), limit).await?
+    ))
+}
+
+fn to_search_query(req_params: &SearchAssets) -> Result<AssetSearchQuery, DasApiError> {
+    let collection = match &req_params.grouping {
+        None => None,
+        Some((key, value)) if key == COLLECTION_GROUP_KEY => {
+            Some(PublicKey::from_bs58(value).ok_or(DasApiError::PubkeyValidationError(value.to_string()))?)
+        }
+        Some((key, _)) => return Err(DasApiError::UnsupportedGrouping(key.to_string())),
+    };
+
+    Ok(AssetSearchQuery {
+        condition_type: match req_params.condition_type {
+            None | Some(SearchConditionType::All) => L2SearchConditionType::All,
+            Some(SearchConditionType::Any) => L2SearchConditionType::Any,
+        },
+        owner: req_params.owner_address.clone(),
+        creator: req_params.creator_address.clone(),
+        authority: req_params.authority_address.clone(),
+        collection,
+        name: req_params.name.clone(),
+        burnt: req_params.burnt,
+        state: req_params.asset_state.clone(),
+    })
+}
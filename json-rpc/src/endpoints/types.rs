@@ -1,34 +1,50 @@
-use entities::dto::Asset;
+use entities::dto::{Asset, AssetMintStatus};
 use entities::l2::{
     AssetSortBy as L2AssetSortBy, AssetSortDirection as L2AssetSortDirection, AssetSorting as L2AssetSorting,
 };
 use jsonrpc_core::Error;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
 pub type JsonRpcError = Error;
 pub type JsonRpcResponse = Result<JsonValue, JsonRpcError>;
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct GetAsset {
     pub id: String,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct GetAssetBatch {
     pub ids: Vec<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Returned in place of a batch item, that couldn't be fetched
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct BatchItemError {
+    pub id: String,
+    pub error: String,
+}
+
+/// Item of the `getAssetBatch` response, `null` if the asset is not found
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum AssetBatchItem {
+    Asset(Box<Asset>),
+    Error(BatchItemError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct AssetSorting {
     pub sort_by: AssetSortBy,
     pub sort_direction: Option<AssetSortDirection>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum AssetSortDirection {
     Asc,
@@ -36,7 +52,7 @@ pub enum AssetSortDirection {
     Desc,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum AssetSortBy {
     #[default]
@@ -64,7 +80,8 @@ impl Into<L2AssetSorting> for AssetSorting {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// Assets transferred to the owner on L1 are not returned, as only the L2 ownership is indexed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct GetAssetsByOwner {
     pub owner_address: String,
@@ -76,7 +93,7 @@ pub struct GetAssetsByOwner {
     pub cursor: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase", default)]
 pub struct AssetList {
     pub total: u32,
@@ -94,7 +111,7 @@ pub struct AssetList {
     pub cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct GetAssetsByCreator {
     pub creator_address: String,
@@ -106,3 +123,58 @@ pub struct GetAssetsByCreator {
     pub after: Option<String>,
     pub cursor: Option<String>,
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct GetAssetsByAuthority {
+    pub authority_address: String,
+    pub sort_by: Option<AssetSorting>,
+    pub limit: Option<u32>,
+    pub page: Option<u32>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct GetAssetsByGroup {
+    /// Only `collection` is supported
+    pub group_key: String,
+    pub group_value: String,
+    pub sort_by: Option<AssetSorting>,
+    pub limit: Option<u32>,
+    pub page: Option<u32>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchConditionType {
+    All,
+    Any,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SearchAssets {
+    /// Whether an asset has to meet all the conditions (default), or any of them
+    pub condition_type: Option<SearchConditionType>,
+    pub owner_address: Option<String>,
+    pub creator_address: Option<String>,
+    pub authority_address: Option<String>,
+    /// Only `["collection", "<collection pubkey>"]` grouping is supported
+    pub grouping: Option<(String, String)>,
+    /// Case insensitive substring of the asset name
+    pub name: Option<String>,
+    pub burnt: Option<bool>,
+    pub asset_state: Option<AssetMintStatus>,
+    pub sort_by: Option<AssetSorting>,
+    pub limit: Option<u32>,
+    pub page: Option<u32>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub cursor: Option<String>,
+}
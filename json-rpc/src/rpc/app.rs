@@ -1,4 +1,5 @@
 use crate::setup::app_setup::AppSetup;
+use crate::setup::request_throttler::RequestThrottler;
 use jsonrpc_http_server::ServerBuilder;
 use std::io::Result;
 use tracing::{error, info};
@@ -6,11 +7,14 @@ use tracing::{error, info};
 pub async fn start_up_json_rpc(app_config: AppSetup) -> Result<()> {
     let bind_address = app_config.socket_addr();
     let handler = app_config.register_rpc_methods().await;
+    let request_throttler = RequestThrottler::new(app_config.settings.rate_limits.clone())
+        .inspect_err(|e| error!("Failed to init request throttling: {e}."))?;
 
     info!("Starting Json RPC using: '{bind_address}'.");
 
     ServerBuilder::new(handler)
         .health_api(("/health", "health"))
+        .request_middleware(request_throttler)
         .start_http(&bind_address)
         .inspect_err(|e| error!("Failed to start http: {e}."))?
         .wait();
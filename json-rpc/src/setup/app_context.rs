@@ -2,7 +2,10 @@ use crate::setup::app_setup::AppSetup;
 use crate::setup::types::MetadataUriCreator;
 
 use interfaces::asset_service::AssetService;
+use interfaces::l1_asset_provider::L1AssetProvider;
 use service::asset_service_impl::AssetServiceImpl;
+use service::mint_confirmation_worker::MintConfirmationWorker;
+use solana_integration::das_client::DasClient;
 use solana_integration::l1_service_solana::SolanaService;
 
 use sqlx::{
@@ -10,6 +13,7 @@ use sqlx::{
     ConnectOptions, PgPool,
 };
 use std::sync::Arc;
+use std::time::Duration;
 use storage::asset_storage_s3::S3Storage;
 use storage::l2_storage_pg::L2StoragePg;
 use tracing::log::LevelFilter;
@@ -22,6 +26,8 @@ pub type ArcedAppCtx = Arc<AppCtx>;
 pub struct AppCtx {
     pub asset_service: Arc<dyn AssetService + Sync + Send>,
     pub metadata_uri_base: MetadataUriCreator,
+    /// Serves the assets minted on L1, which are omitted from responses if it is not configured
+    pub l1_asset_provider: Option<Arc<dyn L1AssetProvider + Sync + Send>>,
 }
 
 impl AppCtx {
@@ -70,10 +76,14 @@ impl AppCtx {
             wallet_producer,
             derivation_sequence: l2_storage.clone(),
             l2_storage: l2_storage.clone(),
+            collection_storage: l2_storage.clone(),
             asset_metadata_storage: s3_storage.clone(),
             blob_storage: s3_storage.clone(),
-            l1_service: solana_service,
+            l1_service: solana_service.clone(),
+            webhook_storage: l2_storage.clone(),
+            mint_job_storage: l2_storage.clone(),
             metadata_server_base_url: app_config.settings.rest_server.base_url.clone(),
+            max_json_metadata_size: app_config.settings.obj_storage.max_json_metadata_size,
         });
 
         asset_service
@@ -81,7 +91,17 @@ impl AppCtx {
             .await
             .unwrap_or_else(|e| error!("Failed to start 'process_minting_assets'; Cause: {e}."));
 
-        Self { asset_service, metadata_uri_base }
+        MintConfirmationWorker::new(solana_service, l2_storage, app_config.settings.mint_confirmation.clone()).start();
+
+        let l1_asset_provider = app_config.settings.das_fallback.url.as_ref().map(|url| {
+            info!("Using upstream DAS API for minted assets: '{url}'");
+            let timeout = Duration::from_secs(app_config.settings.das_fallback.request_timeout_secs);
+            let das_client =
+                DasClient::new(url, timeout).unwrap_or_else(|e| panic!("Failed to init 'DasClient' cause: {e}"));
+            Arc::new(das_client) as Arc<dyn L1AssetProvider + Sync + Send>
+        });
+
+        Self { asset_service, metadata_uri_base, l1_asset_provider }
     }
 
     async fn create_connection_pool(db_url: &str, max_size_pool: u32, min_size_pool: u32) -> PgPool {
@@ -1,8 +1,13 @@
-use crate::endpoints::get_asset::{get_asset, get_asset_batch, get_asset_by_creator, get_asset_by_owner};
+use crate::endpoints::get_asset::{
+    get_asset, get_asset_batch, get_asset_by_authority, get_asset_by_creator, get_asset_by_group, get_asset_by_owner,
+};
 use crate::endpoints::health_check::health;
+use crate::endpoints::search_assets::search_assets;
+use crate::endpoints::types::{AssetBatchItem, AssetList};
 use crate::setup::app_context::AppCtx;
-use crate::setup::method_registrar::RpcMethodRegistrar;
-use jsonrpc_core::IoHandler;
+use crate::setup::batch_limiter::BatchLimiter;
+use crate::setup::method_registrar::{RpcHandler, RpcMethodRegistrar};
+use entities::dto::Asset;
 use std::net::SocketAddr;
 use util::config::Settings;
 
@@ -16,17 +21,36 @@ impl AppSetup {
         Self { settings }
     }
 
-    pub async fn register_rpc_methods(&self) -> IoHandler {
-        RpcMethodRegistrar::using_ctx(AppCtx::new(self).await.arced())
+    pub async fn register_rpc_methods(&self) -> RpcHandler {
+        let batch_limiter = BatchLimiter::new(
+            self.settings.json_rpc_server.max_batch_size,
+            self.settings.json_rpc_server.max_batch_concurrency,
+        );
+
+        RpcMethodRegistrar::using_ctx(AppCtx::new(self).await.arced(), batch_limiter)
             .method_without_ctx_and_params(health)
+            .returning::<String>()
             .method(get_asset)
+            .returning::<Asset>()
             .method(get_asset_batch)
+            .returning::<Vec<Option<AssetBatchItem>>>()
             .method(get_asset_by_owner)
+            .returning::<AssetList>()
             .method(get_asset_by_creator)
+            .returning::<AssetList>()
+            .method(get_asset_by_authority)
+            .returning::<AssetList>()
+            .method(get_asset_by_group)
+            .returning::<AssetList>()
+            .method(search_assets)
+            .returning::<AssetList>()
             .add_alias("getAsset", "get_asset")
             .add_alias("getAssetBatch", "get_asset_batch")
             .add_alias("getAssetByOwner", "get_asset_by_owner")
             .add_alias("getAssetByCreator", "get_asset_by_creator")
+            .add_alias("getAssetsByAuthority", "get_asset_by_authority")
+            .add_alias("getAssetsByGroup", "get_asset_by_group")
+            .add_alias("searchAssets", "search_assets")
             .finish()
     }
 
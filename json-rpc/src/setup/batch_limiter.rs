@@ -0,0 +1,113 @@
+use jsonrpc_core::futures_util::future::Either;
+use jsonrpc_core::futures_util::{stream, StreamExt};
+use jsonrpc_core::{Error, ErrorCode, Metadata, Middleware, Output, Request, Response, Version};
+use std::future::Future;
+use std::pin::Pin;
+
+pub type BatchLimiterFuture = Pin<Box<dyn Future<Output = Option<Response>> + Send>>;
+pub type BatchLimiterCallFuture = Pin<Box<dyn Future<Output = Option<Output>> + Send>>;
+
+/// Middleware that rejects batch requests with too many calls,
+/// and processes the calls of a batch with bounded concurrency, keeping their order in the response.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchLimiter {
+    max_batch_size: usize,
+    max_concurrency: usize,
+}
+
+impl BatchLimiter {
+    pub fn new(max_batch_size: usize, max_concurrency: usize) -> Self {
+        Self { max_batch_size, max_concurrency: max_concurrency.max(1) }
+    }
+}
+
+impl<M: Metadata> Middleware<M> for BatchLimiter {
+    type Future = BatchLimiterFuture;
+    type CallFuture = BatchLimiterCallFuture;
+
+    fn on_request<F, X>(&self, request: Request, meta: M, next: F) -> Either<Self::Future, X>
+    where
+        F: Fn(Request, M) -> X + Send + Sync,
+        X: Future<Output = Option<Response>> + Send + 'static,
+    {
+        let calls = match request {
+            Request::Batch(calls) if calls.len() > self.max_batch_size => {
+                let error = Error {
+                    code: ErrorCode::InvalidRequest,
+                    message: format!("Batch is too large. Up to '{}' calls are supported.", self.max_batch_size),
+                    data: None,
+                };
+                return Either::Left(Box::pin(async move { Some(Response::from(error, Some(Version::V2))) }));
+            }
+            // Empty batch is rejected by the handler itself
+            Request::Batch(calls) if !calls.is_empty() => calls,
+            request => return Either::Right(next(request, meta)),
+        };
+
+        // Futures do nothing until polled, so only `max_concurrency` calls are in progress at a time
+        let responses = calls
+            .into_iter()
+            .map(|call| next(Request::Single(call), meta.clone()))
+            .collect::<Vec<X>>();
+        let max_concurrency = self.max_concurrency;
+
+        Either::Left(Box::pin(async move {
+            let outputs = stream::iter(responses)
+                .buffered(max_concurrency)
+                .filter_map(|response| async move {
+                    match response {
+                        Some(Response::Single(output)) => Some(output),
+                        _ => None,
+                    }
+                })
+                .collect::<Vec<Output>>()
+                .await;
+
+            // Batch of notifications gets no response
+            (!outputs.is_empty()).then_some(Response::Batch(outputs))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::setup::batch_limiter::BatchLimiter;
+    use jsonrpc_core::{MetaIoHandler, Params, Value};
+
+    fn handler(max_batch_size: usize) -> MetaIoHandler<(), BatchLimiter> {
+        let mut handler = MetaIoHandler::with_middleware(BatchLimiter::new(max_batch_size, 2));
+        handler.add_method("echo", |params: Params| async move { params.parse::<Value>() });
+        handler
+    }
+
+    #[tokio::test]
+    async fn test_batch_is_processed_in_order() {
+        let request = r#"[
+            {"jsonrpc": "2.0", "method": "echo", "params": [1], "id": 1},
+            {"jsonrpc": "2.0", "method": "unknown", "id": 2},
+            {"jsonrpc": "2.0", "method": "echo", "params": [3], "id": 3}
+        ]"#;
+
+        let response = handler(3).handle_request(request, ()).await.unwrap();
+
+        assert_eq!(
+            response,
+            r#"[{"jsonrpc":"2.0","result":[1],"id":1},{"jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"},"id":2},{"jsonrpc":"2.0","result":[3],"id":3}]"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_too_large_batch_is_rejected() {
+        let request = r#"[
+            {"jsonrpc": "2.0", "method": "echo", "params": [1], "id": 1},
+            {"jsonrpc": "2.0", "method": "echo", "params": [2], "id": 2}
+        ]"#;
+
+        let response = handler(1).handle_request(request, ()).await.unwrap();
+
+        assert_eq!(
+            response,
+            r#"{"jsonrpc":"2.0","error":{"code":-32600,"message":"Batch is too large. Up to '1' calls are supported."},"id":null}"#
+        );
+    }
+}
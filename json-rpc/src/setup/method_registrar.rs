@@ -1,6 +1,9 @@
 use crate::endpoints::types::JsonRpcResponse;
 use crate::setup::app_context::ArcedAppCtx;
-use jsonrpc_core::{IoHandler, Params};
+use crate::setup::batch_limiter::BatchLimiter;
+use crate::setup::open_rpc::OpenRpcCollector;
+use jsonrpc_core::{MetaIoHandler, Params};
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use std::any::type_name;
 use std::future::Future;
@@ -17,8 +20,14 @@ use tracing::info;
 ///  they must be passed in a strict order, with the parameter coming first and the context second.
 /// 4) Also, all methods must return a `JsonRpcResponse` type.
 ///
+/// All the methods share the same application context, including the calls of batch requests,
+/// which are limited by the [`BatchLimiter`].
+///
 /// For examples of such methods implementation, refer to the `src/endpoints` folder.
 ///
+/// Schemas of the params and the results of the registered methods are collected into an OpenRPC document,
+/// which is served by the `rpc.discover` method. Results are described with [`RpcMethodRegistrar::returning`].
+///
 /// Usage example:
 /// ```ignore
 /// #[derive(Serialize, Deserialize)]
@@ -44,8 +53,9 @@ use tracing::info;
 /// async fn main() {
 ///     let app_ctx = AppCtx::new(/* args */).await.arced();
 ///
-///     let io_handler = RpcMethodRegistrar::using_ctx(app_ctx.clone())
+///     let io_handler = RpcMethodRegistrar::using_ctx(app_ctx.clone(), BatchLimiter::new(100, 10))
 ///         .method(get_user)
+///         .returning::<User>()
 ///         .method_without_params(get_random_number)
 ///         .method_without_ctx_and_params(get_status)
 ///         .add_alias("getUser", "get_user")
@@ -60,18 +70,29 @@ use tracing::info;
 ///         .wait();
 /// }
 /// ```
+pub type RpcHandler = MetaIoHandler<(), BatchLimiter>;
+
+/// Method serving the OpenRPC document, as required by the OpenRPC specification
+const OPEN_RPC_DISCOVER_METHOD: &str = "rpc.discover";
+
 #[derive(Clone)]
 pub struct RpcMethodRegistrar {
-    handler: IoHandler,
+    handler: RpcHandler,
     ctx: ArcedAppCtx,
+    open_rpc: OpenRpcCollector,
 }
 
 #[allow(dead_code)]
 impl RpcMethodRegistrar {
-    /// Creates a new instance of `RpcMethodRegistrar` using the provided application context (State).
-    pub fn using_ctx(ctx: ArcedAppCtx) -> Self {
+    /// Creates a new instance of `RpcMethodRegistrar` using the provided application context (State),
+    /// batch requests are limited by the `batch_limiter`.
+    pub fn using_ctx(ctx: ArcedAppCtx, batch_limiter: BatchLimiter) -> Self {
         info!("Registration of RPC methods has started.");
-        Self { handler: IoHandler::new(), ctx }
+        Self {
+            handler: MetaIoHandler::with_middleware(batch_limiter),
+            ctx,
+            open_rpc: OpenRpcCollector::default(),
+        }
     }
 
     /// Registers a method that takes no parameters other than the application context.
@@ -91,6 +112,7 @@ impl RpcMethodRegistrar {
         };
 
         self.handler.add_method(&method_name, closure);
+        self.open_rpc.add_method_without_params(&method_name);
 
         info!("Added method: '{method_name}'.");
 
@@ -110,6 +132,7 @@ impl RpcMethodRegistrar {
         };
 
         self.handler.add_method(&method_name, closure);
+        self.open_rpc.add_method_without_params(&method_name);
 
         info!("Added method: '{method_name}'.");
 
@@ -123,7 +146,7 @@ impl RpcMethodRegistrar {
     ) -> Self
     where
         FunctionResult: Future<Output = JsonRpcResponse> + Sized + Send + 'static,
-        RequestParam: DeserializeOwned + Send + 'static,
+        RequestParam: DeserializeOwned + JsonSchema + Send + 'static,
     {
         let method_name = Self::get_endpoint_name(&endpoint);
         let cloned_ctx = self.ctx.clone();
@@ -134,21 +157,40 @@ impl RpcMethodRegistrar {
         };
 
         self.handler.add_method(&method_name, closure);
+        self.open_rpc.add_method::<RequestParam>(&method_name);
 
         info!("Added method: '{method_name}'.");
 
         self
     }
 
+    /// Describes the result of the last registered method in the OpenRPC document.
+    pub fn returning<FunctionResult: JsonSchema>(mut self) -> Self {
+        self.open_rpc.set_result::<FunctionResult>();
+        self
+    }
+
     /// Adds an alias for a registered method.
     pub fn add_alias(mut self, alias: &str, for_method: &str) -> Self {
         info!("Adding alias '{alias}' for method '{for_method}'.");
         self.handler.add_alias(alias, for_method);
+        self.open_rpc.add_alias(alias, for_method);
         self
     }
 
-    /// Completes the registration of methods and returns the handler containing all registered methods.
-    pub fn finish(self) -> IoHandler {
+    /// Completes the registration of methods and returns the handler containing all registered methods,
+    /// together with the `rpc.discover` method serving their OpenRPC document.
+    pub fn finish(mut self) -> RpcHandler {
+        let open_rpc_document = self.open_rpc.document();
+        self.handler
+            .add_method(OPEN_RPC_DISCOVER_METHOD, move |_params: Params| {
+                let open_rpc_document = open_rpc_document.clone();
+                async move {
+                    _params.expect_no_params()?;
+                    Ok(open_rpc_document)
+                }
+            });
+
         info!("Registration of RPC methods has ended.");
         self.handler
     }
@@ -174,7 +216,7 @@ impl RpcMethodRegistrar {
     ) -> fn(RequestParam, ArcedAppCtx) -> FunctionResult
     where
         FunctionResult: Future<Output = JsonRpcResponse> + Sized + Send + 'static,
-        RequestParam: DeserializeOwned + Send + 'static,
+        RequestParam: DeserializeOwned + JsonSchema + Send + 'static,
     {
         endpoint
     }
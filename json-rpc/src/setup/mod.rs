@@ -1,4 +1,7 @@
 pub mod app_context;
 pub mod app_setup;
+mod batch_limiter;
 mod method_registrar;
+mod open_rpc;
+pub mod request_throttler;
 mod types;
@@ -0,0 +1,153 @@
+use schemars::gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::Schema;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+use std::collections::BTreeMap;
+
+const OPEN_RPC_VERSION: &str = "1.2.6";
+
+/// Collects the schemas of the registered methods into an [OpenRPC](https://spec.open-rpc.org) document.
+/// Schemas of the nested types are shared between methods, and are placed into `components.schemas`.
+#[derive(Clone)]
+pub struct OpenRpcCollector {
+    generator: SchemaGenerator,
+    methods: Vec<MethodObject>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MethodObject {
+    name: String,
+    params: Vec<ContentDescriptor>,
+    result: ContentDescriptor,
+    param_structure: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ContentDescriptor {
+    name: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    required: bool,
+    schema: Schema,
+}
+
+impl Default for OpenRpcCollector {
+    fn default() -> Self {
+        let mut settings = SchemaSettings::draft07();
+        settings.definitions_path = "#/components/schemas/".to_string();
+
+        Self { generator: settings.into_generator(), methods: Vec::new() }
+    }
+}
+
+impl OpenRpcCollector {
+    /// Adds the method, which params are the fields of `RequestParam`, passed by name.
+    pub fn add_method<RequestParam: JsonSchema>(&mut self, name: &str) {
+        let root_schema = self.generator.root_schema_for::<RequestParam>();
+        let params = root_schema
+            .schema
+            .object
+            .map(|object| {
+                object
+                    .properties
+                    .into_iter()
+                    .map(|(name, schema)| ContentDescriptor { required: object.required.contains(&name), name, schema })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.push_method(name, params);
+    }
+
+    pub fn add_method_without_params(&mut self, name: &str) {
+        self.push_method(name, Vec::new());
+    }
+
+    /// Sets the result of the last added method.
+    pub fn set_result<FunctionResult: JsonSchema>(&mut self) {
+        let schema = self.generator.subschema_for::<FunctionResult>();
+        if let Some(method) = self.methods.last_mut() {
+            method.result.schema = schema;
+        }
+    }
+
+    /// Adds the alias as a separate method, with the same params and result as the aliased method.
+    pub fn add_alias(&mut self, alias: &str, for_method: &str) {
+        if let Some(method) = self.methods.iter().find(|method| method.name == for_method) {
+            let aliased_method = MethodObject { name: alias.to_string(), ..method.clone() };
+            self.methods.push(aliased_method);
+        }
+    }
+
+    pub fn document(&self) -> JsonValue {
+        let schemas = self
+            .generator
+            .definitions()
+            .iter()
+            .map(|(name, schema)| (name.clone(), schema.clone()))
+            .collect::<BTreeMap<String, Schema>>();
+
+        json!({
+            "openrpc": OPEN_RPC_VERSION,
+            "info": {
+                "title": "Spell JSON-RPC API",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "methods": self.methods,
+            "components": {
+                "schemas": schemas,
+            },
+        })
+    }
+
+    fn push_method(&mut self, name: &str, params: Vec<ContentDescriptor>) {
+        self.methods.push(MethodObject {
+            name: name.to_string(),
+            params,
+            // Any JSON value, until the result is set
+            result: ContentDescriptor { name: "result".to_string(), required: false, schema: Schema::Bool(true) },
+            param_structure: "by-name",
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::endpoints::types::{AssetList, GetAssetsByOwner};
+    use crate::setup::open_rpc::OpenRpcCollector;
+
+    #[test]
+    fn test_open_rpc_document() {
+        let mut collector = OpenRpcCollector::default();
+        collector.add_method::<GetAssetsByOwner>("get_asset_by_owner");
+        collector.set_result::<AssetList>();
+        collector.add_method_without_params("health");
+        collector.add_alias("getAssetsByOwner", "get_asset_by_owner");
+
+        let document = collector.document();
+
+        let methods = document["methods"].as_array().unwrap();
+        let method_names = methods
+            .iter()
+            .map(|method| method["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(method_names, vec!["get_asset_by_owner", "health", "getAssetsByOwner"]);
+
+        assert_eq!(methods[2]["params"], methods[0]["params"]);
+        assert_eq!(methods[2]["result"], methods[0]["result"]);
+
+        let owner_param = methods[0]["params"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|param| param["name"] == "ownerAddress")
+            .unwrap();
+        assert_eq!(owner_param["required"], true);
+        assert_eq!(owner_param["schema"]["type"], "string");
+
+        assert_eq!(methods[0]["result"]["schema"]["$ref"], "#/components/schemas/AssetList");
+        assert!(document["components"]["schemas"]["AssetList"].is_object());
+        assert!(document["components"]["schemas"]["AssetSorting"].is_object());
+    }
+}
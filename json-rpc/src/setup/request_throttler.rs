@@ -0,0 +1,138 @@
+use jsonrpc_http_server::hyper::header::{HeaderValue, CONTENT_TYPE, RETRY_AFTER};
+use jsonrpc_http_server::hyper::{Body, Request, Response, StatusCode};
+use jsonrpc_http_server::{RequestMiddleware, RequestMiddlewareAction};
+use util::config::RateLimitsCfg;
+use util::rate_limiter::{client_ip, retry_after_secs, RateLimiter};
+
+const HEALTH_PATH: &str = "/health";
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// HTTP middleware that throttles the clients with the anonymous rate limit.
+/// The HTTP server doesn't expose the peer address, so the client is identified by the `X-Forwarded-For` header
+/// of the reverse proxy, the server has to be deployed behind, see [`client_ip`] for which of the entries is taken.
+/// The server must only be reachable via the proxy, and the proxy must be listed in `trusted_proxies`,
+/// otherwise the clients are able to pick the IP they are throttled by.
+pub struct RequestThrottler {
+    cfg: RateLimitsCfg,
+    rate_limiter: RateLimiter,
+}
+
+impl RequestThrottler {
+    /// Fails if the throttling is enabled, but no proxy is trusted to identify the clients
+    pub fn new(cfg: RateLimitsCfg) -> std::io::Result<Self> {
+        if cfg.enabled && cfg.trusted_proxies.is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "'rate_limits.trusted_proxies' must be set when the rate limits are enabled",
+            ));
+        }
+
+        Ok(Self { cfg, rate_limiter: RateLimiter::default() })
+    }
+
+    fn client_ip(&self, request: &Request<Body>) -> String {
+        let forwarded_for = request
+            .headers()
+            .get(FORWARDED_FOR_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        client_ip(None, forwarded_for, &self.cfg.trusted_proxies)
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+impl RequestMiddleware for RequestThrottler {
+    fn on_request(&self, request: Request<Body>) -> RequestMiddlewareAction {
+        if !self.cfg.enabled || request.uri().path() == HEALTH_PATH {
+            return RequestMiddlewareAction::Proceed { should_continue_on_invalid_cors: false, request };
+        }
+
+        let key = format!("ip:{}", self.client_ip(&request));
+        let Err(retry_after) = self.rate_limiter.try_acquire(&key, self.cfg.anonymous) else {
+            return RequestMiddlewareAction::Proceed { should_continue_on_invalid_cors: false, request };
+        };
+
+        let mut response = Response::new(Body::from(
+            r#"{"jsonrpc":"2.0","error":{"code":-32005,"message":"Too many requests"},"id":null}"#,
+        ));
+        *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json; charset=utf-8"));
+        response
+            .headers_mut()
+            .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs(retry_after)));
+
+        RequestMiddlewareAction::Respond {
+            should_validate_hosts: true,
+            response: Box::pin(async move { Ok(response) }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::setup::request_throttler::RequestThrottler;
+    use jsonrpc_http_server::hyper::header::RETRY_AFTER;
+    use jsonrpc_http_server::hyper::{Body, Request, StatusCode};
+    use jsonrpc_http_server::{RequestMiddleware, RequestMiddlewareAction};
+    use util::config::RateLimitsCfg;
+    use util::rate_limiter::RateLimit;
+
+    fn request(path: &str, client_ip: &str) -> Request<Body> {
+        Request::post(path)
+            .header("x-forwarded-for", format!("{client_ip}, 10.0.0.1"))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_clients_are_throttled_separately() {
+        let throttler = RequestThrottler::new(RateLimitsCfg {
+            enabled: true,
+            anonymous: RateLimit { requests_per_second: 0.5, burst: 1 },
+            trusted_proxies: vec!["10.0.0.1".parse().unwrap()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(matches!(throttler.on_request(request("/", "1.1.1.1")), RequestMiddlewareAction::Proceed { .. }));
+        assert!(matches!(throttler.on_request(request("/", "2.2.2.2")), RequestMiddlewareAction::Proceed { .. }));
+        assert!(matches!(
+            throttler.on_request(request("/health", "1.1.1.1")),
+            RequestMiddlewareAction::Proceed { .. }
+        ));
+
+        let RequestMiddlewareAction::Respond { response, .. } = throttler.on_request(request("/", "1.1.1.1")) else {
+            panic!("Request is expected to be throttled");
+        };
+        let response = response.await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "2");
+
+        // the entries prepended by the client are ignored
+        assert!(matches!(
+            throttler.on_request(request("/", "3.3.3.3, 1.1.1.1")),
+            RequestMiddlewareAction::Respond { .. }
+        ));
+
+        // the header set by the client is not trusted
+        let request = Request::post("/")
+            .header("x-real-ip", "4.4.4.4")
+            .body(Body::empty())
+            .unwrap();
+        assert!(matches!(throttler.on_request(request), RequestMiddlewareAction::Proceed { .. }));
+        let request = Request::post("/")
+            .header("x-real-ip", "5.5.5.5")
+            .body(Body::empty())
+            .unwrap();
+        assert!(matches!(throttler.on_request(request), RequestMiddlewareAction::Respond { .. }));
+    }
+
+    #[test]
+    fn test_trusted_proxies_are_required() {
+        assert!(RequestThrottler::new(RateLimitsCfg { enabled: true, ..Default::default() }).is_err());
+        assert!(RequestThrottler::new(RateLimitsCfg { enabled: false, ..Default::default() }).is_ok());
+    }
+}
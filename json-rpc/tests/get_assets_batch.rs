@@ -2,8 +2,9 @@ use crate::utils::{
     create_different_assets_requests, create_single_asset_request, fill_database_with_test_data, form_asset_json_uri,
 };
 use entities::dto::Asset;
+use json_rpc::endpoints::errors::DasApiError;
 use json_rpc::endpoints::get_asset::get_asset_batch;
-use json_rpc::endpoints::types::GetAssetBatch;
+use json_rpc::endpoints::types::{BatchItemError, GetAssetBatch};
 use json_rpc::setup::app_context::AppCtx;
 use json_rpc::setup::app_setup::AppSetup;
 use serde_json::Value;
@@ -39,6 +40,43 @@ async fn get_asset_batch_negative() {
     assert_eq!(expected_res, actual_res);
 }
 
+#[tokio::test]
+async fn get_asset_batch_with_invalid_ids() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    let asset_pubkey = fill_database_with_test_data(app_ctx.clone(), create_single_asset_request)
+        .await
+        .first()
+        .expect("Should be present.")
+        .asset
+        .pubkey
+        .to_string();
+
+    let get_assets_batch_req = GetAssetBatch {
+        ids: vec![
+            "invalid".to_string(),
+            asset_pubkey.clone(),
+            Pubkey::new_unique().to_string(),
+        ],
+    };
+
+    let res = get_asset_batch(get_assets_batch_req, app_ctx.clone())
+        .await
+        .expect("Failed to get assets.");
+    let actual_res = serde_json::from_value::<Vec<Value>>(res).expect("Failed to serialize values");
+
+    let expected_error = BatchItemError {
+        id: "invalid".to_string(),
+        error: DasApiError::PubkeyValidationError("invalid".to_string()).to_string(),
+    };
+    assert_eq!(serde_json::from_value::<BatchItemError>(actual_res[0].clone()).unwrap(), expected_error);
+    assert_eq!(serde_json::from_value::<Asset>(actual_res[1].clone()).unwrap().id, asset_pubkey);
+    assert_eq!(actual_res[2], Value::Null);
+}
+
 #[tokio::test]
 async fn get_asset_batch_positive() {
     let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
@@ -0,0 +1,56 @@
+use crate::utils::{
+    create_assets_with_same_owner_requests, extract_asset_name_from_das_asset, fill_database_with_test_data,
+};
+use json_rpc::endpoints::get_asset::get_asset_by_authority;
+use json_rpc::endpoints::types::{AssetList, AssetSortBy, AssetSortDirection, AssetSorting, GetAssetsByAuthority};
+use json_rpc::setup::app_context::{AppCtx, ArcedAppCtx};
+use json_rpc::setup::app_setup::AppSetup;
+use setup::TestEnvironmentCfg;
+
+mod utils;
+
+const AUTHORITY: &str = "6iDkdEY9HVY2XM4T6MbS6fpnX4AGv4sq9bhp28kRSrHf";
+
+async fn get_asset_list_by_authority(req_params: GetAssetsByAuthority, ctx: ArcedAppCtx) -> AssetList {
+    serde_json::from_value::<AssetList>(
+        get_asset_by_authority(req_params, ctx.clone())
+            .await
+            .expect("Failed to get assets."),
+    )
+    .expect("Failed serialize DAO assets..")
+}
+
+#[tokio::test]
+async fn get_assets_by_authority_with_pagination() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+
+    let request_params = GetAssetsByAuthority {
+        authority_address: AUTHORITY.to_string(),
+        sort_by: Some(AssetSorting { sort_by: AssetSortBy::Created, sort_direction: Some(AssetSortDirection::Asc) }),
+        limit: Some(1),
+        page: None,
+        before: None,
+        after: None,
+        cursor: None,
+    };
+
+    let first_page = get_asset_list_by_authority(request_params.clone(), app_ctx.clone()).await;
+    assert_eq!(first_page.items.len(), 1);
+    assert_eq!(extract_asset_name_from_das_asset(first_page.items[0].clone()), "Galactic Explorer #1");
+
+    let request_params = GetAssetsByAuthority { cursor: first_page.cursor, ..request_params };
+    let second_page = get_asset_list_by_authority(request_params.clone(), app_ctx.clone()).await;
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(extract_asset_name_from_das_asset(second_page.items[0].clone()), "Galactic Explorer #2");
+
+    let request_params = GetAssetsByAuthority { cursor: second_page.cursor, ..request_params };
+    assert!(get_asset_list_by_authority(request_params, app_ctx.clone())
+        .await
+        .items
+        .is_empty());
+}
@@ -153,7 +153,7 @@ async fn get_assets_by_creator_with_limit_and_sorting_by_creation_data_desc() {
     let actual_res = serde_json::from_value::<AssetList>(actual_res).expect("Failed serialize DAO assets..");
 
     assert!(actual_res.limit.eq(&(limit as u32)));
-    assert!(actual_res.total.eq(&(limit as u32)));
+    assert!(actual_res.total.eq(&(data_from_db.len() as u32)));
     assert!(actual_res.items.len().eq(&limit));
 
     let actual_res = actual_res
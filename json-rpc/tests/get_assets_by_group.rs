@@ -0,0 +1,62 @@
+use crate::utils::{
+    create_assets_with_same_owner_requests, extract_asset_name_from_das_asset, fill_database_with_test_data,
+};
+use json_rpc::endpoints::errors::DasApiError;
+use json_rpc::endpoints::get_asset::get_asset_by_group;
+use json_rpc::endpoints::types::{AssetList, GetAssetsByGroup};
+use json_rpc::setup::app_context::AppCtx;
+use json_rpc::setup::app_setup::AppSetup;
+use setup::TestEnvironmentCfg;
+
+mod utils;
+
+const COLLECTION: &str = "4kR9SmmSp4T86PvPLg3Jr7Erz5fD8sD83MwFzGJ4v4cD";
+
+fn request_params(group_key: &str, group_value: &str) -> GetAssetsByGroup {
+    GetAssetsByGroup {
+        group_key: group_key.to_string(),
+        group_value: group_value.to_string(),
+        sort_by: None,
+        limit: None,
+        page: None,
+        before: None,
+        after: None,
+        cursor: None,
+    }
+}
+
+#[tokio::test]
+async fn get_assets_by_collection() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+
+    let actual_res = get_asset_by_group(request_params("collection", COLLECTION), app_ctx.clone())
+        .await
+        .expect("Failed to get assets.");
+    let actual_res = serde_json::from_value::<AssetList>(actual_res).expect("Failed serialize DAO assets..");
+
+    assert_eq!(actual_res.items.len(), 1);
+    assert_eq!(extract_asset_name_from_das_asset(actual_res.items[0].clone()), "Galactic Explorer #3");
+}
+
+#[tokio::test]
+async fn get_assets_by_unsupported_group() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    let err = get_asset_by_group(request_params("creator", COLLECTION), app_ctx.clone())
+        .await
+        .unwrap_err();
+    assert_eq!(err, DasApiError::UnsupportedGrouping("creator".to_string()).into());
+
+    let err = get_asset_by_group(request_params("collection", "invalid"), app_ctx.clone())
+        .await
+        .unwrap_err();
+    assert_eq!(err, DasApiError::PubkeyValidationError("invalid".to_string()).into());
+}
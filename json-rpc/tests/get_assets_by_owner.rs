@@ -150,7 +150,7 @@ async fn get_assets_by_owner_with_limit_and_sorting_by_creation_date_desc() {
     let actual_res = serde_json::from_value::<AssetList>(actual_res).expect("Failed serialize DAO assets..");
 
     assert!(actual_res.limit.eq(&(limit as u32)));
-    assert!(actual_res.total.eq(&(limit as u32)));
+    assert!(actual_res.total.eq(&(data_from_db.len() as u32)));
     assert!(actual_res.items.len().eq(&limit));
 
     let actual_res = actual_res
@@ -323,6 +323,158 @@ async fn get_assets_by_owner_with_pagination() {
     check_pagination(app_ctx.clone(), asset_owner_address).await;
 }
 
+#[tokio::test]
+async fn get_assets_by_owner_using_page() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    // Put some assets for tests
+    let data_from_db =
+        fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests_with_random_values).await;
+
+    let asset_owner = data_from_db.first().unwrap().asset.owner.to_string();
+
+    let payload = GetAssetsByOwner {
+        owner_address: asset_owner.clone(),
+        sort_by: None,
+        limit: Some(30),
+        page: None,
+        before: None,
+        after: None,
+        cursor: None,
+    };
+    let first_30 = get_asset_list_by_owner(payload, app_ctx.clone()).await;
+
+    let payload = GetAssetsByOwner {
+        owner_address: asset_owner.clone(),
+        sort_by: None,
+        limit: Some(10),
+        page: Some(2),
+        before: None,
+        after: None,
+        cursor: None,
+    };
+    let second_page = get_asset_list_by_owner(payload, app_ctx.clone()).await;
+
+    assert_eq!(second_page.page, Some(2));
+    assert_eq!(second_page.total, data_from_db.len() as u32);
+    assert_eq!(second_page.items, first_30.items[10..20]);
+
+    let payload = GetAssetsByOwner {
+        owner_address: asset_owner.clone(),
+        sort_by: None,
+        limit: Some(40),
+        page: Some(3),
+        before: None,
+        after: None,
+        cursor: None,
+    };
+    let last_page = get_asset_list_by_owner(payload, app_ctx.clone()).await;
+
+    assert_eq!(last_page.items.len(), data_from_db.len() - 80);
+    assert_eq!(last_page.total, data_from_db.len() as u32);
+}
+
+#[tokio::test]
+async fn get_assets_by_owner_walking_every_sorting() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    let data_from_db = fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+    let asset_owner = data_from_db.first().unwrap().asset.owner.to_string();
+
+    // Updating #3 and then #1 moves them to the end of the list sorted by update date
+    for index in [2, 0] {
+        app_ctx
+            .asset_service
+            .update_asset(data_from_db[index].asset.pubkey, None, None, None, None, None, None)
+            .await
+            .expect("Failed to update asset.");
+    }
+
+    let created_order = [1, 2, 3, 4, 5];
+    let updated_order = [2, 4, 5, 3, 1];
+
+    for (sort_by, order) in [
+        (AssetSortBy::Created, created_order),
+        (AssetSortBy::Updated, updated_order),
+    ] {
+        for sort_direction in [AssetSortDirection::Asc, AssetSortDirection::Desc] {
+            let mut expected_names = order
+                .iter()
+                .map(|number| format!("Galactic Explorer #{number}"))
+                .collect::<Vec<String>>();
+            if sort_direction == AssetSortDirection::Desc {
+                expected_names.reverse();
+            }
+
+            let sorting = AssetSorting { sort_by: sort_by.clone(), sort_direction: Some(sort_direction) };
+            for use_after in [false, true] {
+                let actual_names = walk_assets_by_owner(app_ctx.clone(), &asset_owner, &sorting, use_after).await;
+                assert_eq!(actual_names, expected_names, "sorting: {sorting:?}, using after: {use_after}");
+            }
+        }
+    }
+
+    // Cursor made for one sorting cannot be used with another one
+    let payload = GetAssetsByOwner {
+        owner_address: asset_owner.clone(),
+        sort_by: Some(AssetSorting { sort_by: AssetSortBy::Created, sort_direction: None }),
+        limit: Some(1),
+        page: None,
+        before: None,
+        after: None,
+        cursor: None,
+    };
+    let cursor = get_asset_list_by_owner(payload.clone(), app_ctx.clone()).await.cursor;
+
+    let payload = GetAssetsByOwner {
+        sort_by: Some(AssetSorting { sort_by: AssetSortBy::Updated, sort_direction: None }),
+        cursor: cursor.clone(),
+        ..payload
+    };
+    let expected_err = get_asset_by_owner(payload, app_ctx.clone())
+        .await
+        .expect_err("Should fail.");
+
+    assert_eq!(expected_err, DasApiError::CursorValidationError(cursor.unwrap()).into());
+}
+
+/// Fetches all the assets of the owner, two at a time, following either `cursor` or `after` of every page
+async fn walk_assets_by_owner(
+    app_ctx: ArcedAppCtx,
+    asset_owner: &str,
+    sorting: &AssetSorting,
+    use_after: bool,
+) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut position = None;
+
+    loop {
+        let payload = GetAssetsByOwner {
+            owner_address: asset_owner.to_string(),
+            sort_by: Some(sorting.clone()),
+            limit: Some(2),
+            page: None,
+            before: None,
+            after: position.clone().filter(|_| use_after),
+            cursor: position.clone().filter(|_| !use_after),
+        };
+        let asset_list = get_asset_list_by_owner(payload, app_ctx.clone()).await;
+        if asset_list.items.is_empty() {
+            return names;
+        }
+
+        // The first page is always requested by cursor
+        position = asset_list.after.or(asset_list.cursor);
+        names.extend(asset_list.items.into_iter().map(extract_asset_name_from_das_asset));
+    }
+}
+
 async fn check_pagination(app_ctx: ArcedAppCtx, asset_owner: String) {
     let payload = GetAssetsByOwner {
         limit: Some(10),
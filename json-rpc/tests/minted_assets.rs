@@ -0,0 +1,173 @@
+use crate::utils::{
+    create_assets_with_same_owner_requests, extract_asset_name_from_das_asset, fill_database_with_test_data,
+};
+use entities::dto::Asset;
+use entities::l2::PublicKey;
+use interfaces::l1_asset_provider::L1AssetProvider;
+use interfaces::l2_storage::L2Storage;
+use json_rpc::endpoints::errors::DasApiError;
+use json_rpc::endpoints::get_asset::{get_asset, get_asset_batch, get_asset_by_owner};
+use json_rpc::endpoints::types::{AssetBatchItem, AssetList, GetAsset, GetAssetBatch, GetAssetsByOwner};
+use json_rpc::setup::app_context::AppCtx;
+use json_rpc::setup::app_setup::AppSetup;
+use setup::{TestEnvironment, TestEnvironmentCfg};
+use std::collections::HashMap;
+use std::sync::Arc;
+use storage::l2_storage_pg::L2StoragePg;
+use util::publickey::PublicKeyExt;
+
+mod utils;
+
+const GET_ASSET_BATCH_RESPONSE: &str =
+    include_str!("../../solana-integration/tests/data/das_get_asset_batch_response.json");
+
+/// Serves the asset from the DAS response fixture for the known pubkeys, with the id and owner substituted
+struct StubL1AssetProvider {
+    owners: HashMap<PublicKey, String>,
+}
+
+#[async_trait::async_trait]
+impl L1AssetProvider for StubL1AssetProvider {
+    async fn get_assets(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<Option<Asset>>> {
+        let response: serde_json::Value = serde_json::from_str(GET_ASSET_BATCH_RESPONSE)?;
+        let upstream_asset: Asset = serde_json::from_value(response["result"][0].clone())?;
+
+        Ok(asset_pubkeys
+            .iter()
+            .map(|pubkey| {
+                self.owners.get(pubkey).map(|owner| {
+                    let mut asset = upstream_asset.clone();
+                    asset.id = pubkey.to_bs58();
+                    asset.ownership.owner = owner.clone();
+                    asset
+                })
+            })
+            .collect())
+    }
+}
+
+async fn mark_minted(t_env: &TestEnvironment, asset_pubkeys: &[PublicKey]) {
+    let storage = L2StoragePg::new_from_cfg(&t_env.database_cfg().await).await.unwrap();
+    for pubkey in asset_pubkeys {
+        assert!(storage.lock_asset_before_minting(pubkey).await.unwrap());
+        storage.add_l1_asset(pubkey, &[1u8; 64]).await.unwrap();
+        storage.finalize_mint(pubkey).await.unwrap();
+    }
+}
+
+fn get_assets_by_owner_request(owner: &str) -> GetAssetsByOwner {
+    GetAssetsByOwner {
+        owner_address: owner.to_string(),
+        sort_by: None,
+        limit: None,
+        page: None,
+        before: None,
+        after: None,
+        cursor: None,
+    }
+}
+
+#[tokio::test]
+async fn get_minted_assets_without_upstream_das() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    let data_from_db = fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+    let minted_pubkey = data_from_db[0].asset.pubkey;
+    let l2_pubkey = data_from_db[1].asset.pubkey;
+    mark_minted(&t_env, &[minted_pubkey]).await;
+
+    let res = get_asset(GetAsset { id: minted_pubkey.to_bs58() }, app_ctx.clone()).await;
+    assert_eq!(res.unwrap_err(), DasApiError::NoDataFoundError.into());
+
+    let res =
+        get_asset_batch(GetAssetBatch { ids: vec![minted_pubkey.to_bs58(), l2_pubkey.to_bs58()] }, app_ctx.clone())
+            .await
+            .unwrap();
+    let items = serde_json::from_value::<Vec<Option<AssetBatchItem>>>(res).unwrap();
+    assert!(items[0].is_none());
+    assert!(matches!(&items[1], Some(AssetBatchItem::Asset(asset)) if asset.id == l2_pubkey.to_bs58()));
+
+    // minted asset is omitted as its L1 view is unavailable
+    let owner = data_from_db[0].asset.owner.clone();
+    let res = get_asset_by_owner(get_assets_by_owner_request(&owner), app_ctx.clone())
+        .await
+        .unwrap();
+    let asset_list = serde_json::from_value::<AssetList>(res).unwrap();
+    assert_eq!(asset_list.items.len(), data_from_db.len() - 1);
+    assert_eq!(asset_list.total, data_from_db.len() as u32 - 1);
+    assert!(asset_list.items.iter().all(|asset| asset.id != minted_pubkey.to_bs58()));
+}
+
+#[tokio::test]
+async fn get_minted_assets_from_upstream_das() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await)).await;
+
+    let data_from_db =
+        fill_database_with_test_data(app_ctx.clone().arced(), create_assets_with_same_owner_requests).await;
+    let owner = data_from_db[0].asset.owner.clone();
+    let minted_pubkey = data_from_db[0].asset.pubkey;
+    // transferred to someone else on L1 after the mint
+    let transferred_pubkey = data_from_db[1].asset.pubkey;
+    let l2_pubkey = data_from_db[2].asset.pubkey;
+    mark_minted(&t_env, &[minted_pubkey, transferred_pubkey]).await;
+
+    let l1_asset_provider = StubL1AssetProvider {
+        owners: HashMap::from([
+            (minted_pubkey, owner.clone()),
+            (transferred_pubkey, PublicKey::new_unique().to_bs58()),
+        ]),
+    };
+    let app_ctx = AppCtx { l1_asset_provider: Some(Arc::new(l1_asset_provider)), ..app_ctx }.arced();
+
+    let res = get_asset(GetAsset { id: minted_pubkey.to_bs58() }, app_ctx.clone())
+        .await
+        .unwrap();
+    let asset = serde_json::from_value::<Asset>(res).unwrap();
+    assert_eq!(asset.id, minted_pubkey.to_bs58());
+    // the L1 view is served as the upstream DAS API returns it
+    assert_eq!(extract_asset_name_from_das_asset(asset.clone()), "Galactic Explorer #7");
+    assert_eq!(asset.plugins.unwrap()["royalties"]["authority"]["type"], "UpdateAuthority");
+
+    let res = get_asset_batch(
+        GetAssetBatch {
+            ids: vec![
+                minted_pubkey.to_bs58(),
+                l2_pubkey.to_bs58(),
+                PublicKey::new_unique().to_bs58(),
+            ],
+        },
+        app_ctx.clone(),
+    )
+    .await
+    .unwrap();
+    let items = serde_json::from_value::<Vec<Option<AssetBatchItem>>>(res).unwrap();
+    assert!(matches!(
+        &items[0],
+        Some(AssetBatchItem::Asset(asset)) if extract_asset_name_from_das_asset(*asset.clone()) == "Galactic Explorer #7"
+    ));
+    assert!(matches!(
+        &items[1],
+        Some(AssetBatchItem::Asset(asset)) if extract_asset_name_from_das_asset(*asset.clone()) == data_from_db[2].asset.name
+    ));
+    assert!(items[2].is_none());
+
+    // L1 and L2 assets are merged, the transferred asset is omitted as it's not owned by the owner anymore
+    let res = get_asset_by_owner(get_assets_by_owner_request(&owner), app_ctx.clone())
+        .await
+        .unwrap();
+    let asset_list = serde_json::from_value::<AssetList>(res).unwrap();
+    let ids = asset_list
+        .items
+        .iter()
+        .map(|asset| asset.id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids.len(), data_from_db.len() - 1);
+    assert_eq!(asset_list.total, data_from_db.len() as u32 - 1);
+    assert!(ids.contains(&minted_pubkey.to_bs58()));
+    assert!(ids.contains(&l2_pubkey.to_bs58()));
+    assert!(!ids.contains(&transferred_pubkey.to_bs58()));
+}
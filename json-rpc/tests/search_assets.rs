@@ -0,0 +1,144 @@
+use crate::utils::{
+    create_assets_with_same_owner_requests, extract_asset_name_from_das_asset, fill_database_with_test_data,
+};
+use entities::dto::AssetMintStatus;
+use json_rpc::endpoints::errors::DasApiError;
+use json_rpc::endpoints::search_assets::search_assets;
+use json_rpc::endpoints::types::{
+    AssetList, AssetSortBy, AssetSortDirection, AssetSorting, SearchAssets, SearchConditionType,
+};
+use json_rpc::setup::app_context::{AppCtx, ArcedAppCtx};
+use json_rpc::setup::app_setup::AppSetup;
+use setup::TestEnvironmentCfg;
+
+mod utils;
+
+const OWNER: &str = "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq";
+const CREATOR: &str = "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM";
+
+async fn search_asset_names(req_params: SearchAssets, ctx: ArcedAppCtx) -> Vec<String> {
+    let req_params = SearchAssets {
+        sort_by: Some(AssetSorting { sort_by: AssetSortBy::Created, sort_direction: Some(AssetSortDirection::Asc) }),
+        ..req_params
+    };
+
+    serde_json::from_value::<AssetList>(search_assets(req_params, ctx).await.expect("Failed to search assets."))
+        .expect("Failed serialize DAO assets..")
+        .items
+        .into_iter()
+        .map(extract_asset_name_from_das_asset)
+        .collect()
+}
+
+#[tokio::test]
+async fn search_assets_with_all_conditions() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+
+    let req_params = SearchAssets {
+        owner_address: Some(OWNER.to_string()),
+        creator_address: Some(CREATOR.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        search_asset_names(req_params, app_ctx.clone()).await,
+        vec!["Galactic Explorer #1", "Galactic Explorer #2"]
+    );
+
+    let req_params = SearchAssets {
+        owner_address: Some(OWNER.to_string()),
+        name: Some("explorer #3".to_string()),
+        burnt: Some(false),
+        asset_state: Some(AssetMintStatus::L2),
+        ..Default::default()
+    };
+    assert_eq!(search_asset_names(req_params, app_ctx.clone()).await, vec!["Galactic Explorer #3"]);
+
+    let req_params = SearchAssets {
+        grouping: Some(("collection".to_string(), "4kR9SmmSp4T86PvPLg3Jr7Erz5fD8sD83MwFzGJ4v4cD".to_string())),
+        ..Default::default()
+    };
+    assert_eq!(search_asset_names(req_params, app_ctx.clone()).await, vec!["Galactic Explorer #3"]);
+
+    // wildcards are matched literally
+    let req_params = SearchAssets { name: Some("%".to_string()), ..Default::default() };
+    assert!(search_asset_names(req_params, app_ctx.clone()).await.is_empty());
+
+    let req_params = SearchAssets { burnt: Some(true), ..Default::default() };
+    assert!(search_asset_names(req_params, app_ctx.clone()).await.is_empty());
+}
+
+#[tokio::test]
+async fn search_assets_with_any_condition() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+
+    let req_params = SearchAssets {
+        condition_type: Some(SearchConditionType::Any),
+        creator_address: Some(CREATOR.to_string()),
+        name: Some("#5".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        search_asset_names(req_params, app_ctx.clone()).await,
+        vec!["Galactic Explorer #1", "Galactic Explorer #2", "Galactic Explorer #5"]
+    );
+}
+
+#[tokio::test]
+async fn search_assets_with_pagination() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    fill_database_with_test_data(app_ctx.clone(), create_assets_with_same_owner_requests).await;
+
+    let req_params = SearchAssets {
+        owner_address: Some(OWNER.to_string()),
+        sort_by: Some(AssetSorting { sort_by: AssetSortBy::Created, sort_direction: Some(AssetSortDirection::Asc) }),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let first_page =
+        serde_json::from_value::<AssetList>(search_assets(req_params.clone(), app_ctx.clone()).await.unwrap()).unwrap();
+    let first_page_names = first_page
+        .items
+        .into_iter()
+        .map(extract_asset_name_from_das_asset)
+        .collect::<Vec<_>>();
+    assert_eq!(first_page_names, vec!["Galactic Explorer #1", "Galactic Explorer #2"]);
+
+    let req_params = SearchAssets { cursor: first_page.cursor, ..req_params };
+    let second_page = serde_json::from_value::<AssetList>(search_assets(req_params, app_ctx.clone()).await.unwrap())
+        .unwrap()
+        .items
+        .into_iter()
+        .map(extract_asset_name_from_das_asset)
+        .collect::<Vec<_>>();
+    assert_eq!(second_page, vec!["Galactic Explorer #3", "Galactic Explorer #4"]);
+}
+
+#[tokio::test]
+async fn search_assets_with_unsupported_grouping() {
+    let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+    let app_ctx = AppCtx::new(&AppSetup::from_settings(t_env.make_test_cfg().await))
+        .await
+        .arced();
+
+    let req_params = SearchAssets {
+        grouping: Some(("creator".to_string(), CREATOR.to_string())),
+        ..Default::default()
+    };
+    let err = search_assets(req_params, app_ctx.clone()).await.unwrap_err();
+
+    assert_eq!(err, DasApiError::UnsupportedGrouping("creator".to_string()).into());
+}
@@ -22,7 +22,7 @@ pub struct CreateAssetRequest {
 
 impl CreateAssetRequest {
     const METADATA_JSON: &'static str = r#"
-        {"description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}
+        {"name": "Galactic Explorer", "description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}
     "#;
 
     fn with_name_and_owner(name: &str, owner: &str) -> Self {
@@ -54,7 +54,7 @@ pub fn create_assets_with_same_owner_requests() -> Vec<CreateAssetRequest> {
     vec![
         CreateAssetRequest {
             name: "Galactic Explorer #1".to_string(),
-            metadata_json: r#"{"description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #1", "description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "6iDkdEY9HVY2XM4T6MbS6fpnX4AGv4sq9bhp28kRSrHf".to_string(),
@@ -63,7 +63,7 @@ pub fn create_assets_with_same_owner_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #2".to_string(),
-            metadata_json: r#"{"description": "A futuristic spaceship navigating through nebulae.", "image": "https://example.com/images/galactic_explorer_2.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #2", "description": "A futuristic spaceship navigating through nebulae.", "image": "https://example.com/images/galactic_explorer_2.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "6iDkdEY9HVY2XM4T6MbS6fpnX4AGv4sq9bhp28kRSrHf".to_string(),
@@ -72,7 +72,7 @@ pub fn create_assets_with_same_owner_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #3".to_string(),
-            metadata_json: r#"{"description": "A cosmic landscape with distant stars.", "image": "https://example.com/images/galactic_explorer_3.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #3", "description": "A cosmic landscape with distant stars.", "image": "https://example.com/images/galactic_explorer_3.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "8L5jqJ9mY9btqQF8XM6hpv9Y1rM3K6jF3y5qfJ7bH7v9".to_string(),
             authority: "7T3prz4G8HT8JnY3n3K8GR7n7s3yzM2u5b6pqWfD6WnP".to_string(),
@@ -81,7 +81,7 @@ pub fn create_assets_with_same_owner_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #4".to_string(),
-            metadata_json: r#"{"description": "A robotic explorer on a distant planet.", "image": "https://example.com/images/galactic_explorer_4.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #4", "description": "A robotic explorer on a distant planet.", "image": "https://example.com/images/galactic_explorer_4.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "5Fw2t5H9L6CpZqB8H9vL5pJt4N9E7bHk9N5qR7yK2L8H".to_string(),
             authority: "8YkF9K6cLg77zSxVpN9rX4cqj6Jr7L6QK9Hg2PzQb1tP".to_string(),
@@ -90,7 +90,7 @@ pub fn create_assets_with_same_owner_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #5".to_string(),
-            metadata_json: r#"{"description": "A space station orbiting a vibrant star.", "image": "https://example.com/images/galactic_explorer_5.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #5", "description": "A space station orbiting a vibrant star.", "image": "https://example.com/images/galactic_explorer_5.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "6C9vX6D8c2T7D9pM3Y5jK6BzR9V2Jh8X8W3L6Q5Z4NxG".to_string(),
             authority: "9FgR2z8vZ8N4D7qH6K7hL2W7X8uR3vT2b6qW3L9J9Pt".to_string(),
@@ -116,7 +116,7 @@ pub fn create_different_assets_requests() -> Vec<CreateAssetRequest> {
     vec![
         CreateAssetRequest {
             name: "ArtPieceOne".to_string(),
-            metadata_json: r#"{"description": "First NFT", "attributes": {"rarity": "rare"}}"#.to_string(),
+            metadata_json: r#"{"name": "ArtPieceOne", "description": "First NFT", "image": "https://example.com/images/art_piece_one.png", "attributes": [{"trait_type": "rarity", "value": "rare"}]}"#.to_string(),
             owner: "5HueWz2D9f8yjXAx8eb6WY8ocE2Fy6smAt1NkJ39kF9z".to_string(),
             creator: "7YmM7NUj9wFmAkT4mJ2LD6yyYHtKBu9qMEfF7cX5mH9J".to_string(),
             authority: "3JzRkTrzYa1ZFbVRd2kVy5YoAUp4XCN8UNW5NTR4jMgP".to_string(),
@@ -125,7 +125,7 @@ pub fn create_different_assets_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "DigitalCollectible".to_string(),
-            metadata_json: r#"{"description": "Exclusive collectible", "attributes": {"series": "limited"}}"#
+            metadata_json: r#"{"name": "DigitalCollectible", "description": "Exclusive collectible", "image": "https://example.com/images/digital_collectible.png", "attributes": [{"trait_type": "series", "value": "limited"}]}"#
                 .to_string(),
             owner: "8QzJK3WYaZBfX9qf9XJe8Phc7xZ8yz6kC8QzTfPyLfPb".to_string(),
             creator: "4VfFHrM7vZYg5Ezm8YQVBr9N7vHj3DFJLqPh2Qj9L9Tp".to_string(),
@@ -135,7 +135,7 @@ pub fn create_different_assets_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "GamingAvatar".to_string(),
-            metadata_json: r#"{"description": "Avatar for gaming", "attributes": {"level": 5}}"#.to_string(),
+            metadata_json: r#"{"name": "GamingAvatar", "description": "Avatar for gaming", "image": "https://example.com/images/gaming_avatar.png", "attributes": [{"trait_type": "level", "value": 5}]}"#.to_string(),
             owner: "2YhMfS7oMv2gV8hB3kJ8PmZbW6zQmLq5rK1TrF1yNq7Z".to_string(),
             creator: "3RfNrQk8zDfX5GvH9pV7cPdT3hWj2Ff8VNpF8Xm8PfJk".to_string(),
             authority: "6TpLpS8b2cZk3mZk8qW5Pc6yJoVt8XVXrK3XrF2YkNtK".to_string(),
@@ -148,7 +148,7 @@ pub fn create_different_assets_requests() -> Vec<CreateAssetRequest> {
 pub fn create_single_asset_request() -> Vec<CreateAssetRequest> {
     vec![CreateAssetRequest {
         name: "GamingAvatar".to_string(),
-        metadata_json: r#"{"description": "Avatar for gaming", "attributes": {"level": 5}}"#.to_string(),
+        metadata_json: r#"{"name": "GamingAvatar", "description": "Avatar for gaming", "image": "https://example.com/images/gaming_avatar.png", "attributes": [{"trait_type": "level", "value": 5}]}"#.to_string(),
         owner: "2YhMfS7oMv2gV8hB3kJ8PmZbW6zQmLq5rK1TrF1yNq7Z".to_string(),
         creator: "3RfNrQk8zDfX5GvH9pV7cPdT3hWj2Ff8VNpF8Xm8PfJk".to_string(),
         authority: "6TpLpS8b2cZk3mZk8qW5Pc6yJoVt8XVXrK3XrF2YkNtK".to_string(),
@@ -161,7 +161,7 @@ pub fn create_assets_with_same_creator_requests() -> Vec<CreateAssetRequest> {
     vec![
         CreateAssetRequest {
             name: "Galactic Explorer #1".to_string(),
-            metadata_json: r#"{"description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #1", "description": "An astronaut exploring distant galaxies.", "image": "https://example.com/images/galactic_explorer_1.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "6iDkdEY9HVY2XM4T6MbS6fpnX4AGv4sq9bhp28kRSrHf".to_string(),
@@ -170,7 +170,7 @@ pub fn create_assets_with_same_creator_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #2".to_string(),
-            metadata_json: r#"{"description": "A futuristic spaceship navigating through nebulae.", "image": "https://example.com/images/galactic_explorer_2.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #2", "description": "A futuristic spaceship navigating through nebulae.", "image": "https://example.com/images/galactic_explorer_2.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "6iDkdEY9HVY2XM4T6MbS6fpnX4AGv4sq9bhp28kRSrHf".to_string(),
@@ -179,7 +179,7 @@ pub fn create_assets_with_same_creator_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #3".to_string(),
-            metadata_json: r#"{"description": "A cosmic landscape with distant stars.", "image": "https://example.com/images/galactic_explorer_3.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #3", "description": "A cosmic landscape with distant stars.", "image": "https://example.com/images/galactic_explorer_3.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "7T3prz4G8HT8JnY3n3K8GR7n7s3yzM2u5b6pqWfD6WnP".to_string(),
@@ -188,7 +188,7 @@ pub fn create_assets_with_same_creator_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #4".to_string(),
-            metadata_json: r#"{"description": "A robotic explorer on a distant planet.", "image": "https://example.com/images/galactic_explorer_4.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #4", "description": "A robotic explorer on a distant planet.", "image": "https://example.com/images/galactic_explorer_4.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "8YkF9K6cLg77zSxVpN9rX4cqj6Jr7L6QK9Hg2PzQb1tP".to_string(),
@@ -197,7 +197,7 @@ pub fn create_assets_with_same_creator_requests() -> Vec<CreateAssetRequest> {
         },
         CreateAssetRequest {
             name: "Galactic Explorer #5".to_string(),
-            metadata_json: r#"{"description": "A space station orbiting a vibrant star.", "image": "https://example.com/images/galactic_explorer_5.png"}"#.to_string(),
+            metadata_json: r#"{"name": "Galactic Explorer #5", "description": "A space station orbiting a vibrant star.", "image": "https://example.com/images/galactic_explorer_5.png"}"#.to_string(),
             owner: "9hfHbS34pV8eDPi8F3B9N6N9hvX2MjLs1B3fKm6vQeEq".to_string(),
             creator: "4R7zW4cV9D6x2nZyZ2DtCzF8H9jtyqG7nD8P8ZJ8ZxkM".to_string(),
             authority: "9FgR2z8vZ8N4D7qH6K7hL2W7X8uR3vT2b6qW3L9J9Pt".to_string(),
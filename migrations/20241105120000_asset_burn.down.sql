@@ -0,0 +1,2 @@
+-- PostgreSQL doesn't support removing a value from an enum type,
+-- 'BURNT' stays in asset_state and is dropped together with the type.
@@ -0,0 +1,2 @@
+-- Burnt assets are kept in the table for audit purposes
+ALTER TYPE asset_state ADD VALUE IF NOT EXISTS 'BURNT';
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_asset_transfers_asset_pubkey;
+DROP TABLE IF EXISTS l2_asset_transfers;
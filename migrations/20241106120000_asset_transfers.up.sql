@@ -0,0 +1,12 @@
+-- History of L2 assets ownership changes
+CREATE TABLE IF NOT EXISTS l2_asset_transfers (
+    transfer_id BIGSERIAL NOT NULL,
+    asset_pubkey BYTEA NOT NULL,
+    from_owner varchar(64) NOT NULL,
+    to_owner varchar(64) NOT NULL,
+    transfer_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    api_key_username varchar(200) NOT NULL,
+    CONSTRAINT pk_transfer_id PRIMARY KEY (transfer_id)
+);
+
+CREATE INDEX idx_asset_transfers_asset_pubkey ON l2_asset_transfers(asset_pubkey, transfer_timestamp);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS l2_collections_v1;
@@ -0,0 +1,12 @@
+-- Table that stores information about L2 collections.
+-- Collection pubkeys are derived from the same l2_bip44_sequence as asset pubkeys
+CREATE TABLE IF NOT EXISTS l2_collections_v1 (
+    collection_pubkey BYTEA NOT NULL,
+    collection_name varchar(200) NOT NULL,
+    collection_authority varchar(64) NOT NULL,
+    collection_create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    collection_last_update_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    bip44_account_num INT8 NOT NULL CHECK (bip44_account_num >= 0),
+    bip44_address_num INT8 NOT NULL CHECK (bip44_address_num >= 0),
+    CONSTRAINT pk_collection_pubkey PRIMARY KEY (collection_pubkey)
+);
@@ -0,0 +1,25 @@
+-- Callbacks registered by clients for the mints in progress
+CREATE TABLE IF NOT EXISTS mint_callbacks (
+    asset_pubkey BYTEA NOT NULL,
+    callback_url TEXT NOT NULL,
+    api_key_username varchar(200) NOT NULL,
+    CONSTRAINT pk_mint_callbacks PRIMARY KEY (asset_pubkey)
+);
+
+-- Webhooks to be delivered to the clients, kept until delivered to survive restarts
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    webhook_id BIGSERIAL NOT NULL,
+    callback_url TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    api_key_username varchar(200) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    -- NULL once delivered or given up
+    next_attempt_timestamp TIMESTAMP,
+    last_error TEXT,
+    create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    delivered_timestamp TIMESTAMP,
+    CONSTRAINT pk_webhook_id PRIMARY KEY (webhook_id)
+);
+
+CREATE INDEX idx_webhook_deliveries_next_attempt ON webhook_deliveries(next_attempt_timestamp)
+    WHERE next_attempt_timestamp IS NOT NULL;
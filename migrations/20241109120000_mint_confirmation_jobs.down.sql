@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_mint_confirmation_jobs_next_attempt;
+DROP TABLE IF EXISTS mint_confirmation_jobs;
@@ -0,0 +1,13 @@
+-- Queue of jobs awaiting confirmation of L1 mint transactions
+CREATE TABLE IF NOT EXISTS mint_confirmation_jobs (
+    asset_pubkey BYTEA NOT NULL,
+    signature BYTEA NOT NULL,
+    -- NULL for the mints that had been sent before the jobs were introduced
+    recent_blockhash BYTEA,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT pk_mint_confirmation_jobs PRIMARY KEY (asset_pubkey)
+);
+
+CREATE INDEX idx_mint_confirmation_jobs_next_attempt ON mint_confirmation_jobs(next_attempt_timestamp);
@@ -0,0 +1 @@
+ALTER TABLE IF EXISTS mint_confirmation_jobs DROP COLUMN IF EXISTS last_valid_block_height;
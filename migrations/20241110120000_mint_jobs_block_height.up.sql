@@ -0,0 +1,2 @@
+-- Block height after which the mint transaction cannot land
+ALTER TABLE mint_confirmation_jobs ADD COLUMN IF NOT EXISTS last_valid_block_height BIGINT;
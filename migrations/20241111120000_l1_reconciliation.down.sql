@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS l1_reconciliation;
+DROP TYPE IF EXISTS l1_drift_kind;
@@ -0,0 +1,17 @@
+CREATE TYPE l1_drift_kind AS ENUM (
+    'MISSING_ON_CHAIN',
+    'OWNER_MISMATCH',
+    'NAME_MISMATCH',
+    'URI_MISMATCH',
+    'MINTED_BUT_L2'
+);
+
+-- Discrepancies between assets and their mpl-core accounts on L1, found by the last reconciliation
+CREATE TABLE IF NOT EXISTS l1_reconciliation (
+    asset_pubkey BYTEA NOT NULL,
+    drift_kind l1_drift_kind NOT NULL,
+    db_value TEXT,
+    chain_value TEXT,
+    detected_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT pk_l1_reconciliation PRIMARY KEY (asset_pubkey, drift_kind)
+);
@@ -0,0 +1,12 @@
+-- Assets minted on L1 are returned by owner and creator too, so the indexes shouldn't exclude them
+DROP INDEX IF EXISTS idx_asset_owner_create_timestamp;
+DROP INDEX IF EXISTS idx_asset_owner_update_timestamp;
+
+DROP INDEX IF EXISTS idx_asset_creator_create_timestamp;
+DROP INDEX IF EXISTS idx_asset_creator_update_timestamp;
+
+CREATE INDEX IF NOT EXISTS idx_asset_owner_create_timestamp ON l2_assets_v1(asset_owner, asset_create_timestamp);
+CREATE INDEX IF NOT EXISTS idx_asset_owner_update_timestamp ON l2_assets_v1(asset_owner, asset_last_update_timestamp);
+
+CREATE INDEX IF NOT EXISTS idx_asset_creator_create_timestamp ON l2_assets_v1(asset_creator, asset_create_timestamp);
+CREATE INDEX IF NOT EXISTS idx_asset_creator_update_timestamp ON l2_assets_v1(asset_creator, asset_last_update_timestamp);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_asset_authority_create_timestamp;
+DROP INDEX IF EXISTS idx_asset_authority_update_timestamp;
+
+DROP INDEX IF EXISTS idx_asset_collection_create_timestamp;
+DROP INDEX IF EXISTS idx_asset_collection_update_timestamp;
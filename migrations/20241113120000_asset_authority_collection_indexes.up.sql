@@ -0,0 +1,5 @@
+CREATE INDEX IF NOT EXISTS idx_asset_authority_create_timestamp ON l2_assets_v1(asset_authority, asset_create_timestamp);
+CREATE INDEX IF NOT EXISTS idx_asset_authority_update_timestamp ON l2_assets_v1(asset_authority, asset_last_update_timestamp);
+
+CREATE INDEX IF NOT EXISTS idx_asset_collection_create_timestamp ON l2_assets_v1(asset_collection, asset_create_timestamp);
+CREATE INDEX IF NOT EXISTS idx_asset_collection_update_timestamp ON l2_assets_v1(asset_collection, asset_last_update_timestamp);
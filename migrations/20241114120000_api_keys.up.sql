@@ -0,0 +1,13 @@
+-- API keys issued via the admin endpoints, only SHA-256 hashes of the keys are stored
+CREATE TABLE IF NOT EXISTS api_keys (
+    api_key_id BIGSERIAL NOT NULL,
+    key_hash varchar(64) NOT NULL,
+    username varchar(200) NOT NULL,
+    label TEXT NOT NULL,
+    create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    expiry_timestamp TIMESTAMP,
+    -- revoked keys are kept for the audit
+    revocation_timestamp TIMESTAMP,
+    CONSTRAINT pk_api_keys PRIMARY KEY (api_key_id),
+    CONSTRAINT uq_api_keys_key_hash UNIQUE (key_hash)
+);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS api_key_authorities;
+
+ALTER TABLE api_keys
+    DROP COLUMN IF EXISTS scopes,
+    DROP COLUMN IF EXISTS restricted;
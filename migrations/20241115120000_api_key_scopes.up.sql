@@ -0,0 +1,15 @@
+-- Keys issued before the scopes were introduced keep the access to the assets
+ALTER TABLE api_keys
+    ADD COLUMN scopes TEXT[] NOT NULL DEFAULT '{assets:create,assets:update,assets:mint}',
+    ADD COLUMN restricted BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE api_keys ALTER COLUMN scopes DROP DEFAULT;
+
+-- Authorities of the assets created by the restricted API keys, every authority belongs to a single key
+CREATE TABLE IF NOT EXISTS api_key_authorities (
+    authority varchar(64) NOT NULL,
+    api_key_id BIGINT NOT NULL,
+    create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT pk_api_key_authorities PRIMARY KEY (authority),
+    CONSTRAINT fk_api_key_authorities_api_key FOREIGN KEY (api_key_id) REFERENCES api_keys (api_key_id)
+);
@@ -0,0 +1,7 @@
+-- Nonces of the wallet signatures, kept until the signatures expire to reject replayed requests
+CREATE TABLE IF NOT EXISTS wallet_signature_nonces (
+    signer varchar(64) NOT NULL,
+    nonce varchar(64) NOT NULL,
+    expiry_timestamp TIMESTAMP NOT NULL,
+    CONSTRAINT pk_wallet_signature_nonces PRIMARY KEY (signer, nonce)
+);
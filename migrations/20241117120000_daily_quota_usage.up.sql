@@ -0,0 +1,8 @@
+-- Number of the operations performed by the API key users per UTC day, used to enforce the daily quotas
+CREATE TABLE IF NOT EXISTS daily_quota_usage (
+    username varchar(200) NOT NULL,
+    operation varchar(32) NOT NULL,
+    usage_date DATE NOT NULL,
+    usage_count INTEGER NOT NULL,
+    CONSTRAINT pk_daily_quota_usage PRIMARY KEY (username, operation, usage_date)
+);
@@ -0,0 +1,11 @@
+-- Idempotency keys of the API key users, the response is set once the first request succeeds
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    username TEXT NOT NULL,
+    idempotency_key varchar(255) NOT NULL,
+    request_hash varchar(64) NOT NULL,
+    response_status SMALLINT,
+    response_content_type TEXT,
+    response_body BYTEA,
+    create_timestamp TIMESTAMP NOT NULL DEFAULT NOW(),
+    CONSTRAINT pk_idempotency_keys PRIMARY KEY (username, idempotency_key)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE webhook_deliveries DROP COLUMN IF EXISTS api_key_id;
+ALTER TABLE mint_callbacks DROP COLUMN IF EXISTS api_key_id;
@@ -0,0 +1,9 @@
+-- Webhooks of the callbacks registered with the issued API keys are signed with the secret of the key,
+-- the secret isn't stored, it's derived from the key hash and the server-side key
+ALTER TABLE mint_callbacks
+    ADD COLUMN api_key_id BIGINT,
+    ADD CONSTRAINT fk_mint_callbacks_api_key FOREIGN KEY (api_key_id) REFERENCES api_keys (api_key_id);
+
+ALTER TABLE webhook_deliveries
+    ADD COLUMN api_key_id BIGINT,
+    ADD CONSTRAINT fk_webhook_deliveries_api_key FOREIGN KEY (api_key_id) REFERENCES api_keys (api_key_id);
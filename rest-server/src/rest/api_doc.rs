@@ -0,0 +1,143 @@
+use entities::api_key::ApiKeyScope;
+use entities::dto::AssetMintStatus;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use crate::rest::auth::API_KEY_HEADER;
+use crate::rest::endpoints::api_keys::{ApiKeyResponse, IssueApiKeyRequest, IssuedApiKeyResponse};
+use crate::rest::endpoints::l2_assets::{
+    AssetTransferResponse, BinaryUploadForm, CreateAssetRequest, CreateAssetsBatchRequest, CreateAssetsBatchResponse,
+    CreatedAssetResult, L1MintRequest, MintStatusResponse, MintTransactionResponse, TransferAssetRequest,
+    UpdateAssetRequest,
+};
+use crate::rest::endpoints::l2_collections::{CollectionResponse, CreateCollectionRequest, UpdateCollectionRequest};
+use crate::rest::endpoints::{api_keys, health_check, l2_assets, l2_collections};
+use crate::rest::errors::ErrorResponse;
+
+pub const OPEN_API_JSON_PATH: &str = "/api-docs/openapi.json";
+pub const SWAGGER_UI_PATH: &str = "/swagger-ui/{_:.*}";
+
+/// OpenAPI document of the REST server.
+/// Every route has to be listed in `paths`, otherwise `test_every_route_is_documented` fails.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Spell REST API"),
+    paths(
+        health_check::health,
+        health_check::secured_health,
+        l2_assets::create_asset,
+        l2_assets::create_assets_batch,
+        l2_assets::update_asset,
+        l2_assets::burn_asset,
+        l2_assets::transfer_asset,
+        l2_assets::get_transfers,
+        l2_assets::get_asset,
+        l2_assets::get_metadata,
+        l2_assets::upload_binary,
+        l2_assets::get_binary,
+        l2_assets::create_mint_transaction,
+        l2_assets::mint_transaction,
+        l2_assets::mint_transaction_async,
+        l2_assets::mint_status,
+        l2_collections::create_collection,
+        l2_collections::update_collection,
+        l2_collections::get_collection,
+        l2_collections::get_collection_metadata,
+        api_keys::issue_api_key,
+        api_keys::list_api_keys,
+        api_keys::revoke_api_key,
+    ),
+    components(schemas(
+        CreateAssetRequest,
+        CreateAssetsBatchRequest,
+        CreateAssetsBatchResponse,
+        CreatedAssetResult,
+        UpdateAssetRequest,
+        TransferAssetRequest,
+        AssetTransferResponse,
+        BinaryUploadForm,
+        L1MintRequest,
+        MintTransactionResponse,
+        MintStatusResponse,
+        AssetMintStatus,
+        CreateCollectionRequest,
+        UpdateCollectionRequest,
+        CollectionResponse,
+        ApiKeyScope,
+        IssueApiKeyRequest,
+        ApiKeyResponse,
+        IssuedApiKeyResponse,
+        ErrorResponse,
+    )),
+    modifiers(&ApiKeySecurity),
+    tags(
+        (name = "health", description = "Server health checks"),
+        (name = "assets", description = "L2 assets and their minting to Solana"),
+        (name = "collections", description = "L2 collections"),
+        (name = "api-keys", description = "Management of the API keys, requires the admin scope"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Registers the `x-api-key` header, that is required by the secured routes.
+struct ApiKeySecurity;
+
+impl Modify for ApiKeySecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "api_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use utoipa::openapi::PathItemType;
+    use utoipa::OpenApi;
+
+    use super::ApiDoc;
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let open_api = ApiDoc::openapi();
+        let endpoints_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src/rest/endpoints");
+
+        let mut routes = Vec::new();
+        for entry in fs::read_dir(endpoints_dir).expect("Failed to read endpoints dir") {
+            let source = fs::read_to_string(entry.expect("Failed to read dir entry").path())
+                .expect("Failed to read endpoints source");
+            routes.extend(source.lines().filter_map(parse_route));
+        }
+        assert!(!routes.is_empty());
+
+        for (method_name, method, path) in routes {
+            let is_documented = open_api
+                .paths
+                .paths
+                .get(&path)
+                .is_some_and(|path_item| path_item.operations.contains_key(&method));
+            assert!(is_documented, "Route '{method_name} {path}' is missing in the OpenAPI document");
+        }
+    }
+
+    /// Parses actix route macro, e.g. `#[get("/asset/{pubkey}")]`.
+    fn parse_route(line: &str) -> Option<(String, PathItemType, String)> {
+        let (method_name, rest) = line.trim().strip_prefix("#[")?.split_once("(\"")?;
+        let method = match method_name {
+            "get" => PathItemType::Get,
+            "post" => PathItemType::Post,
+            "put" => PathItemType::Put,
+            "delete" => PathItemType::Delete,
+            "patch" => PathItemType::Patch,
+            _ => return None,
+        };
+        let path = rest.strip_suffix("\")]")?;
+
+        Some((method_name.to_uppercase(), method, path.to_string()))
+    }
+}
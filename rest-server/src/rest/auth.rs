@@ -1,51 +1,154 @@
-use actix_web::error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized};
 use actix_web::web::Data;
-use actix_web::{dev::Payload, Error as ActixError, FromRequest, HttpRequest};
-use entities::api_key::{ApiKeys, Username};
-use futures::future::{ready, Ready};
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use entities::api_key::{ApiKeyOwner, ApiKeyScope, Username};
+use futures::future::LocalBoxFuture;
+use interfaces::api_key_provider::ApiKeysProvider;
+use interfaces::wallet_signature::{WalletSignature, WalletSignatureError};
+use service::api_key_provider_service::ApiKeysProviderService;
+use std::sync::Arc;
 
-pub const API_KEY_HEADER: &'static str = "x-api-key";
+use crate::rest::{errors::ApiError, web_app::AppState};
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+pub const WALLET_SIGNATURE_HEADER: &str = "x-wallet-signature";
+pub const WALLET_SIGNATURE_NONCE_HEADER: &str = "x-wallet-signature-nonce";
+pub const WALLET_SIGNATURE_EXPIRY_HEADER: &str = "x-wallet-signature-expiry";
 
-/// For now just store api keys in memory
 pub struct ApiKeysProviderCtx {
-    pub api_keys: ApiKeys,
+    pub api_keys_provider: Arc<dyn ApiKeysProvider + Sync + Send>,
 }
 
-#[allow(dead_code)]
 pub struct ApiKeyExtractor {
-    authorized_user: Username,
+    owner: ApiKeyOwner,
 }
 
 impl ApiKeyExtractor {
-    fn new(user: Username) -> Self {
-        Self { authorized_user: user }
+    fn new(owner: ApiKeyOwner) -> Self {
+        Self { owner }
+    }
+
+    /// Name of the user the provided API key belongs to
+    pub fn username(&self) -> &Username {
+        &self.owner.username
+    }
+
+    /// Every endpoint declares the scope the provided API key must have
+    pub fn require_scope(&self, scope: ApiKeyScope) -> Result<(), ApiError> {
+        if self.owner.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(ApiError::MissingScope(scope))
+        }
+    }
+
+    /// Restricted key takes over the authority of the asset or collection it creates,
+    /// fails if the authority already belongs to another key.
+    pub async fn claim_authority(
+        &self,
+        api_key_service: &ApiKeysProviderService,
+        authority: &str,
+    ) -> Result<(), ApiError> {
+        if api_key_service.claim_authority(&self.owner, authority).await? {
+            Ok(())
+        } else {
+            Err(ApiError::ForeignAuthority)
+        }
+    }
+
+    /// Restricted key is only allowed to modify the assets and collections whose authority it created
+    pub async fn check_authority(
+        &self,
+        api_key_service: &ApiKeysProviderService,
+        authority: &str,
+    ) -> Result<(), ApiError> {
+        if api_key_service.is_authority_allowed(&self.owner, authority).await? {
+            Ok(())
+        } else {
+            Err(ApiError::ForeignAuthority)
+        }
+    }
+
+    pub fn is_restricted(&self) -> bool {
+        self.owner.restricted_key_id.is_some()
+    }
+
+    /// ID of the API key issued via the admin endpoints, `None` for the keys from the config
+    pub fn stored_key_id(&self) -> Option<i64> {
+        self.owner.stored_key_id
     }
 }
 
 impl FromRequest for ApiKeyExtractor {
-    type Error = ActixError;
-    type Future = Ready<Result<Self, Self::Error>>;
+    type Error = ApiError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        ready(match req.app_data::<Data<ApiKeysProviderCtx>>() {
-            Some(api_keys_provider) => {
-                let Some(provided_api_key) = req.head().headers.get(API_KEY_HEADER) else {
-                    return ready(Err(ErrorBadRequest("No header found.")));
-                };
-
-                let Some(provided_api_key) = provided_api_key.to_str().ok() else {
-                    return ready(Err(ErrorBadRequest("Invalid header string.")));
-                };
-
-                match api_keys_provider
-                    .api_keys
-                    .contains_api_key_then_get_username(provided_api_key)
-                {
-                    Some(user) => Ok(ApiKeyExtractor::new(user)),
-                    None => Err(ErrorUnauthorized("Invalid API key.")),
+        let api_keys_provider = req.app_data::<Data<ApiKeysProviderCtx>>().cloned();
+        let rate_limit_service = req
+            .app_data::<Data<AppState>>()
+            .map(|state| state.rate_limit_service.clone());
+        let provided_api_key = req
+            .head()
+            .headers
+            .get(API_KEY_HEADER)
+            .map(|header| header.to_str().map(ToString::to_string));
+
+        Box::pin(async move {
+            let Some(api_keys_provider) = api_keys_provider else {
+                return Err(ApiError::Internal(anyhow::anyhow!("Couldn't retrieve 'ApiKeysProviderCtx'!")));
+            };
+            let Some(rate_limit_service) = rate_limit_service else {
+                return Err(ApiError::Internal(anyhow::anyhow!("Couldn't retrieve 'AppState'!")));
+            };
+
+            let provided_api_key = match provided_api_key {
+                Some(Ok(provided_api_key)) => provided_api_key,
+                Some(Err(_)) => return Err(ApiError::InvalidApiKey),
+                None => return Err(ApiError::MissingApiKey),
+            };
+
+            match api_keys_provider
+                .api_keys_provider
+                .get_api_key_owner(&provided_api_key)
+                .await?
+            {
+                Some(owner) => {
+                    rate_limit_service.check_user(&owner.username)?;
+                    Ok(ApiKeyExtractor::new(owner))
                 }
+                None => Err(ApiError::InvalidApiKey),
             }
-            None => Err(ErrorInternalServerError("Couldn't retrieve 'ApiKeysProviderCtx'!")),
         })
     }
 }
+
+/// Takes the wallet signature of the request from the `x-wallet-signature*` headers,
+/// returns `None` if none of them is provided.
+pub fn wallet_signature_from_request(req: &HttpRequest) -> Result<Option<WalletSignature>, ApiError> {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .map(|value| value.to_str().map(ToString::to_string))
+            .transpose()
+            .map_err(|_| WalletSignatureError::Malformed(format!("'{name}' header is not a valid string")))
+    };
+
+    match (
+        header(WALLET_SIGNATURE_HEADER)?,
+        header(WALLET_SIGNATURE_NONCE_HEADER)?,
+        header(WALLET_SIGNATURE_EXPIRY_HEADER)?,
+    ) {
+        (None, None, None) => Ok(None),
+        (Some(signature), Some(nonce), Some(expiry)) => {
+            let expiry = expiry.parse().map_err(|_| {
+                WalletSignatureError::Malformed(format!("'{WALLET_SIGNATURE_EXPIRY_HEADER}' is not a unix timestamp"))
+            })?;
+            Ok(Some(WalletSignature { signature, nonce, expiry }))
+        }
+        _ => Err(WalletSignatureError::Malformed(format!(
+            "'{WALLET_SIGNATURE_HEADER}', '{WALLET_SIGNATURE_NONCE_HEADER}' and '{WALLET_SIGNATURE_EXPIRY_HEADER}' \
+             headers must be provided together"
+        ))
+        .into()),
+    }
+}
@@ -0,0 +1,181 @@
+use actix_web::{delete, get, post, web, HttpResponse};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use entities::api_key::{ApiKeyInfo, ApiKeyScope, Username};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::rest::{auth::ApiKeyExtractor, errors::ApiError, web_app::AppState};
+
+/// Request object for issuing an API key
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct IssueApiKeyRequest {
+    /// Name of the user the key belongs to
+    pub username: String,
+
+    /// Description of the key
+    pub label: String,
+
+    /// Operations the key is allowed to perform
+    pub scopes: Vec<ApiKeyScope>,
+
+    /// Restricts the key to the assets whose authority it created
+    #[serde(default)]
+    pub restricted: bool,
+
+    /// RFC 3339 timestamp after which the key is rejected, the key never expires if not set
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub expiry_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeyResponse {
+    pub id: i64,
+    pub username: String,
+    pub label: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub restricted: bool,
+    pub create_timestamp: String,
+    pub expiry_timestamp: Option<String>,
+    pub revocation_timestamp: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct IssuedApiKeyResponse {
+    /// The API key itself, it is not stored and cannot be retrieved later
+    pub api_key: String,
+
+    /// Secret the mint webhooks of the key are signed with, it cannot be retrieved later either.
+    /// Not set if the webhook secrets of the issued keys aren't configured, then the key cannot register callbacks.
+    pub webhook_secret: Option<String>,
+
+    #[serde(flatten)]
+    pub info: ApiKeyResponse,
+}
+
+/// Issues a new API key, requires the `admin` scope.
+#[utoipa::path(
+    tag = "api-keys",
+    request_body = IssueApiKeyRequest,
+    responses(
+        (status = 201, description = "API key issued", body = IssuedApiKeyResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key doesn't have the admin scope", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+)]
+#[post("/admin/api-keys")]
+pub async fn issue_api_key(
+    api_key: ApiKeyExtractor,
+    req: web::Json<IssueApiKeyRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::Admin)?;
+
+    if req.username.is_empty() {
+        return Err(ApiError::validation("username cannot be empty string"));
+    }
+    if req.scopes.is_empty() {
+        return Err(ApiError::validation("scopes cannot be empty"));
+    }
+    if req.expiry_timestamp.is_some_and(|expiry| expiry <= Utc::now()) {
+        return Err(ApiError::validation("expiry timestamp must be in the future"));
+    }
+
+    let (issued_api_key, api_key_info, webhook_secret) = state
+        .api_key_service
+        .issue_api_key(
+            &Username::new(req.username.as_str()),
+            &req.label,
+            &req.scopes,
+            req.restricted,
+            req.expiry_timestamp.map(|expiry| expiry.naive_utc()),
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(IssuedApiKeyResponse {
+        api_key: issued_api_key,
+        webhook_secret,
+        info: api_key_info.into(),
+    }))
+}
+
+/// Lists the issued API keys, including the revoked and expired ones. Requires the `admin` scope.
+#[utoipa::path(
+    tag = "api-keys",
+    responses(
+        (status = 200, description = "Issued API keys", body = Vec<ApiKeyResponse>),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key doesn't have the admin scope", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+)]
+#[get("/admin/api-keys")]
+pub async fn list_api_keys(api_key: ApiKeyExtractor, state: web::Data<AppState>) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::Admin)?;
+
+    let resp = state
+        .api_key_service
+        .list_api_keys()
+        .await?
+        .into_iter()
+        .map(ApiKeyResponse::from)
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Revokes the API key, requires the `admin` scope.
+#[utoipa::path(
+    tag = "api-keys",
+    params(("id" = i64, Path, description = "ID of the API key")),
+    responses(
+        (status = 200, description = "API key revoked", body = ApiKeyResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key doesn't have the admin scope", body = ErrorResponse),
+        (status = 404, description = "API key not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+)]
+#[delete("/admin/api-keys/{id}")]
+pub async fn revoke_api_key(
+    api_key: ApiKeyExtractor,
+    id: web::Path<i64>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::Admin)?;
+
+    let api_key_info = state
+        .api_key_service
+        .revoke_api_key(id.into_inner())
+        .await?
+        .ok_or(ApiError::ApiKeyNotFound)?;
+
+    Ok(HttpResponse::Ok().json(ApiKeyResponse::from(api_key_info)))
+}
+
+impl From<ApiKeyInfo> for ApiKeyResponse {
+    fn from(api_key_info: ApiKeyInfo) -> Self {
+        ApiKeyResponse {
+            id: api_key_info.id,
+            username: api_key_info.username.inner(),
+            label: api_key_info.label,
+            scopes: api_key_info.scopes,
+            restricted: api_key_info.restricted,
+            create_timestamp: format_timestamp(api_key_info.create_timestamp),
+            expiry_timestamp: api_key_info.expiry_timestamp.map(format_timestamp),
+            revocation_timestamp: api_key_info.revocation_timestamp.map(format_timestamp),
+        }
+    }
+}
+
+// Postgres timestamp keep 6 digits fraction of a second
+fn format_timestamp(timestamp: NaiveDateTime) -> String {
+    timestamp.format("%Y-%m-%d %H:%M:%S%.6f").to_string()
+}
@@ -1,11 +1,20 @@
 use crate::rest::auth::ApiKeyExtractor;
 use actix_web::{get, HttpResponse, Responder};
 
+#[utoipa::path(tag = "health", responses((status = 200, description = "Server is up")))]
 #[get("/health")]
 pub async fn health() -> impl Responder {
     HttpResponse::Ok().body("Server is ok.")
 }
 
+#[utoipa::path(
+    tag = "health",
+    responses(
+        (status = 200, description = "Server is up and the API key is valid"),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+    ),
+    security(("api_key" = [])),
+)]
 #[get("/secured_health")]
 pub async fn secured_health(_: ApiKeyExtractor) -> impl Responder {
     HttpResponse::Ok().body("Server is ok.")
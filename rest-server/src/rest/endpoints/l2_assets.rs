@@ -1,29 +1,47 @@
-use std::ops::Deref;
-
+use actix_multipart::{Field, Multipart};
 use actix_web::{
-    body::BoxBody,
-    get,
-    http::{header::ContentType, StatusCode},
-    post, put, web, HttpResponse, Responder,
+    delete, get,
+    http::{
+        header::{self, ContentType},
+        StatusCode,
+    },
+    post, put, web, HttpRequest, HttpResponse, ResponseError,
 };
+use entities::api_key::ApiKeyScope;
 use entities::dto::AssetMintStatus;
 use entities::l2::PublicKey;
+use entities::webhook::MintCallback;
+use futures::TryStreamExt;
 use interfaces::{
-    asset_service::{L1MintError, L2AssetInfo},
+    asset_service::{L2AssetInfo, NewL2Asset},
     l1_service::L1MintTransactionError,
-    l2_storage::L2StorageError,
+    rate_limit::{ConsumedQuota, QuotaOperation},
 };
-use serde::{Deserialize, Deserializer, Serialize};
+use mime_guess::{mime, Mime};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use serde_json::json;
+use service::webhook_sender::resolve_callback_url;
+use std::collections::{HashMap, HashSet};
+use util::nft_json::validate_metadata_json;
 use util::publickey::PublicKeyExt;
+use util::wallet_signature::request_digest;
+use utoipa::{IntoParams, ToSchema};
 
-use crate::rest::{auth::ApiKeyExtractor, marshalling, web_app::AppState};
+use crate::rest::{
+    auth::{wallet_signature_from_request, ApiKeyExtractor},
+    errors::{ApiError, ErrorResponse},
+    idempotency::idempotent,
+    marshalling,
+    rate_limit::{check_client_ip, ClientIpRateLimit},
+    web_app::AppState,
+};
 
-const ASSET_NOT_FOUND: &str = "No asset found with given ID";
 const ROYALTY_BASIS_POINTS_MAX_VALUE: u16 = 10_000;
+const BINARY_FILE_FIELD_NAME: &str = "file";
+const SUPPORTED_BINARY_MIME_TYPES: [&str; 4] = ["image", "video", "audio", "model"];
 
 /// Request object for creating an L2 asset
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateAssetRequest {
     /// NFT asset name
     pub name: String,
@@ -47,18 +65,82 @@ pub struct CreateAssetRequest {
     pub collection: Option<String>,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+/// Request object for creating L2 assets in bulk
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateAssetsBatchRequest {
+    /// Up to `max_asset_batch_size` assets
+    pub assets: Vec<CreateAssetRequest>,
+}
+
+/// Results of the bulk creation, in the order of the requested assets
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateAssetsBatchResponse {
+    pub results: Vec<CreatedAssetResult>,
+}
+
+/// Either the created asset or the reason it is not created
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatedAssetResult {
+    /// Status `POST /asset` would respond with for the asset
+    pub status: u16,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub asset: Option<serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+impl CreatedAssetResult {
+    fn created(asset: serde_json::Value) -> Self {
+        Self { status: StatusCode::CREATED.as_u16(), asset: Some(asset), error: None }
+    }
+
+    fn failed(e: ApiError) -> Self {
+        Self {
+            status: e.status_code().as_u16(),
+            asset: None,
+            error: Some(e.to_error_response()),
+        }
+    }
+}
+
+/// Request object for updating an L2 asset, only the provided fields are changed
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 #[serde(default)]
 pub struct UpdateAssetRequest {
     pub name: Option<String>,
     pub metadata_json: Option<String>,
+    /// Not updatable, the request is rejected if it's set.
+    /// The owner is changed by the `POST /asset/{pubkey}/transfer` endpoint
     pub owner: Option<String>,
     pub creator: Option<String>,
     pub authority: Option<String>,
+    /// Base58 encoded public key of a new collection, `null` removes the asset from its collection
     #[serde(deserialize_with = "deserialize_optional_field")]
+    #[schema(value_type = Option<String>)]
     pub collection: Option<Option<String>>,
 }
 
+/// Request object for transferring an L2 asset to a new owner
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransferAssetRequest {
+    /// Current owner of the asset, the transfer is refused if the actual owner differs
+    pub from: String,
+
+    /// New owner of the asset
+    pub to: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AssetTransferResponse {
+    pub from: String,
+    pub to: String,
+    pub timestamp: String,
+    pub username: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct L2AssetInfoResponse {
     pub pubkey: String,
@@ -71,91 +153,396 @@ pub struct L2AssetInfoResponse {
     pub medata_json: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct L1MintRequest {
     /// BASE64 encoded bincode serialized solana transaction
     pub tx: String,
+    /// URL that receives a webhook once the mint is finalized or rolled back
     pub callback: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MintTransactionQuery {
+    /// Solana account that pays for the mint
+    pub payer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MintTransactionResponse {
+    /// BASE64 encoded bincode serialized unsigned solana transaction
+    pub tx: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct MintStatusResponse {
     pub status: AssetMintStatus,
     pub signature: Option<String>,
 }
 
-/// Creates an L2 asset.
+/// Multipart form of the binary file upload, only describes the payload in the OpenAPI document
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct BinaryUploadForm {
+    /// Image, video, audio or 3D model file
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+/// Wallet signature of an asset mutation, only describes the headers in the OpenAPI document.
+/// The signature is required if the server is configured so, otherwise it is only verified when provided.
+#[allow(dead_code)]
+#[derive(IntoParams)]
+#[into_params(parameter_in = Header, rename_all = "kebab-case")]
+pub struct WalletSignatureHeaders {
+    /// Base58 encoded ed25519 signature of `{request_digest}.{nonce}.{expiry}` by the asset authority
+    /// (or owner for transfers), where `request_digest` is hex encoded SHA-256 of `{METHOD} {path}\n{body}`
+    pub x_wallet_signature: Option<String>,
+    /// Unique per signer string of up to 64 characters
+    pub x_wallet_signature_nonce: Option<String>,
+    /// Unix timestamp in seconds, after which the signature is rejected
+    pub x_wallet_signature_expiry: Option<i64>,
+}
+
+/// Idempotency key of a request, only describes the header in the OpenAPI document.
+/// The key requires an API key and is scoped by its user.
+#[allow(dead_code)]
+#[derive(IntoParams)]
+#[into_params(parameter_in = Header, rename_all = "kebab-case")]
+pub struct IdempotencyKeyHeader {
+    /// Unique string of up to 255 characters, the successful response to the request is replayed
+    /// to the retries with the same key for `idempotency_key_ttl_secs`
+    pub idempotency_key: Option<String>,
+}
+
+/// Wallet that has to sign the asset mutation
+enum AssetSigner {
+    Authority,
+    Owner,
+}
+
+/// Creates an L2 asset, requires the `assets:create` scope.
+/// The authority of the asset created by a restricted API key belongs to the key,
+/// and the key can only add the asset to the collections whose authority it owns.
+/// Retries with the same `Idempotency-Key` get the originally created asset.
+#[utoipa::path(
+    tag = "assets",
+    params(IdempotencyKeyHeader),
+    request_body = CreateAssetRequest,
+    responses(
+        (status = 201, description = "Asset created", body = serde_json::Value),
+        (status = 400, description = "Invalid request or metadata JSON", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Missing scope, the authority or the collection belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Idempotency key is reused or its request is in progress", body = ErrorResponse),
+        (status = 429, description = "Too many requests or daily asset quota is exhausted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:create"])),
+)]
 #[post("/asset")]
 pub async fn create_asset(
-    _: ApiKeyExtractor,
+    api_key: ApiKeyExtractor,
+    http_req: HttpRequest,
     req: web::Json<CreateAssetRequest>,
     state: web::Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsCreate)?;
+
+    let username = api_key.username().clone();
+    let handler_state = state.clone();
+    idempotent(&http_req, &state, Some(&username), req.into_inner(), move |req| async move {
+        let state = handler_state;
+        let (new_asset, consumed_quota) = prepare_new_asset(&api_key, &req, &state).await?;
+
+        let asset_info = match state
+            .asset_service
+            .create_asset(
+                &new_asset.metadata_json,
+                &new_asset.owner,
+                &new_asset.creator,
+                &new_asset.authority,
+                &new_asset.name,
+                new_asset.royalty_basis_points,
+                new_asset.collection,
+            )
+            .await
+        {
+            Ok(asset_info) => asset_info,
+            Err(e) => {
+                state
+                    .rate_limit_service
+                    .refund_daily_quota(consumed_quota.as_ref(), 1)
+                    .await;
+                return Err(e.into());
+            }
+        };
+
+        let dto = state.asset_converter.to_response_asset_dto(asset_info);
+        Ok(HttpResponse::Created()
+            .content_type(ContentType::json())
+            .body(json!(dto).to_string()))
+    })
+    .await
+}
+
+/// Creates up to `max_asset_batch_size` L2 assets at once, requires the `assets:create` scope.
+/// Every asset is processed as by `POST /asset`, the failure of one asset doesn't prevent the others
+/// from being created and is reported in its result.
+/// The daily quota is consumed for the valid assets at once, the batch is rejected if they don't fit into it.
+#[utoipa::path(
+    tag = "assets",
+    params(IdempotencyKeyHeader),
+    request_body = CreateAssetsBatchRequest,
+    responses(
+        (status = 200, description = "Results of the assets, some of them may fail", body = CreateAssetsBatchResponse),
+        (status = 400, description = "Empty or too large batch", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "API key doesn't have the assets:create scope", body = ErrorResponse),
+        (status = 409, description = "Idempotency key is reused or its request is in progress", body = ErrorResponse),
+        (status = 429, description = "Too many requests or daily asset quota is exhausted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:create"])),
+)]
+#[post("/assets/batch")]
+pub async fn create_assets_batch(
+    api_key: ApiKeyExtractor,
+    http_req: HttpRequest,
+    req: web::Json<CreateAssetsBatchRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsCreate)?;
+
+    if req.assets.is_empty() {
+        return Err(ApiError::validation("assets cannot be empty"));
+    }
+    if req.assets.len() > state.max_asset_batch_size {
+        return Err(ApiError::validation(format!(
+            "Batch is too large. Up to '{}' assets are supported.",
+            state.max_asset_batch_size
+        )));
+    }
+
+    let username = api_key.username().clone();
+    let handler_state = state.clone();
+    idempotent(&http_req, &state, Some(&username), req.into_inner(), move |req| async move {
+        let state = handler_state;
+        let mut results = Vec::with_capacity(req.assets.len());
+        let mut new_assets = Vec::with_capacity(req.assets.len());
+        let mut allowed_collections = HashSet::new();
+        for (index, asset_req) in req.assets.iter().enumerate() {
+            let new_asset = match validate_new_asset(asset_req, &state) {
+                Ok(new_asset) => new_asset,
+                Err(e) => {
+                    results.push((index, CreatedAssetResult::failed(e)));
+                    continue;
+                }
+            };
+            // Every collection is checked once, unless it fails
+            if let Some(collection) = new_asset.collection.filter(|c| !allowed_collections.contains(c)) {
+                match authorize_collection_membership(&api_key, collection, &state).await {
+                    Ok(()) => {
+                        allowed_collections.insert(collection);
+                    }
+                    Err(e @ (ApiError::CollectionNotFound | ApiError::ForeignAuthority)) => {
+                        results.push((index, CreatedAssetResult::failed(e)));
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            new_assets.push((index, new_asset));
+        }
+
+        // Every authority is claimed once, the assets with an authority of another key fail
+        let mut claimed_authorities = HashMap::new();
+        for new_asset in new_assets.iter().map(|(_, new_asset)| new_asset) {
+            if !claimed_authorities.contains_key(&new_asset.authority) {
+                let claimed = match api_key
+                    .claim_authority(&state.api_key_service, &new_asset.authority)
+                    .await
+                {
+                    Ok(()) => true,
+                    Err(ApiError::ForeignAuthority) => false,
+                    Err(e) => return Err(e),
+                };
+                claimed_authorities.insert(new_asset.authority.clone(), claimed);
+            }
+        }
+        let (new_assets, foreign_assets): (Vec<_>, Vec<_>) = new_assets
+            .into_iter()
+            .partition(|(_, new_asset)| claimed_authorities[&new_asset.authority]);
+        results.extend(
+            foreign_assets
+                .into_iter()
+                .map(|(index, _)| (index, CreatedAssetResult::failed(ApiError::ForeignAuthority))),
+        );
+
+        // The quota is consumed at once, so the batch is either created in full or rejected,
+        // the assets failed to be created are refunded
+        let consumed_quota = state
+            .rate_limit_service
+            .consume_daily_quota(api_key.username(), QuotaOperation::AssetCreation, new_assets.len() as u32)
+            .await?;
+
+        let (indexes, new_assets): (Vec<usize>, Vec<NewL2Asset>) = new_assets.into_iter().unzip();
+        let created = match state.asset_service.create_assets_batch(new_assets).await {
+            Ok(created) => created,
+            Err(e) => {
+                state
+                    .rate_limit_service
+                    .refund_daily_quota(consumed_quota.as_ref(), indexes.len() as u32)
+                    .await;
+                return Err(e.into());
+            }
+        };
+        let failed = created.iter().filter(|result| result.is_err()).count();
+        state
+            .rate_limit_service
+            .refund_daily_quota(consumed_quota.as_ref(), failed as u32)
+            .await;
+        results.extend(indexes.into_iter().zip(created).map(|(index, result)| {
+            let result = match result {
+                Ok(asset_info) => {
+                    CreatedAssetResult::created(json!(state.asset_converter.to_response_asset_dto(asset_info)))
+                }
+                Err(e) => CreatedAssetResult::failed(e.into()),
+            };
+            (index, result)
+        }));
+        results.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(HttpResponse::Ok()
+            .json(CreateAssetsBatchResponse { results: results.into_iter().map(|(_, result)| result).collect() }))
+    })
+    .await
+}
+
+/// Validates the request, claims the asset authority for the restricted API key
+/// and counts the asset against the daily quota of the user, the consumed quota is returned to be refunded on failure.
+async fn prepare_new_asset(
+    api_key: &ApiKeyExtractor,
+    req: &CreateAssetRequest,
+    state: &AppState,
+) -> Result<(NewL2Asset, Option<ConsumedQuota>), ApiError> {
+    let new_asset = validate_new_asset(req, state)?;
+
+    if let Some(collection) = new_asset.collection {
+        authorize_collection_membership(api_key, collection, state).await?;
+    }
+    api_key
+        .claim_authority(&state.api_key_service, &new_asset.authority)
+        .await?;
+    let consumed_quota = state
+        .rate_limit_service
+        .consume_daily_quota(api_key.username(), QuotaOperation::AssetCreation, 1)
+        .await?;
+
+    Ok((new_asset, consumed_quota))
+}
+
+/// Checks that the collection exists and the API key is allowed to add assets to it,
+/// a restricted key is only allowed to add them to the collections whose authority it created,
+/// otherwise it could make its assets look like verified members of another collection.
+async fn authorize_collection_membership(
+    api_key: &ApiKeyExtractor,
+    collection: PublicKey,
+    state: &AppState,
+) -> Result<(), ApiError> {
+    let collection_info = state
+        .asset_service
+        .fetch_collection(collection)
+        .await?
+        .ok_or(ApiError::CollectionNotFound)?;
+
+    api_key
+        .check_authority(&state.api_key_service, &collection_info.collection.authority)
+        .await
+}
+
+/// Validates the request along with its metadata JSON
+fn validate_new_asset(req: &CreateAssetRequest, state: &AppState) -> Result<NewL2Asset, ApiError> {
     if req.owner.is_empty() {
-        return bad_request("owner cannot be empty string");
+        return Err(ApiError::validation("owner cannot be empty string"));
     };
     if req.creator.is_empty() {
-        return bad_request("creator cannot be empty string");
+        return Err(ApiError::validation("creator cannot be empty string"));
     };
     if req.authority.is_empty() {
-        return bad_request("authority cannot be empty string");
+        return Err(ApiError::validation("authority cannot be empty string"));
     };
     let royalty_basis_points = if req.royalty_basis_points > ROYALTY_BASIS_POINTS_MAX_VALUE {
-        return bad_request("royalty basis points cannot be greater than '10 000'");
+        return Err(ApiError::validation("royalty basis points cannot be greater than '10 000'"));
     } else {
         req.royalty_basis_points
     };
     let collection = if let Some(collection_str) = &req.collection {
         let Some(collection) = PublicKey::from_bs58(collection_str) else {
-            return bad_request("collection contains malformed public key");
+            return Err(ApiError::validation("collection contains malformed public key"));
         };
         Some(collection)
     } else {
         None
     };
+    validate_metadata_json(&req.metadata_json, state.max_json_metadata_size)?;
 
-    match state
-        .asset_service
-        .create_asset(
-            &req.metadata_json,
-            req.owner.deref(),
-            req.creator.deref(),
-            req.authority.deref(),
-            &req.name,
-            royalty_basis_points,
-            collection,
-        )
-        .await
-    {
-        Ok(L2AssetInfo { asset, metadata }) => {
-            let dto = state.asset_converter.to_response_asset_dto(&asset, metadata);
-            HttpResponse::Created()
-                .content_type(ContentType::json())
-                .body(json!(dto).to_string())
-        }
-        Err(e) => internal_server_error(Some(&e.to_string())),
-    }
+    Ok(NewL2Asset {
+        metadata_json: req.metadata_json.clone(),
+        owner: req.owner.clone(),
+        creator: req.creator.clone(),
+        authority: req.authority.clone(),
+        name: req.name.clone(),
+        royalty_basis_points,
+        collection,
+    })
 }
 
+/// Updates an L2 asset, requires the `assets:update` scope and the signature of the asset authority wallet.
+/// A restricted API key can only move the asset to the collections whose authority it owns.
+#[utoipa::path(
+    tag = "assets",
+    params(
+        ("pubkey" = String, Path, description = "Base58 encoded public key of the asset"),
+        WalletSignatureHeaders
+    ),
+    request_body = UpdateAssetRequest,
+    responses(
+        (status = 200, description = "Asset updated", body = serde_json::Value),
+        (status = 400, description = "Invalid request or metadata JSON", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key or wallet signature", body = ErrorResponse),
+        (status = 403, description = "Missing scope, the asset authority or the collection belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Asset or collection not found", body = ErrorResponse),
+        (status = 409, description = "Asset is burnt, being minted, minted on L1 or its authority has changed", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:update"])),
+)]
 #[put("/asset/{pubkey}")]
 pub async fn update_asset(
-    _: ApiKeyExtractor,
+    api_key: ApiKeyExtractor,
     asset_pubkey: web::Path<String>,
-    req: web::Json<UpdateAssetRequest>,
+    http_req: HttpRequest,
+    body: web::Bytes,
     state: web::Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsUpdate)?;
+
     let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
-        return bad_request("Invalid asset public key");
+        return Err(ApiError::validation("Invalid asset public key"));
     };
+    let req: UpdateAssetRequest = parse_json_body(&body)?;
 
-    if req.owner.as_ref().map(|v| v.is_empty()).unwrap_or(false) {
-        return bad_request("owner cannot be empty string");
+    if req.owner.is_some() {
+        return Err(ApiError::validation("owner cannot be updated, use POST /asset/{pubkey}/transfer to change it"));
     }
     if req.creator.as_ref().map(|v| v.is_empty()).unwrap_or(false) {
-        return bad_request("creator cannot be empty string");
+        return Err(ApiError::validation("creator cannot be empty string"));
     }
     if req.authority.as_ref().map(|v| v.is_empty()).unwrap_or(false) {
-        return bad_request("authority cannot be empty string");
+        return Err(ApiError::validation("authority cannot be empty string"));
     }
 
     // None - don't touch collection during the update
@@ -167,159 +554,658 @@ pub async fn update_asset(
         .map(|op| op.as_ref().map(|v| PublicKey::from_bs58(v)))
     {
         Some(Some(Some(v))) => Some(Some(v)),
-        Some(Some(None)) => return bad_request("collection contains malformed public key"),
+        Some(Some(None)) => return Err(ApiError::validation("collection contains malformed public key")),
         Some(None) => Some(None),
         None => None,
     };
 
-    match state
+    let authorized_authority =
+        authorize_asset_mutation(&api_key, pubkey, AssetSigner::Authority, &http_req, &body, &state).await?;
+    if let Some(Some(collection)) = collection {
+        authorize_collection_membership(&api_key, collection, &state).await?;
+    }
+    if let Some(authority) = &req.authority {
+        api_key.claim_authority(&state.api_key_service, authority).await?;
+    }
+
+    let asset_info = state
         .asset_service
         .update_asset(
             pubkey,
+            authorized_authority.as_deref(),
             req.metadata_json.as_deref(),
-            req.owner.clone(),
             req.creator.clone(),
             req.authority.clone(),
             req.name.as_deref(),
             collection,
         )
-        .await
-    {
-        Ok(mayble_l2) => match mayble_l2 {
-            Some(L2AssetInfo { asset, metadata }) => {
-                let dto = state.asset_converter.to_response_asset_dto(&asset, metadata);
-                HttpResponse::Ok()
-                    .content_type(ContentType::json())
-                    .body(json!(dto).to_string())
-            }
-            None => bad_request(ASSET_NOT_FOUND),
-        },
-        Err(e) => internal_server_error(Some(&e.to_string())),
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    let dto = state.asset_converter.to_response_asset_dto(asset_info);
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(dto).to_string()))
+}
+
+/// Burns an L2 asset, requires the `assets:update` scope and the signature of the asset authority wallet.
+#[utoipa::path(
+    tag = "assets",
+    params(
+        ("pubkey" = String, Path, description = "Base58 encoded public key of the asset"),
+        WalletSignatureHeaders
+    ),
+    responses(
+        (status = 200, description = "Asset burnt", body = serde_json::Value),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key or wallet signature", body = ErrorResponse),
+        (status = 403, description = "Missing scope or the asset authority belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset is already burnt, being minted or minted on L1", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:update"])),
+)]
+#[delete("/asset/{pubkey}")]
+pub async fn burn_asset(
+    api_key: ApiKeyExtractor,
+    asset_pubkey: web::Path<String>,
+    http_req: HttpRequest,
+    body: web::Bytes,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsUpdate)?;
+
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+
+    authorize_asset_mutation(&api_key, pubkey, AssetSigner::Authority, &http_req, &body, &state).await?;
+
+    let asset_info = state
+        .asset_service
+        .burn_asset(pubkey)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    let dto = state.asset_converter.to_response_asset_dto(asset_info);
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(dto).to_string()))
+}
+
+/// Transfers an L2 asset, requires the `assets:update` scope and the signature of the asset owner wallet.
+#[utoipa::path(
+    tag = "assets",
+    params(
+        ("pubkey" = String, Path, description = "Base58 encoded public key of the asset"),
+        WalletSignatureHeaders
+    ),
+    request_body = TransferAssetRequest,
+    responses(
+        (status = 200, description = "Asset transferred", body = serde_json::Value),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key or wallet signature", body = ErrorResponse),
+        (status = 403, description = "Missing scope or the asset authority belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset has another owner, is burnt or is not in L2", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:update"])),
+)]
+#[post("/asset/{pubkey}/transfer")]
+pub async fn transfer_asset(
+    api_key: ApiKeyExtractor,
+    asset_pubkey: web::Path<String>,
+    http_req: HttpRequest,
+    body: web::Bytes,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsUpdate)?;
+
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+    let req: TransferAssetRequest = parse_json_body(&body)?;
+
+    if PublicKey::from_bs58(&req.from).is_none() {
+        return Err(ApiError::validation("Invalid from public key"));
+    }
+    if PublicKey::from_bs58(&req.to).is_none() {
+        return Err(ApiError::validation("Invalid to public key"));
     }
+
+    authorize_asset_mutation(&api_key, pubkey, AssetSigner::Owner, &http_req, &body, &state).await?;
+
+    let asset_info = state
+        .asset_service
+        .transfer_asset(pubkey, &req.from, &req.to, &api_key.username().inner())
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    let dto = state.asset_converter.to_response_asset_dto(asset_info);
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(dto).to_string()))
+}
+
+#[utoipa::path(
+    tag = "assets",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the asset")),
+    responses(
+        (status = 200, description = "Transfers of the asset", body = Vec<AssetTransferResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[get("/asset/{pubkey}/transfers")]
+pub async fn get_transfers(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+
+    let resp = state
+        .asset_service
+        .fetch_transfers(pubkey)
+        .await?
+        .into_iter()
+        .map(|transfer| AssetTransferResponse {
+            from: transfer.from,
+            to: transfer.to,
+            timestamp: transfer.timestamp.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+            username: transfer.username,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().content_type(ContentType::json()).json(resp))
 }
 
+#[utoipa::path(
+    tag = "assets",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the asset")),
+    responses(
+        (status = 200, description = "Asset", body = serde_json::Value),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
 #[get("/asset/{pubkey}")]
-pub async fn get_asset(asset_pubkey: web::Path<String>, state: web::Data<AppState>) -> impl Responder {
+pub async fn get_asset(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
     let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
-        return bad_request("Invalid asset public key");
+        return Err(ApiError::validation("Invalid asset public key"));
     };
 
-    match state.asset_service.fetch_asset(pubkey).await {
-        Ok(mayble_l2) => match mayble_l2 {
-            Some(L2AssetInfo { asset, metadata }) => {
-                let dto = state.asset_converter.to_response_asset_dto(&asset, metadata);
-                HttpResponse::Ok()
-                    .content_type(ContentType::json())
-                    .body(json!(dto).to_string())
-            }
-            None => bad_request(ASSET_NOT_FOUND),
-        },
-        Err(e) => internal_server_error(Some(&e.to_string())),
-    }
+    let asset_info = state
+        .asset_service
+        .fetch_asset(pubkey)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    let dto = state.asset_converter.to_response_asset_dto(asset_info);
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(dto).to_string()))
 }
 
+#[utoipa::path(
+    tag = "assets",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the asset")),
+    responses(
+        (status = 200, description = "Metadata JSON of the asset", content_type = "application/json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
 #[get("/asset/{pubkey}/metadata.json")]
-pub async fn get_metadata(asset_pubkey: web::Path<String>, state: web::Data<AppState>) -> impl Responder {
+pub async fn get_metadata(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
     let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
-        return bad_request("Invalid asset public key");
+        return Err(ApiError::validation("Invalid asset public key"));
     };
 
-    match state.asset_service.fetch_metadata(pubkey).await {
-        Ok(mayble_metadata) => match mayble_metadata {
-            Some(metadata) => HttpResponse::Ok().content_type(ContentType::json()).body(metadata),
-            None => bad_request(ASSET_NOT_FOUND),
-        },
-        Err(e) => internal_server_error(Some(&e.to_string())),
+    let metadata = state
+        .asset_service
+        .fetch_metadata(pubkey)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::json()).body(metadata))
+}
+
+/// Uploads binary file (image, video, etc.) of an L2 asset.
+/// Expects `multipart/form-data` payload with the file in the `file` field.
+/// The MIME type is taken from the field content type, or guessed from the file name.
+/// On success, the asset metadata JSON points to the uploaded file.
+/// Requires the `assets:update` scope and the signature of the asset authority wallet,
+/// the signed body is the content of the uploaded file.
+#[utoipa::path(
+    tag = "assets",
+    params(
+        ("pubkey" = String, Path, description = "Base58 encoded public key of the asset"),
+        WalletSignatureHeaders
+    ),
+    request_body(content = BinaryUploadForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Binary file uploaded", body = serde_json::Value),
+        (status = 400, description = "Invalid request or unsupported file type", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key or wallet signature", body = ErrorResponse),
+        (status = 403, description = "Missing scope or the asset authority belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset is burnt, being minted or minted on L1", body = ErrorResponse),
+        (status = 413, description = "File is too large", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:update"])),
+)]
+#[post("/asset/{pubkey}/binary")]
+pub async fn upload_binary(
+    api_key: ApiKeyExtractor,
+    asset_pubkey: web::Path<String>,
+    http_req: HttpRequest,
+    mut payload: Multipart,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsUpdate)?;
+
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+
+    let mut file = loop {
+        match payload.try_next().await {
+            Ok(Some(field)) if field.name() == Some(BINARY_FILE_FIELD_NAME) => break field,
+            Ok(Some(_)) => continue,
+            Ok(None) => return Err(ApiError::validation("No 'file' field in multipart payload")),
+            Err(e) => return Err(ApiError::validation(e.to_string())),
+        }
+    };
+
+    let Some(mime) = detect_mime(&file) else {
+        return Err(ApiError::validation("Unsupported file type"));
+    };
+
+    let mut bytes = Vec::new();
+    loop {
+        match file.try_next().await {
+            Ok(Some(chunk)) if bytes.len() + chunk.len() > state.max_binary_asset_size => {
+                return Err(ApiError::PayloadTooLarge(state.max_binary_asset_size));
+            }
+            Ok(Some(chunk)) => bytes.extend_from_slice(&chunk),
+            Ok(None) => break,
+            Err(e) => return Err(ApiError::validation(e.to_string())),
+        }
+    }
+
+    if bytes.is_empty() {
+        return Err(ApiError::validation("File cannot be empty"));
     }
+
+    authorize_asset_mutation(&api_key, pubkey, AssetSigner::Authority, &http_req, &bytes, &state).await?;
+
+    let asset_info = state
+        .asset_service
+        .upload_binary(pubkey, bytes, mime.essence_str())
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    let dto = state.asset_converter.to_response_asset_dto(asset_info);
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(dto).to_string()))
+}
+
+/// The file is served with the uploaded content type, so it's sandboxed and never sniffed,
+/// otherwise e.g. an uploaded SVG could run scripts on the API origin.
+#[utoipa::path(
+    tag = "assets",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the asset")),
+    responses(
+        (status = 200, description = "Binary file of the asset"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Asset or its binary file not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[get("/asset/{pubkey}/binary")]
+pub async fn get_binary(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+
+    let (bytes, mime) = state
+        .asset_service
+        .fetch_binary(pubkey)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(mime)
+        .insert_header((header::X_CONTENT_TYPE_OPTIONS, "nosniff"))
+        .insert_header((header::CONTENT_SECURITY_POLICY, "sandbox"))
+        .body(bytes))
+}
+
+/// This endpoint builds unsigned mint CreateV1 mpl-core transaction for the L2 asset, with a recent blockhash.
+/// The client only needs to sign it with the payer (and the authority) and send it to `/asset/mint`,
+/// which locks the asset and signs the transaction by the asset keypair.
+#[utoipa::path(
+    tag = "assets",
+    params(
+        ("pubkey" = String, Path, description = "Base58 encoded public key of the asset"),
+        MintTransactionQuery
+    ),
+    responses(
+        (status = 200, description = "Unsigned mint transaction", body = MintTransactionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset is locked, burnt or already minted", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[post("/asset/{pubkey}/mint-transaction")]
+pub async fn create_mint_transaction(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    query: web::Query<MintTransactionQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
+    };
+    let Some(payer) = PublicKey::from_bs58(&query.payer) else {
+        return Err(ApiError::validation("Invalid payer public key"));
+    };
+
+    let tx = state
+        .asset_service
+        .create_mint_transaction(pubkey, payer)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?;
+    let tx = marshalling::encode_transaction(&tx)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .json(MintTransactionResponse { tx }))
 }
 
 /// This endpoint accepts mint CreateV1 mpl-core transaction, that is fully populated
 /// and partially signed on the client side.
 /// The transaction is verified, signed on by the asset keypair and sent to Solana.
+#[utoipa::path(
+    tag = "assets",
+    params(IdempotencyKeyHeader),
+    request_body = L1MintRequest,
+    responses(
+        (status = 200, description = "Asset minted"),
+        (status = 400, description = "Invalid transaction or callback", body = ErrorResponse),
+        (status = 403, description = "Provided API key doesn't have the assets:mint scope", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset is locked or already minted, or the idempotency key is reused", body = ErrorResponse),
+        (status = 429, description = "Too many requests or daily mint quota is exhausted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+        (status = 504, description = "Mint transaction has been sent, but not confirmed yet", body = ErrorResponse),
+    ),
+    security((), ("api_key" = ["assets:mint"])),
+)]
 #[post("/asset/mint")]
-pub async fn mint_transaction(req: web::Json<L1MintRequest>, state: web::Data<AppState>) -> impl Responder {
-    let Ok(tx) = marshalling::decode_transaction(&req.0.tx) else {
-        return bad_request("Malformed transaction");
-    };
-    match state.asset_service.execute_asset_l1_mint(tx, true).await {
-        Ok(()) => HttpResponse::new(StatusCode::OK),
-        Err(e) => {
-            if let Some(e) = e.downcast_ref::<L1MintError>() {
-                bad_request(&e.to_string())
-            } else if let Some(e) = e.downcast_ref::<L2StorageError>() {
-                bad_request(&e.to_string())
-            } else if let Some(e) = e.downcast_ref::<L1MintTransactionError>() {
-                bad_request(&e.to_string())
-            } else {
-                internal_server_error(Some(&e.to_string()))
-            }
-        }
-    }
+pub async fn mint_transaction(
+    api_key: Result<ApiKeyExtractor, ApiError>,
+    http_req: HttpRequest,
+    req: web::Json<L1MintRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let api_key = optional_mint_api_key(api_key, &http_req)?;
+    let username = api_key.as_ref().map(|api_key| api_key.username().clone());
+
+    let handler_state = state.clone();
+    idempotent(&http_req, &state, username.as_ref(), req.into_inner(), move |req| async move {
+        execute_mint(api_key.as_ref(), req, true, &handler_state).await
+    })
+    .await
 }
 
 /// This endpoint accepts mint CreateV1 mpl-core transaction, that is fully populated
 /// and partially signed on the client side.
 /// The transaction is verified, signed on by the asset keypair and sent to Solana.
+/// If the `callback` is provided, it receives a webhook signed with the webhook secret of the API key user
+/// once the mint is finalized or rolled back.
+#[utoipa::path(
+    tag = "assets",
+    params(IdempotencyKeyHeader),
+    request_body = L1MintRequest,
+    responses(
+        (status = 200, description = "Mint transaction sent"),
+        (status = 400, description = "Invalid transaction or callback", body = ErrorResponse),
+        (status = 403, description = "Provided API key doesn't have the assets:mint scope", body = ErrorResponse),
+        (status = 404, description = "Asset not found", body = ErrorResponse),
+        (status = 409, description = "Asset is locked or already minted, or the idempotency key is reused", body = ErrorResponse),
+        (status = 429, description = "Too many requests or daily mint quota is exhausted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+        (status = 504, description = "Mint transaction has been sent, but not confirmed yet", body = ErrorResponse),
+    ),
+    security((), ("api_key" = ["assets:mint"])),
+)]
 #[post("/asset/mint-async")]
-pub async fn mint_transaction_async(req: web::Json<L1MintRequest>, state: web::Data<AppState>) -> impl Responder {
-    let Ok(tx) = marshalling::decode_transaction(&req.0.tx) else {
-        return bad_request("Malformed transaction");
+pub async fn mint_transaction_async(
+    api_key: Result<ApiKeyExtractor, ApiError>,
+    http_req: HttpRequest,
+    req: web::Json<L1MintRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let api_key = optional_mint_api_key(api_key, &http_req)?;
+    let username = api_key.as_ref().map(|api_key| api_key.username().clone());
+
+    let handler_state = state.clone();
+    idempotent(&http_req, &state, username.as_ref(), req.into_inner(), move |req| async move {
+        execute_mint(api_key.as_ref(), req, false, &handler_state).await
+    })
+    .await
+}
+
+#[utoipa::path(
+    tag = "assets",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the asset")),
+    responses(
+        (status = 200, description = "Mint status of the asset", body = MintStatusResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[get("/asset/mint/{pubkey}")]
+pub async fn mint_status(
+    _: ClientIpRateLimit,
+    asset_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
+        return Err(ApiError::validation("Invalid asset public key"));
     };
-    match state.asset_service.execute_asset_l1_mint(tx, false).await {
-        Ok(()) => HttpResponse::new(StatusCode::OK),
-        Err(e) => {
-            if let Some(e) = e.downcast_ref::<L1MintError>() {
-                bad_request(&e.to_string())
-            } else if let Some(e) = e.downcast_ref::<L2StorageError>() {
-                bad_request(&e.to_string())
-            } else if let Some(e) = e.downcast_ref::<L1MintTransactionError>() {
-                bad_request(&e.to_string())
-            } else {
-                internal_server_error(Some(&e.to_string()))
-            }
+
+    let (status, signature) = state.asset_service.get_mint_status(pubkey).await?;
+    let resp = MintStatusResponse { status, signature: signature.map(|signature| signature.to_string()) };
+
+    Ok(HttpResponse::Ok().content_type(ContentType::json()).json(resp))
+}
+
+/// API key is optional for the mint, the requests without it are throttled by the client IP.
+/// The provided key has to be valid and have the `assets:mint` scope.
+fn optional_mint_api_key(
+    api_key: Result<ApiKeyExtractor, ApiError>,
+    http_req: &HttpRequest,
+) -> Result<Option<ApiKeyExtractor>, ApiError> {
+    match api_key {
+        Ok(api_key) => {
+            api_key.require_scope(ApiKeyScope::AssetsMint)?;
+            Ok(Some(api_key))
+        }
+        Err(ApiError::MissingApiKey) => {
+            check_client_ip(http_req)?;
+            Ok(None)
         }
+        Err(e) => Err(e),
     }
 }
 
-#[get("/asset/mint/{pubkey}")]
-pub async fn mint_status(asset_pubkey: web::Path<String>, state: web::Data<AppState>) -> impl Responder {
-    let Some(pubkey) = PublicKey::from_bs58(&asset_pubkey) else {
-        return bad_request("Invalid asset public key");
+/// Executes the mint transaction received by the mint endpoints.
+/// The daily quota is refunded if the mint fails, unless the transaction has been sent and may still land.
+async fn execute_mint(
+    api_key: Option<&ApiKeyExtractor>,
+    req: L1MintRequest,
+    exec_sync: bool,
+    state: &AppState,
+) -> Result<HttpResponse, ApiError> {
+    let Ok(tx) = marshalling::decode_transaction(&req.tx) else {
+        return Err(L1MintTransactionError::MalformedTransaction.into());
+    };
+    let callback = make_mint_callback(req.callback.as_deref(), api_key, state).await?;
+    let consumed_quota = match api_key {
+        Some(api_key) => {
+            state
+                .rate_limit_service
+                .consume_daily_quota(api_key.username(), QuotaOperation::Mint, 1)
+                .await?
+        }
+        None => None,
     };
 
-    match state.asset_service.get_mint_status(pubkey).await {
-        Ok((status, signature)) => {
-            let resp = MintStatusResponse { status, signature: signature.map(|signature| signature.to_string()) };
-            HttpResponse::Ok().content_type(ContentType::json()).json(resp)
+    if let Err(e) = state.asset_service.execute_asset_l1_mint(tx, exec_sync, callback).await {
+        let e = ApiError::from(e);
+        if !e.has_taken_effect() {
+            state
+                .rate_limit_service
+                .refund_daily_quota(consumed_quota.as_ref(), 1)
+                .await;
         }
-        Err(e) => internal_server_error(Some(&e.to_string())),
+        return Err(e);
     }
+
+    Ok(HttpResponse::new(StatusCode::OK))
 }
 
-fn bad_request(msg: &str) -> HttpResponse {
-    // TODO: need to define common error message structure
-    let payload = json!({
-        "error": msg,
-    });
+/// Validates the callback URL provided along with the mint transaction.
+/// Webhooks are signed with the secret of the issued API key, or of the user of the key from the config,
+/// so the callback requires a valid API key.
+async fn make_mint_callback(
+    callback: Option<&str>,
+    api_key: Option<&ApiKeyExtractor>,
+    state: &AppState,
+) -> Result<Option<MintCallback>, ApiError> {
+    let Some(callback) = callback else {
+        return Ok(None);
+    };
+    if let Err(e) = resolve_callback_url(callback).await {
+        return Err(ApiError::validation(e.to_string()));
+    }
+    let Some(api_key) = api_key else {
+        return Err(ApiError::validation("Valid API key is required to register a callback"));
+    };
+    let has_webhook_secret = match api_key.stored_key_id() {
+        Some(_) => state.api_key_service.has_webhook_secrets(),
+        None => state.webhook_secrets.get_secret(api_key.username()).is_some(),
+    };
+    if !has_webhook_secret {
+        return Err(ApiError::validation("No webhook secret configured for the API key"));
+    }
 
-    HttpResponse::Ok()
-        .status(StatusCode::BAD_REQUEST)
-        .body(payload.to_string())
+    Ok(Some(MintCallback {
+        url: callback.to_string(),
+        username: api_key.username().inner(),
+        api_key_id: api_key.stored_key_id(),
+    }))
 }
 
-fn internal_server_error(msg: Option<&str>) -> HttpResponse {
-    let mut response = HttpResponse::new(StatusCode::INTERNAL_SERVER_ERROR);
-    if let Some(m) = msg {
-        let payload = json!({
-            "error": m,
-        });
-        response = response.set_body(BoxBody::new(payload.to_string()));
+/// Checks that the API key is allowed to modify the asset, and verifies the wallet signature of the request.
+/// The asset is only fetched if either of the checks needs it.
+/// Returns the asset authority the request has been authorized for, or `None` if no check was needed.
+async fn authorize_asset_mutation(
+    api_key: &ApiKeyExtractor,
+    pubkey: PublicKey,
+    signer: AssetSigner,
+    http_req: &HttpRequest,
+    body: &[u8],
+    state: &AppState,
+) -> Result<Option<String>, ApiError> {
+    let wallet_signature = wallet_signature_from_request(http_req)?;
+    if !api_key.is_restricted() && wallet_signature.is_none() && !state.wallet_signature_verifier.is_required() {
+        return Ok(None);
     }
-    response
+
+    let asset = state
+        .asset_service
+        .fetch_asset(pubkey)
+        .await?
+        .ok_or(ApiError::AssetNotFound)?
+        .asset;
+
+    api_key
+        .check_authority(&state.api_key_service, &asset.authority)
+        .await?;
+
+    let signer = match signer {
+        AssetSigner::Authority => &asset.authority,
+        AssetSigner::Owner => &asset.owner,
+    };
+    state
+        .wallet_signature_verifier
+        .verify(
+            signer,
+            &request_digest(http_req.method().as_str(), http_req.path(), body),
+            wallet_signature.as_ref(),
+        )
+        .await?;
+
+    Ok(Some(asset.authority))
+}
+
+/// The asset mutations take the raw body, since the wallet signature covers the exact bytes sent by the client
+fn parse_json_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, ApiError> {
+    serde_json::from_slice(body).map_err(|e| ApiError::validation(format!("Invalid JSON body: {e}")))
+}
+
+/// Takes MIME type from the multipart field content type,
+/// or guesses it from the file name if the content type is not specific.
+fn detect_mime(field: &Field) -> Option<Mime> {
+    field
+        .content_type()
+        .filter(|mime| **mime != mime::APPLICATION_OCTET_STREAM)
+        .cloned()
+        .or_else(|| {
+            field
+                .content_disposition()
+                .and_then(|content_disposition| content_disposition.get_filename())
+                .and_then(|file_name| mime_guess::from_path(file_name).first())
+        })
+        .filter(|mime| SUPPORTED_BINARY_MIME_TYPES.contains(&mime.type_().as_str()))
 }
 
 impl From<L2AssetInfo> for L2AssetInfoResponse {
-    fn from(L2AssetInfo { asset, metadata }: L2AssetInfo) -> Self {
+    fn from(L2AssetInfo { asset, metadata, .. }: L2AssetInfo) -> Self {
         L2AssetInfoResponse {
             pubkey: bs58::encode(asset.pubkey).into_string(),
             name: asset.name.clone(),
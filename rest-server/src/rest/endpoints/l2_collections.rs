@@ -0,0 +1,233 @@
+use actix_web::{get, http::header::ContentType, post, put, web, HttpResponse};
+use entities::api_key::ApiKeyScope;
+use entities::l2::PublicKey;
+use interfaces::asset_service::L2CollectionInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use service::converter::get_collection_metadata_uri_for_key;
+use util::publickey::PublicKeyExt;
+use utoipa::ToSchema;
+
+use crate::rest::{auth::ApiKeyExtractor, errors::ApiError, rate_limit::ClientIpRateLimit, web_app::AppState};
+
+/// Request object for creating an L2 collection
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateCollectionRequest {
+    /// Collection name
+    pub name: String,
+
+    /// NFT Metadata JSON of the collection
+    pub metadata_json: String,
+
+    /// Base58 encoded public key of the collection authority
+    pub authority: String,
+}
+
+/// Request object for updating an L2 collection, only the provided fields are changed
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct UpdateCollectionRequest {
+    pub name: Option<String>,
+    pub metadata_json: Option<String>,
+    pub authority: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CollectionResponse {
+    pub id: String,
+    pub name: String,
+    pub authority: String,
+    pub metadata_uri: String,
+    pub metadata_json: Option<String>,
+    pub create_timestamp: String,
+    pub update_timestamp: String,
+}
+
+/// Creates an L2 collection, requires the `assets:create` scope.
+/// The authority of the collection created by a restricted API key belongs to the key.
+#[utoipa::path(
+    tag = "collections",
+    request_body = CreateCollectionRequest,
+    responses(
+        (status = 201, description = "Collection created", body = CollectionResponse),
+        (status = 400, description = "Invalid request or metadata JSON", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Missing scope or the authority belongs to another API key", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:create"])),
+)]
+#[post("/collection")]
+pub async fn create_collection(
+    api_key: ApiKeyExtractor,
+    req: web::Json<CreateCollectionRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsCreate)?;
+
+    if req.name.is_empty() {
+        return Err(ApiError::validation("name cannot be empty string"));
+    };
+    if PublicKey::from_bs58(&req.authority).is_none() {
+        return Err(ApiError::validation("Invalid authority public key"));
+    };
+
+    api_key.claim_authority(&state.api_key_service, &req.authority).await?;
+
+    let collection_info = state
+        .asset_service
+        .create_collection(&req.metadata_json, &req.name, &req.authority)
+        .await?;
+
+    Ok(HttpResponse::Created()
+        .content_type(ContentType::json())
+        .body(json!(to_collection_response(collection_info, &state)).to_string()))
+}
+
+#[utoipa::path(
+    tag = "collections",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the collection")),
+    request_body = UpdateCollectionRequest,
+    responses(
+        (status = 200, description = "Collection updated", body = CollectionResponse),
+        (status = 400, description = "Invalid request or metadata JSON", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Missing scope or the collection authority belongs to another API key", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(("api_key" = ["assets:update"])),
+)]
+#[put("/collection/{pubkey}")]
+pub async fn update_collection(
+    api_key: ApiKeyExtractor,
+    collection_pubkey: web::Path<String>,
+    req: web::Json<UpdateCollectionRequest>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    api_key.require_scope(ApiKeyScope::AssetsUpdate)?;
+
+    let Some(pubkey) = PublicKey::from_bs58(&collection_pubkey) else {
+        return Err(ApiError::validation("Invalid collection public key"));
+    };
+
+    if req.name.as_ref().map(|v| v.is_empty()).unwrap_or(false) {
+        return Err(ApiError::validation("name cannot be empty string"));
+    }
+    if req
+        .authority
+        .as_ref()
+        .map(|v| PublicKey::from_bs58(v).is_none())
+        .unwrap_or(false)
+    {
+        return Err(ApiError::validation("Invalid authority public key"));
+    }
+
+    if api_key.is_restricted() {
+        let collection_info = state
+            .asset_service
+            .fetch_collection(pubkey)
+            .await?
+            .ok_or(ApiError::CollectionNotFound)?;
+        api_key
+            .check_authority(&state.api_key_service, &collection_info.collection.authority)
+            .await?;
+    }
+    if let Some(authority) = &req.authority {
+        api_key.claim_authority(&state.api_key_service, authority).await?;
+    }
+
+    let collection_info = state
+        .asset_service
+        .update_collection(pubkey, req.metadata_json.as_deref(), req.name.as_deref(), req.authority.clone())
+        .await?
+        .ok_or(ApiError::CollectionNotFound)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(to_collection_response(collection_info, &state)).to_string()))
+}
+
+#[utoipa::path(
+    tag = "collections",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the collection")),
+    responses(
+        (status = 200, description = "Collection", body = CollectionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[get("/collection/{pubkey}")]
+pub async fn get_collection(
+    _: ClientIpRateLimit,
+    collection_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&collection_pubkey) else {
+        return Err(ApiError::validation("Invalid collection public key"));
+    };
+
+    let collection_info = state
+        .asset_service
+        .fetch_collection(pubkey)
+        .await?
+        .ok_or(ApiError::CollectionNotFound)?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(json!(to_collection_response(collection_info, &state)).to_string()))
+}
+
+#[utoipa::path(
+    tag = "collections",
+    params(("pubkey" = String, Path, description = "Base58 encoded public key of the collection")),
+    responses(
+        (status = 200, description = "Metadata JSON of the collection", content_type = "application/json"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 429, description = "Too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+)]
+#[get("/collection/{pubkey}/metadata.json")]
+pub async fn get_collection_metadata(
+    _: ClientIpRateLimit,
+    collection_pubkey: web::Path<String>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let Some(pubkey) = PublicKey::from_bs58(&collection_pubkey) else {
+        return Err(ApiError::validation("Invalid collection public key"));
+    };
+
+    let metadata = state
+        .asset_service
+        .fetch_collection(pubkey)
+        .await?
+        .and_then(|collection_info| collection_info.metadata)
+        .ok_or(ApiError::CollectionNotFound)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::json()).body(metadata))
+}
+
+fn to_collection_response(
+    L2CollectionInfo { collection, metadata }: L2CollectionInfo,
+    state: &AppState,
+) -> CollectionResponse {
+    CollectionResponse {
+        id: collection.pubkey.to_bs58(),
+        name: collection.name,
+        authority: collection.authority,
+        metadata_uri: get_collection_metadata_uri_for_key(
+            &state.asset_converter.metadata_server_base_url,
+            collection.pubkey,
+        ),
+        metadata_json: metadata,
+        // Postgres timestamp keep 6 digits fraction of a second
+        create_timestamp: collection.create_timestamp.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+        update_timestamp: collection.update_timestamp.format("%Y-%m-%d %H:%M:%S%.6f").to_string(),
+    }
+}
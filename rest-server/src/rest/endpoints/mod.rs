@@ -1,2 +1,4 @@
+pub mod api_keys;
 pub mod health_check;
 pub mod l2_assets;
+pub mod l2_collections;
@@ -0,0 +1,312 @@
+use actix_web::{
+    http::{header::RETRY_AFTER, StatusCode},
+    HttpResponse, ResponseError,
+};
+use entities::api_key::ApiKeyScope;
+use interfaces::{
+    asset_service::{AssetBurnError, AssetTransferError, AssetUpdateError, L1MintError},
+    idempotency::IdempotencyError,
+    l1_service::L1MintTransactionError,
+    l2_storage::L2StorageError,
+    rate_limit::RateLimitError,
+    wallet_signature::WalletSignatureError,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::error;
+use util::nft_json::{InvalidMetadataError, JsonMetadataError};
+use util::rate_limiter::retry_after_secs;
+use utoipa::ToSchema;
+
+/// Error returned by the REST endpoints.
+/// Every variant is mapped to an HTTP status and a stable machine-readable code,
+/// internal errors are logged and replaced with a generic message.
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("{0}")]
+    Validation(String),
+    #[error("No API key provided")]
+    MissingApiKey,
+    #[error("Invalid API key")]
+    InvalidApiKey,
+    #[error("API key doesn't have the '{0}' scope")]
+    MissingScope(ApiKeyScope),
+    #[error("API key is not allowed to modify assets of the authority")]
+    ForeignAuthority,
+    #[error("No asset found with given ID")]
+    AssetNotFound,
+    #[error("No collection found with given ID")]
+    CollectionNotFound,
+    #[error("No API key found with given ID")]
+    ApiKeyNotFound,
+    #[error("File size exceeds the limit of '{0}' bytes")]
+    PayloadTooLarge(usize),
+    #[error(transparent)]
+    InvalidMetadata(#[from] InvalidMetadataError),
+    #[error(transparent)]
+    JsonMetadata(#[from] JsonMetadataError),
+    #[error(transparent)]
+    AssetBurn(#[from] AssetBurnError),
+    #[error(transparent)]
+    AssetUpdate(#[from] AssetUpdateError),
+    #[error(transparent)]
+    AssetTransfer(#[from] AssetTransferError),
+    #[error(transparent)]
+    L1Mint(#[from] L1MintError),
+    #[error(transparent)]
+    L1MintTransaction(#[from] L1MintTransactionError),
+    #[error(transparent)]
+    L2Storage(#[from] L2StorageError),
+    #[error(transparent)]
+    WalletSignature(#[from] WalletSignatureError),
+    #[error(transparent)]
+    RateLimit(#[from] RateLimitError),
+    #[error(transparent)]
+    Idempotency(#[from] IdempotencyError),
+    #[error("Internal server error")]
+    Internal(anyhow::Error),
+}
+
+/// Body of the error responses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ErrorResponse {
+    /// Machine-readable error code, e.g. `asset_not_found`
+    pub code: String,
+
+    /// Human-readable error message
+    pub error: String,
+
+    /// Violations of the NFT metadata standard, only present for `invalid_metadata` errors
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<String>,
+}
+
+impl ApiError {
+    pub fn validation(msg: impl Into<String>) -> Self {
+        Self::Validation(msg.into())
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::Validation(_) => "invalid_request",
+            ApiError::MissingApiKey => "missing_api_key",
+            ApiError::InvalidApiKey => "invalid_api_key",
+            ApiError::MissingScope(_) => "missing_scope",
+            ApiError::ForeignAuthority => "foreign_authority",
+            ApiError::AssetNotFound | ApiError::L2Storage(L2StorageError::L2AssetNotFound(_)) => "asset_not_found",
+            ApiError::CollectionNotFound => "collection_not_found",
+            ApiError::ApiKeyNotFound => "api_key_not_found",
+            ApiError::PayloadTooLarge(_) => "payload_too_large",
+            ApiError::InvalidMetadata(_) | ApiError::JsonMetadata(_) => "invalid_metadata",
+            ApiError::AssetBurn(AssetBurnError::Minting) => "asset_minting",
+            ApiError::AssetBurn(AssetBurnError::MintedOnL1) => "asset_minted_on_l1",
+            ApiError::AssetBurn(AssetBurnError::Burnt) => "asset_burnt",
+            ApiError::AssetUpdate(AssetUpdateError::AuthorityChanged(_)) => "authority_mismatch",
+            ApiError::AssetUpdate(AssetUpdateError::NotL2Asset) => "asset_not_in_l2",
+            ApiError::AssetTransfer(AssetTransferError::WrongOwner(_)) => "owner_mismatch",
+            ApiError::AssetTransfer(AssetTransferError::NotL2Asset) => "asset_not_in_l2",
+            ApiError::L1Mint(L1MintError::NotUnlockedL2Asset) => "asset_locked",
+            ApiError::L1Mint(L1MintError::NotConfirmed(_)) => "mint_not_confirmed",
+            ApiError::L1Mint(_) => "invalid_mint_transaction",
+            ApiError::L1MintTransaction(_) => "malformed_mint_transaction",
+            ApiError::WalletSignature(WalletSignatureError::Missing(_)) => "missing_wallet_signature",
+            ApiError::WalletSignature(
+                WalletSignatureError::Malformed(_)
+                | WalletSignatureError::InvalidSigner(_)
+                | WalletSignatureError::ExpiryTooFar(_),
+            ) => "malformed_wallet_signature",
+            ApiError::WalletSignature(WalletSignatureError::Expired) => "wallet_signature_expired",
+            ApiError::WalletSignature(WalletSignatureError::InvalidSignature) => "invalid_wallet_signature",
+            ApiError::WalletSignature(WalletSignatureError::ReplayedNonce) => "wallet_signature_replayed",
+            ApiError::RateLimit(RateLimitError::TooManyRequests { .. }) => "rate_limited",
+            ApiError::RateLimit(RateLimitError::QuotaExceeded { .. }) => "quota_exceeded",
+            ApiError::Idempotency(IdempotencyError::Malformed(_) | IdempotencyError::MissingApiKey) => {
+                "invalid_idempotency_key"
+            }
+            ApiError::Idempotency(IdempotencyError::KeyReused) => "idempotency_key_reused",
+            ApiError::Idempotency(IdempotencyError::InProgress) => "idempotent_request_in_progress",
+            ApiError::Internal(_) => "internal_error",
+        }
+    }
+
+    /// The request has taken effect despite the error, e.g. the mint transaction has been sent,
+    /// but its confirmation failed, so the request must not be repeated
+    pub fn has_taken_effect(&self) -> bool {
+        matches!(self, ApiError::L1Mint(L1MintError::NotConfirmed(_)))
+    }
+
+    /// Body of the response with the error, internal errors are logged
+    pub fn to_error_response(&self) -> ErrorResponse {
+        if let ApiError::Internal(e) = self {
+            error!("Internal server error: {e:#}");
+        }
+
+        ErrorResponse {
+            code: self.code().to_string(),
+            error: self.to_string(),
+            violations: self.violations(),
+        }
+    }
+
+    fn violations(&self) -> Vec<String> {
+        match self {
+            ApiError::InvalidMetadata(e) => e.0.iter().map(ToString::to_string).collect(),
+            ApiError::JsonMetadata(e) => vec![e.to_string()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Unwraps the errors of the service layer, that are meaningful for the client,
+/// anything else is treated as an internal error.
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        e.downcast::<InvalidMetadataError>()
+            .map(Into::into)
+            .or_else(|e| e.downcast::<JsonMetadataError>().map(Into::into))
+            .or_else(|e| e.downcast::<AssetBurnError>().map(Into::into))
+            .or_else(|e| e.downcast::<AssetUpdateError>().map(Into::into))
+            .or_else(|e| e.downcast::<AssetTransferError>().map(Into::into))
+            .or_else(|e| e.downcast::<L1MintError>().map(Into::into))
+            .or_else(|e| e.downcast::<L1MintTransactionError>().map(Into::into))
+            .or_else(|e| e.downcast::<L2StorageError>().map(Into::into))
+            .or_else(|e| e.downcast::<WalletSignatureError>().map(Into::into))
+            .or_else(|e| e.downcast::<RateLimitError>().map(Into::into))
+            .or_else(|e| e.downcast::<IdempotencyError>().map(Into::into))
+            .unwrap_or_else(ApiError::Internal)
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::L1Mint(L1MintError::NotUnlockedL2Asset) => StatusCode::CONFLICT,
+            ApiError::L1Mint(L1MintError::NotConfirmed(_)) => StatusCode::GATEWAY_TIMEOUT,
+            ApiError::Validation(_)
+            | ApiError::InvalidMetadata(_)
+            | ApiError::JsonMetadata(_)
+            | ApiError::L1Mint(_)
+            | ApiError::L1MintTransaction(_)
+            | ApiError::WalletSignature(
+                WalletSignatureError::Malformed(_)
+                | WalletSignatureError::InvalidSigner(_)
+                | WalletSignatureError::ExpiryTooFar(_),
+            )
+            | ApiError::Idempotency(IdempotencyError::Malformed(_) | IdempotencyError::MissingApiKey) => {
+                StatusCode::BAD_REQUEST
+            }
+            ApiError::MissingApiKey | ApiError::InvalidApiKey | ApiError::WalletSignature(_) => {
+                StatusCode::UNAUTHORIZED
+            }
+            ApiError::MissingScope(_) | ApiError::ForeignAuthority => StatusCode::FORBIDDEN,
+            ApiError::AssetNotFound
+            | ApiError::CollectionNotFound
+            | ApiError::ApiKeyNotFound
+            | ApiError::L2Storage(_) => StatusCode::NOT_FOUND,
+            ApiError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiError::AssetBurn(_)
+            | ApiError::AssetUpdate(_)
+            | ApiError::AssetTransfer(_)
+            | ApiError::Idempotency(_) => StatusCode::CONFLICT,
+            ApiError::RateLimit(_) => StatusCode::TOO_MANY_REQUESTS,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let ApiError::RateLimit(e) = self {
+            response.insert_header((RETRY_AFTER, retry_after_secs(e.retry_after()).to_string()));
+        }
+
+        response.json(self.to_error_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        http::{header::RETRY_AFTER, StatusCode},
+        ResponseError,
+    };
+    use entities::l2::PublicKey;
+    use interfaces::asset_service::{AssetBurnError, L1MintError};
+    use interfaces::rate_limit::RateLimitError;
+    use std::time::Duration;
+    use util::nft_json::{InvalidMetadataError, JsonMetadataError};
+
+    use super::{ApiError, ErrorResponse};
+
+    fn to_response(e: ApiError) -> (StatusCode, ErrorResponse) {
+        let response = e.error_response();
+        let status = response.status();
+        let body = response.into_body().try_into_bytes().expect("Failed to read body");
+
+        (status, serde_json::from_slice(&body).expect("Failed to parse error response"))
+    }
+
+    #[test]
+    fn test_service_errors_are_mapped_to_codes() {
+        let (status, body) = to_response(anyhow::Error::from(AssetBurnError::Burnt).into());
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "asset_burnt");
+
+        let (status, body) =
+            to_response(anyhow::Error::from(L1MintError::WrongName("a".to_string(), "b".to_string())).into());
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "invalid_mint_transaction");
+        assert_eq!(body.error, "Wrong asset name, expected='a', actual='b'");
+
+        let (status, body) = to_response(anyhow::Error::from(L1MintError::NotUnlockedL2Asset).into());
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body.code, "asset_locked");
+
+        let (status, body) = to_response(anyhow::Error::from(L1MintError::NotConfirmed("sig".to_string())).into());
+        assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(body.code, "mint_not_confirmed");
+        assert!(ApiError::from(anyhow::Error::from(L1MintError::NotConfirmed("sig".to_string()))).has_taken_effect());
+        assert!(!ApiError::from(anyhow::Error::from(L1MintError::NotUnlockedL2Asset)).has_taken_effect());
+
+        let (status, body) = to_response(
+            anyhow::Error::from(interfaces::l2_storage::L2StorageError::L2AssetNotFound(PublicKey::default())).into(),
+        );
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "asset_not_found");
+
+        let (status, body) = to_response(
+            anyhow::Error::from(InvalidMetadataError(vec![
+                JsonMetadataError::MissingField("name"),
+                JsonMetadataError::MissingField("image"),
+            ]))
+            .into(),
+        );
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body.code, "invalid_metadata");
+        assert_eq!(body.violations.len(), 2);
+    }
+
+    #[test]
+    fn test_internal_details_are_hidden() {
+        let (status, body) = to_response(anyhow::anyhow!("connection to postgres://user:secret@db refused").into());
+
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            body,
+            ErrorResponse {
+                code: "internal_error".to_string(),
+                error: "Internal server error".to_string(),
+                violations: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_errors_have_retry_after() {
+        let response = ApiError::from(RateLimitError::TooManyRequests { retry_after: Duration::from_millis(1500) })
+            .error_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers().get(RETRY_AFTER).unwrap(), "2");
+    }
+}
@@ -0,0 +1,133 @@
+use std::future::Future;
+
+use actix_web::{
+    body::MessageBody,
+    http::{header::CONTENT_TYPE, StatusCode},
+    HttpRequest, HttpResponse, ResponseError,
+};
+use entities::api_key::Username;
+use interfaces::idempotency::{IdempotencyError, IdempotentResponse};
+use serde::Serialize;
+use service::idempotency_service::IdempotencyService;
+use tracing::error;
+use util::wallet_signature::request_digest;
+
+use crate::rest::{errors::ApiError, web_app::AppState};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Runs the handler at most once per `Idempotency-Key` of the user.
+/// The successful response is stored and replayed to the retries with the same key,
+/// failed requests release the key, so they can be retried, unless they have taken effect despite the error.
+/// The handler is spawned, so it completes and its response is stored even if the client disconnects.
+/// ## Args:
+/// * `username` - user of the API key, the idempotency key is rejected without it
+/// * `request` - parsed request body, its digest along with the path must match on the retries
+/// * `handler` - makes the future processing the request
+pub async fn idempotent<T, F>(
+    http_req: &HttpRequest,
+    state: &AppState,
+    username: Option<&Username>,
+    request: T,
+    handler: impl FnOnce(T) -> F,
+) -> Result<HttpResponse, ApiError>
+where
+    T: Serialize,
+    F: Future<Output = Result<HttpResponse, ApiError>> + 'static,
+{
+    let Some(idempotency_key) = http_req.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return handler(request).await;
+    };
+    let Ok(idempotency_key) = idempotency_key.to_str() else {
+        return Err(ApiError::validation(format!("'{IDEMPOTENCY_KEY_HEADER}' header is not a valid string")));
+    };
+    let Some(username) = username else {
+        return Err(IdempotencyError::MissingApiKey.into());
+    };
+
+    let body = serde_json::to_vec(&request).map_err(|e| ApiError::Internal(e.into()))?;
+    let request_hash = request_digest(http_req.method().as_str(), http_req.path(), &body);
+    if let Some(response) = state
+        .idempotency_service
+        .begin(username, idempotency_key, &request_hash)
+        .await?
+    {
+        return Ok(replay_response(response));
+    }
+
+    let handler = handler(request);
+    let idempotency_service = state.idempotency_service.clone();
+    let username = username.clone();
+    let idempotency_key = idempotency_key.to_string();
+    actix_web::rt::spawn(async move {
+        let result = handler.await;
+        complete(&idempotency_service, &username, &idempotency_key, result).await
+    })
+    .await
+    .map_err(|e| ApiError::Internal(anyhow::anyhow!("Idempotent request handler failed: {e}")))?
+}
+
+/// Stores the successful response of the handler, or releases the key if it failed
+/// before taking any effect
+async fn complete(
+    idempotency_service: &IdempotencyService,
+    username: &Username,
+    idempotency_key: &str,
+    result: Result<HttpResponse, ApiError>,
+) -> Result<HttpResponse, ApiError> {
+    let response = match result {
+        Ok(response) if response.status().is_success() => response,
+        // The retries would repeat the effect, e.g. send another mint transaction, so they get the error instead
+        Err(e) if e.has_taken_effect() => e.error_response(),
+        result => {
+            release(idempotency_service, username, idempotency_key).await;
+            return result;
+        }
+    };
+
+    let (response, body) = response.into_parts();
+    let body = match body.try_into_bytes() {
+        Ok(body) => body,
+        // Streamed bodies are not stored, so the retries are not replayed
+        Err(body) => {
+            release(idempotency_service, username, idempotency_key).await;
+            return Ok(response.set_body(body));
+        }
+    };
+
+    let idempotent_response = IdempotentResponse {
+        status: response.status().as_u16(),
+        content_type: response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(ToString::to_string),
+        body: body.to_vec(),
+    };
+    // The request is already processed, so failing to store its response must not fail it
+    if let Err(e) = idempotency_service
+        .complete(username, idempotency_key, &idempotent_response)
+        .await
+    {
+        error!("Failed to save the response of the idempotent request: {e:#}");
+    }
+
+    Ok(response.set_body(body).map_into_boxed_body())
+}
+
+/// Failing to release the key must not replace the result of the request,
+/// the key is reclaimed once its reservation lease is over
+async fn release(idempotency_service: &IdempotencyService, username: &Username, idempotency_key: &str) {
+    if let Err(e) = idempotency_service.release(username, idempotency_key).await {
+        error!("Failed to release the idempotency key: {e:#}");
+    }
+}
+
+fn replay_response(response: IdempotentResponse) -> HttpResponse {
+    let mut builder = HttpResponse::build(StatusCode::from_u16(response.status).unwrap_or(StatusCode::OK));
+    if let Some(content_type) = response.content_type {
+        builder.insert_header((CONTENT_TYPE, content_type));
+    }
+
+    builder.body(response.body)
+}
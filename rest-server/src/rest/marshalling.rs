@@ -7,6 +7,11 @@ pub fn decode_transaction(base64_encoded: &str) -> anyhow::Result<Transaction> {
     Ok(transaction)
 }
 
+pub fn encode_transaction(tx: &Transaction) -> anyhow::Result<String> {
+    let bytes = bincode::serialize(tx)?;
+    Ok(BASE64_STANDARD.encode(bytes))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -45,5 +50,6 @@ mod test {
         let decoded = decode_transaction(&base64_serialized).unwrap();
 
         assert_eq!(original, decoded);
+        assert_eq!(encode_transaction(&original).unwrap(), base64_serialized);
     }
 }
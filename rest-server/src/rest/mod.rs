@@ -1,4 +1,8 @@
+pub mod api_doc;
 pub mod auth;
 pub mod endpoints;
+pub mod errors;
+pub mod idempotency;
 pub mod marshalling;
+pub mod rate_limit;
 pub mod web_app;
@@ -0,0 +1,36 @@
+use actix_web::web::Data;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use futures::future::{ready, Ready};
+
+use crate::rest::{errors::ApiError, web_app::AppState};
+
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// Throttles the unauthenticated requests by the client IP,
+/// the requests with an API key are throttled by the user in `ApiKeyExtractor`.
+/// The IP is the peer address, or taken from the `X-Forwarded-For` header if the peer is a trusted proxy.
+pub struct ClientIpRateLimit;
+
+impl FromRequest for ClientIpRateLimit {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(check_client_ip(req).map(|_| ClientIpRateLimit))
+    }
+}
+
+pub fn check_client_ip(req: &HttpRequest) -> Result<(), ApiError> {
+    let Some(state) = req.app_data::<Data<AppState>>() else {
+        return Err(ApiError::Internal(anyhow::anyhow!("Couldn't retrieve 'AppState'!")));
+    };
+
+    let forwarded_for = req
+        .headers()
+        .get(FORWARDED_FOR_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    Ok(state
+        .rate_limit_service
+        .check_client_ip(req.peer_addr().map(|addr| addr.ip()), forwarded_for)?)
+}
@@ -1,19 +1,33 @@
 use actix_web::{App, HttpServer};
+use entities::api_key::WebhookSecrets;
 use interfaces::asset_service::AssetService;
 use io::Result;
-use service::{asset_service_impl::AssetServiceImpl, converter::AssetDtoConverter};
+use service::{
+    api_key_provider_service::ApiKeysProviderService, asset_service_impl::AssetServiceImpl,
+    converter::AssetDtoConverter, idempotency_service::IdempotencyService, l1_reconciler::L1Reconciler,
+    mint_confirmation_worker::MintConfirmationWorker, rate_limit_service::RateLimitService,
+    wallet_signature_verifier::WalletSignatureVerifier, webhook_sender::WebhookSender,
+};
 use solana_integration::l1_service_solana::SolanaService;
-use std::{io, sync::Arc};
+use std::{io, sync::Arc, time::Duration};
 use storage::asset_storage_s3::S3Storage;
 use storage::l2_storage_pg::L2StoragePg;
 use tracing::{error, info};
 use tracing_actix_web::TracingLogger;
 use util::{config::Settings, hd_wallet::HdWalletProducer};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
+use crate::rest::endpoints::api_keys::{issue_api_key, list_api_keys, revoke_api_key};
 use crate::rest::endpoints::l2_assets::{
-    create_asset, get_asset, get_metadata, mint_status, mint_transaction, update_asset,
+    burn_asset, create_asset, create_assets_batch, create_mint_transaction, get_asset, get_binary, get_metadata,
+    get_transfers, mint_status, mint_transaction, transfer_asset, update_asset, upload_binary,
+};
+use crate::rest::endpoints::l2_collections::{
+    create_collection, get_collection, get_collection_metadata, update_collection,
 };
 use crate::{
+    rest::api_doc::{ApiDoc, OPEN_API_JSON_PATH, SWAGGER_UI_PATH},
     rest::auth::ApiKeysProviderCtx,
     rest::endpoints::health_check::{health, secured_health},
 };
@@ -25,11 +39,10 @@ pub async fn start_up_rest_server(cfg: &Settings) -> Result<()> {
     info!("Starting server");
 
     let app_state = AppState::create_app_state(cfg).await;
-    let cfg_clone = cfg.clone();
 
     HttpServer::new(move || {
         App::new()
-            .configure(app_state.make_endpoints(&cfg_clone))
+            .configure(app_state.make_endpoints())
             .wrap(TracingLogger::default())
     })
     .bind((cfg.rest_server.host, cfg.rest_server.port))?
@@ -43,6 +56,14 @@ pub async fn start_up_rest_server(cfg: &Settings) -> Result<()> {
 pub struct AppState {
     pub asset_service: Arc<dyn AssetService + Sync + Send>,
     pub asset_converter: AssetDtoConverter,
+    pub max_binary_asset_size: usize,
+    pub max_json_metadata_size: usize,
+    pub max_asset_batch_size: usize,
+    pub webhook_secrets: WebhookSecrets,
+    pub api_key_service: Arc<ApiKeysProviderService>,
+    pub wallet_signature_verifier: Arc<WalletSignatureVerifier>,
+    pub rate_limit_service: Arc<RateLimitService>,
+    pub idempotency_service: Arc<IdempotencyService>,
 }
 
 impl AppState {
@@ -70,11 +91,15 @@ impl AppState {
         let asset_service = Arc::new(AssetServiceImpl {
             wallet_producer: hd_wallet_producer,
             derivation_sequence: l2_storage.clone(),
-            l2_storage,
+            collection_storage: l2_storage.clone(),
+            l2_storage: l2_storage.clone(),
             asset_metadata_storage: obj_storage.clone(),
             blob_storage: obj_storage.clone(),
-            l1_service: solana_service,
+            l1_service: solana_service.clone(),
+            webhook_storage: l2_storage.clone(),
+            mint_job_storage: l2_storage.clone(),
             metadata_server_base_url: cfg.rest_server.base_url.clone(),
+            max_json_metadata_size: cfg.obj_storage.max_json_metadata_size,
         });
 
         asset_service
@@ -82,13 +107,65 @@ impl AppState {
             .await
             .unwrap_or_else(|e| error!("Failed to start 'process_minting_assets'; Cause: {e}."));
 
+        MintConfirmationWorker::new(solana_service.clone(), l2_storage.clone(), cfg.mint_confirmation.clone()).start();
+
+        let l1_reconciler = L1Reconciler::new(
+            solana_service,
+            l2_storage.clone(),
+            cfg.rest_server.base_url.clone(),
+            cfg.l1_reconciliation.clone(),
+        );
+        tokio::spawn(l1_reconciler.run());
+
+        let webhook_sender = WebhookSender::new(
+            l2_storage.clone(),
+            cfg.webhook_secrets(),
+            cfg.webhook_secret_key(),
+            cfg.webhooks.clone(),
+        )
+        .unwrap_or_else(|e| panic!("Failed to init 'WebhookSender' cause: {e}"));
+        tokio::spawn(webhook_sender.run());
+
+        let api_key_service = Arc::new(ApiKeysProviderService::new(
+            cfg.admin_api_keys(),
+            cfg.rest_api_keys(),
+            l2_storage.clone(),
+            cfg.webhook_secret_key(),
+            Duration::from_secs(cfg.rest_server.api_key_cache_ttl_secs),
+        ));
+
+        let rate_limit_service = Arc::new(RateLimitService::new(cfg.rate_limits.clone(), l2_storage.clone()));
+
+        let idempotency_service = Arc::new(IdempotencyService::new(
+            l2_storage.clone(),
+            Duration::from_secs(cfg.rest_server.idempotency_key_ttl_secs),
+            Duration::from_secs(cfg.rest_server.idempotency_reservation_lease_secs),
+        ));
+
+        let wallet_signature_verifier = Arc::new(WalletSignatureVerifier::new(
+            l2_storage,
+            cfg.rest_server.require_wallet_signatures,
+            Duration::from_secs(cfg.rest_server.wallet_signature_max_ttl_secs),
+        ));
+
         let asset_converter = AssetDtoConverter { metadata_server_base_url: cfg.rest_server.base_url.clone() };
 
-        AppState { asset_service, asset_converter }
+        AppState {
+            asset_service,
+            asset_converter,
+            max_binary_asset_size: cfg.obj_storage.max_binary_asset_size,
+            max_json_metadata_size: cfg.obj_storage.max_json_metadata_size,
+            max_asset_batch_size: cfg.rest_server.max_asset_batch_size,
+            webhook_secrets: cfg.webhook_secrets(),
+            api_key_service,
+            wallet_signature_verifier,
+            rate_limit_service,
+            idempotency_service,
+        }
     }
 
-    pub fn make_endpoints(&self, cfg: &Settings) -> impl FnOnce(&mut ServiceConfig) + '_ {
-        let api_keys_provider_ctx = ApiKeysProviderCtx { api_keys: cfg.rest_api_keys() };
+    pub fn make_endpoints(&self) -> impl FnOnce(&mut ServiceConfig) + '_ {
+        let api_keys_provider_ctx = ApiKeysProviderCtx { api_keys_provider: self.api_key_service.clone() };
         let app_state = self.clone();
 
         |serv_cfg: &mut ServiceConfig| {
@@ -97,13 +174,28 @@ impl AppState {
                 .app_data(Data::new(app_state))
                 .service(health)
                 .service(create_asset)
+                .service(create_assets_batch)
                 .service(update_asset)
+                .service(burn_asset)
+                .service(transfer_asset)
+                .service(get_transfers)
                 .service(get_asset)
                 .service(get_metadata)
+                .service(create_mint_transaction)
                 .service(mint_transaction)
                 .service(mint_status)
                 .service(mint_transaction_async)
-                .service(secured_health);
+                .service(upload_binary)
+                .service(get_binary)
+                .service(create_collection)
+                .service(update_collection)
+                .service(get_collection)
+                .service(get_collection_metadata)
+                .service(secured_health)
+                .service(issue_api_key)
+                .service(list_api_keys)
+                .service(revoke_api_key)
+                .service(SwaggerUi::new(SWAGGER_UI_PATH).url(OPEN_API_JSON_PATH, ApiDoc::openapi()));
         }
     }
 }
@@ -0,0 +1,313 @@
+mod test_app_util;
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test};
+    use entities::api_key::ApiKeyScope;
+    use entities::l2::PublicKey;
+    use rest_server::rest::endpoints::api_keys::{ApiKeyResponse, IssueApiKeyRequest};
+    use rest_server::rest::endpoints::l2_assets::{CreateAssetRequest, UpdateAssetRequest};
+    use rest_server::rest::endpoints::l2_collections::{CollectionResponse, CreateCollectionRequest};
+    use setup::TestEnvironmentCfg;
+    use util::publickey::PublicKeyExt;
+
+    use crate::test_app_util;
+
+    const ADMIN_API_KEY: &str = "999";
+
+    #[actix_web::test]
+    async fn test_api_key_endpoints() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // issue API key
+        let issued = test_app_util::issue_api_key(&app, vec![ApiKeyScope::AssetsCreate], false).await;
+        assert_eq!(issued.info.username, "name4");
+        assert_eq!(issued.info.scopes, vec![ApiKeyScope::AssetsCreate]);
+        assert!(!issued.info.restricted);
+        // the webhook secret is returned only once along with the key
+        assert!(issued.webhook_secret.is_some());
+
+        // issued API key is accepted by the secured endpoints
+        {
+            let req = test::TestRequest::get()
+                .uri("/secured_health")
+                .append_header(("x-api-key", issued.api_key.as_str()))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+        }
+
+        // issued API key doesn't have the admin scope
+        {
+            let req = test::TestRequest::get()
+                .uri("/admin/api-keys")
+                .append_header(("x-api-key", issued.api_key.as_str()))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+        }
+
+        // non-admin API key from the config doesn't have the admin scope either
+        {
+            let req = test::TestRequest::get()
+                .uri("/admin/api-keys")
+                .append_header(("x-api-key", "111"))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+        }
+
+        // listed API keys don't contain the keys themselves
+        {
+            let req = test::TestRequest::get()
+                .uri("/admin/api-keys")
+                .append_header(("x-api-key", ADMIN_API_KEY))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            assert!(!String::from_utf8(body.to_vec()).unwrap().contains(&issued.api_key));
+            assert!(!String::from_utf8(body.to_vec())
+                .unwrap()
+                .contains(issued.webhook_secret.as_deref().unwrap()));
+            let api_keys: Vec<ApiKeyResponse> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(api_keys, vec![issued.info.clone()]);
+        }
+
+        // revoked API key is rejected
+        {
+            let req = test::TestRequest::delete()
+                .uri(format!("/admin/api-keys/{}", issued.info.id).as_str())
+                .append_header(("x-api-key", ADMIN_API_KEY))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let revoked: ApiKeyResponse = serde_json::from_slice(&body).unwrap();
+            assert!(revoked.revocation_timestamp.is_some());
+
+            let req = test::TestRequest::get()
+                .uri("/secured_health")
+                .append_header(("x-api-key", issued.api_key.as_str()))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // unknown API key cannot be revoked
+        {
+            let req = test::TestRequest::delete()
+                .uri("/admin/api-keys/100500")
+                .append_header(("x-api-key", ADMIN_API_KEY))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::NOT_FOUND);
+        }
+
+        // API key cannot be issued already expired
+        {
+            let req = test::TestRequest::post()
+                .uri("/admin/api-keys")
+                .append_header(("x-api-key", ADMIN_API_KEY))
+                .set_json(IssueApiKeyRequest {
+                    username: "name4".to_string(),
+                    label: "expired".to_string(),
+                    scopes: vec![ApiKeyScope::AssetsCreate],
+                    restricted: false,
+                    expiry_timestamp: Some(chrono::Utc::now() - chrono::Duration::hours(1)),
+                })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_scoped_api_keys() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        let create_only = test_app_util::issue_api_key(&app, vec![ApiKeyScope::AssetsCreate], true).await;
+        let integrator1 =
+            test_app_util::issue_api_key(&app, vec![ApiKeyScope::AssetsCreate, ApiKeyScope::AssetsUpdate], true).await;
+        let integrator2 =
+            test_app_util::issue_api_key(&app, vec![ApiKeyScope::AssetsCreate, ApiKeyScope::AssetsUpdate], true).await;
+
+        let authority = PublicKey::new_unique().to_bs58();
+        let create_asset_request = |authority: &str| CreateAssetRequest {
+            name: "name1".to_string(),
+            metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+            owner: PublicKey::new_unique().to_bs58(),
+            creator: PublicKey::new_unique().to_bs58(),
+            authority: authority.to_string(),
+            royalty_basis_points: 0,
+            collection: None,
+        };
+
+        // restricted key creates an asset and takes over its authority
+        let asset = {
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", integrator1.api_key.as_str()))
+                .set_json(create_asset_request(&authority))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            test_app_util::extract_asset_from_response(serv_resp)
+        };
+
+        // the authority of another key cannot be used to create assets
+        {
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", integrator2.api_key.as_str()))
+                .set_json(create_asset_request(&authority))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], "foreign_authority");
+        }
+
+        // assets of another key cannot join the collections of the key
+        {
+            let req = test::TestRequest::post()
+                .uri("/collection")
+                .append_header(("x-api-key", integrator1.api_key.as_str()))
+                .set_json(CreateCollectionRequest {
+                    name: "collection1".to_string(),
+                    metadata_json: r#"{"name": "collection1", "image": "http://host/collection.png"}"#.to_string(),
+                    authority: authority.clone(),
+                })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            let collection: CollectionResponse =
+                serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap();
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", integrator2.api_key.as_str()))
+                .set_json(CreateAssetRequest {
+                    collection: Some(collection.id),
+                    ..create_asset_request(&PublicKey::new_unique().to_bs58())
+                })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], "foreign_authority");
+        }
+
+        // assets of another key cannot be updated
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", asset.id).as_str())
+                .append_header(("x-api-key", integrator2.api_key.as_str()))
+                .set_json(UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+        }
+
+        // key without the scope cannot update assets, even its own ones
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", asset.id).as_str())
+                .append_header(("x-api-key", create_only.api_key.as_str()))
+                .set_json(UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], "missing_scope");
+        }
+
+        // the key updates its own assets
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", asset.id).as_str())
+                .append_header(("x-api-key", integrator1.api_key.as_str()))
+                .set_json(UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+        }
+
+        // the config keys are not restricted
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", asset.id).as_str())
+                .append_header(("x-api-key", ADMIN_API_KEY))
+                .set_json(UpdateAssetRequest { name: Some("name3".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+        }
+
+        // the authority of the assets created by the unrestricted keys cannot be taken over
+        let config_authority = PublicKey::new_unique().to_bs58();
+        let config_asset = {
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(create_asset_request(&config_authority))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            test_app_util::extract_asset_from_response(serv_resp)
+        };
+        {
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", integrator2.api_key.as_str()))
+                .set_json(create_asset_request(&config_authority))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], "foreign_authority");
+        }
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", config_asset.id).as_str())
+                .append_header(("x-api-key", integrator2.api_key.as_str()))
+                .set_json(UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::FORBIDDEN);
+        }
+    }
+}
@@ -4,7 +4,13 @@ mod test_app_util;
 mod tests {
     use actix_web::{body::MessageBody, http::StatusCode, test};
     use entities::l2::PublicKey;
-    use rest_server::rest::endpoints::l2_assets::{CreateAssetRequest, UpdateAssetRequest};
+    use rest_server::rest::endpoints::l2_assets::{
+        AssetTransferResponse, CreateAssetRequest, CreateAssetsBatchRequest, CreateAssetsBatchResponse,
+        TransferAssetRequest, UpdateAssetRequest,
+    };
+    use rest_server::rest::endpoints::l2_collections::{
+        CollectionResponse, CreateCollectionRequest, UpdateCollectionRequest,
+    };
     use setup::TestEnvironmentCfg;
     use util::publickey::PublicKeyExt;
 
@@ -17,7 +23,7 @@ mod tests {
         let app = test_app_util::init_web_app(&t_env).await;
 
         // given
-        let metadata_json = "{}".to_string();
+        let metadata_json = r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string();
         let owner = PublicKey::new_unique();
         let creator = PublicKey::new_unique();
         let authority = PublicKey::new_unique();
@@ -44,6 +50,33 @@ mod tests {
             assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
         };
 
+        // create asset with invalid metadata should return `BAD_REQUEST` listing the violations
+        {
+            let req_payload = CreateAssetRequest {
+                name: "name1".to_string(),
+                metadata_json: r#"{"image": "http://host/image.png", "attributes": [{"value": 1}]}"#.to_string(),
+                owner: bs58::encode(owner).into_string(),
+                creator: bs58::encode(creator).into_string(),
+                authority: bs58::encode(authority).into_string(),
+                royalty_basis_points: 0,
+                collection: None,
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(body["code"], "invalid_metadata");
+            assert_eq!(body["violations"].as_array().unwrap().len(), 2);
+        };
+
         // create asset
         let created_asset = {
             let req_payload = CreateAssetRequest {
@@ -118,14 +151,62 @@ mod tests {
         let new_owner = PublicKey::new_unique();
         let new_creator = PublicKey::new_unique();
         let new_authority = PublicKey::new_unique();
-        let new_collection = PublicKey::new_unique();
+        let new_collection = {
+            let req_payload = CreateCollectionRequest {
+                name: "collection1".to_string(),
+                metadata_json: r#"{"name": "collection1", "image": "http://host/collection.png"}"#.to_string(),
+                authority: bs58::encode(new_authority).into_string(),
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/collection")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            let collection: CollectionResponse =
+                serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap();
+            PublicKey::from_bs58(&collection.id).unwrap()
+        };
+
+        // The asset cannot join a collection that doesn't exist
+        {
+            let req_payload =
+                UpdateAssetRequest { collection: Some(Some(PublicKey::new_unique().to_bs58())), ..Default::default() };
+
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::NOT_FOUND);
+        }
+
+        // The owner is changed only by the transfer
+        {
+            let req_payload =
+                UpdateAssetRequest { owner: Some(bs58::encode(new_owner).into_string()), ..Default::default() };
+
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        }
 
         // Update asset
         let updated_asset = {
             let req_payload = UpdateAssetRequest {
                 name: Some("name2".to_string()),
                 metadata_json: Some(new_metadata_json.clone()),
-                owner: Some(bs58::encode(new_owner).into_string()),
+                owner: None,
                 creator: Some(bs58::encode(new_creator).into_string()),
                 authority: Some(bs58::encode(new_authority).into_string()),
                 collection: Some(Some(bs58::encode(new_collection).into_string())),
@@ -154,7 +235,7 @@ mod tests {
                 .unwrap(),
             "name2"
         );
-        assert_eq!(updated_asset.ownership.owner, bs58::encode(new_owner).into_string());
+        assert_eq!(updated_asset.ownership.owner, created_asset.ownership.owner);
         assert_eq!(updated_asset.creators.as_ref().unwrap()[0].address, bs58::encode(new_creator).into_string());
         assert_eq!(updated_asset.authorities.as_ref().unwrap()[0].address, bs58::encode(new_authority).into_string());
         assert_eq!(
@@ -217,4 +298,485 @@ mod tests {
 
         assert_eq!(fetched_metadata, new_metadata_json);
     }
+
+    #[actix_web::test]
+    async fn test_burn_asset_endpoint() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let created_asset = {
+            let req_payload = CreateAssetRequest {
+                name: "name1".to_string(),
+                metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+                owner: PublicKey::new_unique().to_bs58(),
+                creator: PublicKey::new_unique().to_bs58(),
+                authority: PublicKey::new_unique().to_bs58(),
+                royalty_basis_points: 0,
+                collection: None,
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            extract_asset_from_response(serv_resp)
+        };
+        assert!(!created_asset.burnt);
+
+        // burn
+        {
+            let req = test::TestRequest::delete()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            assert!(extract_asset_from_response(serv_resp).burnt);
+        }
+
+        // burnt asset is still available
+        {
+            let req = test::TestRequest::get()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            assert!(extract_asset_from_response(serv_resp).burnt);
+        }
+
+        // burnt asset can be neither burnt again nor updated
+        {
+            let req = test::TestRequest::delete()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CONFLICT);
+
+            let req = test::TestRequest::put()
+                .uri(format!("/asset/{}", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CONFLICT);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_transfer_asset_endpoints() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let owner_1 = PublicKey::new_unique().to_bs58();
+        let owner_2 = PublicKey::new_unique().to_bs58();
+
+        let created_asset = {
+            let req_payload = CreateAssetRequest {
+                name: "name1".to_string(),
+                metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+                owner: owner_1.clone(),
+                creator: PublicKey::new_unique().to_bs58(),
+                authority: PublicKey::new_unique().to_bs58(),
+                royalty_basis_points: 0,
+                collection: None,
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            extract_asset_from_response(serv_resp)
+        };
+
+        // the new owner must be a valid public key
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/transfer", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(TransferAssetRequest { from: owner_1.clone(), to: "not a pubkey".to_string() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        }
+
+        // transfer
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/transfer", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(TransferAssetRequest { from: owner_1.clone(), to: owner_2.clone() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            assert_eq!(extract_asset_from_response(serv_resp).ownership.owner, owner_2);
+        }
+
+        // transfer from the previous owner should be refused
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/transfer", created_asset.id).as_str())
+                .append_header(("x-api-key", "222"))
+                .set_json(TransferAssetRequest { from: owner_1.clone(), to: owner_2.clone() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CONFLICT);
+        }
+
+        // ownership history
+        {
+            let req = test::TestRequest::get()
+                .uri(format!("/asset/{}/transfers", created_asset.id).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            let transfers: Vec<AssetTransferResponse> = serde_json::from_slice(&body).unwrap();
+            assert_eq!(transfers.len(), 1);
+            assert_eq!(transfers[0].from, owner_1);
+            assert_eq!(transfers[0].to, owner_2);
+            assert_eq!(transfers[0].username, "name1");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_collection_endpoints() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let authority = PublicKey::new_unique().to_bs58();
+        let collection_metadata_json =
+            r#"{"name": "collection1", "image": "http://host/collection.png", "description": "test collection"}"#;
+
+        // create collection
+        let created_collection: CollectionResponse = {
+            let req_payload = CreateCollectionRequest {
+                name: "collection1".to_string(),
+                metadata_json: collection_metadata_json.to_string(),
+                authority: authority.clone(),
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/collection")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap()
+        };
+        assert_eq!(created_collection.name, "collection1");
+        assert_eq!(created_collection.authority, authority);
+        assert_eq!(
+            created_collection.metadata_uri,
+            format!("http://localhost/collection/{}/metadata.json", created_collection.id)
+        );
+
+        // update collection
+        {
+            let req = test::TestRequest::put()
+                .uri(format!("/collection/{}", created_collection.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .set_json(UpdateCollectionRequest { name: Some("collection2".to_string()), ..Default::default() })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+        }
+
+        // get collection and its metadata
+        {
+            let req = test::TestRequest::get()
+                .uri(format!("/collection/{}", created_collection.id).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            let fetched: CollectionResponse =
+                serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap();
+            assert_eq!(fetched.name, "collection2");
+
+            let req = test::TestRequest::get()
+                .uri(format!("/collection/{}/metadata.json", created_collection.id).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            let fetched_metadata = serv_resp.into_body().try_into_bytes().unwrap();
+            assert_eq!(fetched_metadata, collection_metadata_json);
+        }
+
+        // asset with the same authority is a verified member of the collection
+        for (asset_authority, verified) in [(authority.clone(), true), (PublicKey::new_unique().to_bs58(), false)] {
+            let req_payload = CreateAssetRequest {
+                name: "name1".to_string(),
+                metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+                owner: PublicKey::new_unique().to_bs58(),
+                creator: PublicKey::new_unique().to_bs58(),
+                authority: asset_authority,
+                royalty_basis_points: 0,
+                collection: Some(created_collection.id.clone()),
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            let created_asset = extract_asset_from_response(serv_resp);
+
+            let group = &created_asset.grouping.as_ref().unwrap()[0];
+            assert_eq!(group.group_value.as_ref().unwrap(), &created_collection.id);
+            assert_eq!(group.verified, Some(verified));
+            let collection_metadata = group.collection_metadata.as_ref().unwrap();
+            assert_eq!(collection_metadata.get_item("name").unwrap(), "collection2");
+            assert_eq!(collection_metadata.get_item("description").unwrap(), "test collection");
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_binary_asset_endpoints() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let image_bytes = vec![137u8, 80, 78, 71, 13, 10, 26, 10, 1, 2, 3, 4];
+
+        let created_asset = {
+            let req_payload = CreateAssetRequest {
+                name: "name1".to_string(),
+                metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+                owner: PublicKey::new_unique().to_bs58(),
+                creator: PublicKey::new_unique().to_bs58(),
+                authority: PublicKey::new_unique().to_bs58(),
+                royalty_basis_points: 0,
+                collection: None,
+            };
+
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(req_payload)
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            extract_asset_from_response(serv_resp)
+        };
+
+        // upload without API key should be rejected
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/binary", created_asset.id).as_str())
+                .append_header(("content-type", MULTIPART_CONTENT_TYPE))
+                .set_payload(multipart_payload("image.png", "image/png", &image_bytes))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // upload of unsupported file type should be rejected
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/binary", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", MULTIPART_CONTENT_TYPE))
+                .set_payload(multipart_payload("script.sh", "application/x-sh", &image_bytes))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        }
+
+        // upload of a file exceeding the size limit should be rejected
+        {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/binary", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", MULTIPART_CONTENT_TYPE))
+                .set_payload(multipart_payload("image.png", "image/png", &vec![0u8; 1024 * 1024 + 1]))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        // upload binary, MIME type is guessed from the file name
+        let updated_asset = {
+            let req = test::TestRequest::post()
+                .uri(format!("/asset/{}/binary", created_asset.id).as_str())
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", MULTIPART_CONTENT_TYPE))
+                .set_payload(multipart_payload("image.png", "application/octet-stream", &image_bytes))
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            extract_asset_from_response(serv_resp)
+        };
+
+        let expected_binary_uri = format!("http://localhost/asset/{}/binary", created_asset.id);
+        let content = updated_asset.content.as_ref().unwrap();
+        assert_eq!(content.links.as_ref().unwrap().get("image").unwrap(), &expected_binary_uri);
+        assert_eq!(content.files.as_ref().unwrap()[0].uri.as_ref().unwrap(), &expected_binary_uri);
+        assert_eq!(content.files.as_ref().unwrap()[0].mime.as_ref().unwrap(), "image/png");
+
+        // download binary
+        {
+            let req = test::TestRequest::get()
+                .uri(format!("/asset/{}/binary", created_asset.id).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            assert_eq!(serv_resp.headers().get("content-type").unwrap(), "image/png");
+            assert_eq!(serv_resp.headers().get("x-content-type-options").unwrap(), "nosniff");
+            assert_eq!(serv_resp.headers().get("content-security-policy").unwrap(), "sandbox");
+            assert_eq!(serv_resp.into_body().try_into_bytes().unwrap().to_vec(), image_bytes);
+        }
+
+        // binary of an asset without uploaded file is not found
+        {
+            let req = test::TestRequest::get()
+                .uri(format!("/asset/{}/binary", PublicKey::new_unique().to_bs58()).as_str())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::NOT_FOUND);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_assets_batch_endpoint() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let asset_request = |name: &str, metadata_json: &str, royalty_basis_points: u16| CreateAssetRequest {
+            name: name.to_string(),
+            metadata_json: metadata_json.to_string(),
+            owner: bs58::encode(PublicKey::new_unique()).into_string(),
+            creator: bs58::encode(PublicKey::new_unique()).into_string(),
+            authority: bs58::encode(PublicKey::new_unique()).into_string(),
+            royalty_basis_points,
+            collection: None,
+        };
+        let metadata_json = r#"{"name": "name1", "image": "http://host/image.png"}"#;
+
+        // every asset gets its own result, the failed ones don't prevent the others from being created
+        let results = {
+            let req = test::TestRequest::post()
+                .uri("/assets/batch")
+                .append_header(("x-api-key", "111"))
+                .set_json(CreateAssetsBatchRequest {
+                    assets: vec![
+                        asset_request("name1", metadata_json, 0),
+                        asset_request("name2", metadata_json, 10_001),
+                        asset_request("name3", r#"{"image": "http://host/image.png"}"#, 0),
+                        asset_request("name4", metadata_json, 500),
+                    ],
+                })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+            let body = serv_resp.into_body().try_into_bytes().unwrap();
+            serde_json::from_slice::<CreateAssetsBatchResponse>(&body)
+                .unwrap()
+                .results
+        };
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].status, 201);
+        assert_eq!(results[1].status, 400);
+        assert_eq!(results[1].error.as_ref().unwrap().code, "invalid_request");
+        assert_eq!(results[2].status, 400);
+        assert_eq!(results[2].error.as_ref().unwrap().code, "invalid_metadata");
+        assert_eq!(results[3].status, 201);
+
+        for result in [&results[0], &results[3]] {
+            let created = result.asset.as_ref().unwrap();
+            let req = test::TestRequest::get()
+                .uri(&format!("/asset/{}", created["id"].as_str().unwrap()))
+                .to_request();
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::OK);
+        }
+
+        // empty batch is rejected
+        {
+            let req = test::TestRequest::post()
+                .uri("/assets/batch")
+                .append_header(("x-api-key", "111"))
+                .set_json(CreateAssetsBatchRequest { assets: vec![] })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_open_api_endpoint() {
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        let req = test::TestRequest::get().uri("/api-docs/openapi.json").to_request();
+        let serv_resp = test::call_service(&app, req).await;
+        assert_eq!(serv_resp.status(), StatusCode::OK);
+
+        let open_api: serde_json::Value =
+            serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap();
+        assert!(open_api["paths"]["/asset/{pubkey}"]["get"].is_object());
+        assert!(open_api["components"]["schemas"]["CreateAssetRequest"].is_object());
+
+        let req = test::TestRequest::get().uri("/swagger-ui/").to_request();
+        let serv_resp = test::call_service(&app, req).await;
+        assert_eq!(serv_resp.status(), StatusCode::OK);
+    }
+
+    const MULTIPART_BOUNDARY: &str = "spell-test-boundary";
+    const MULTIPART_CONTENT_TYPE: &str = "multipart/form-data; boundary=spell-test-boundary";
+
+    fn multipart_payload(file_name: &str, content_type: &str, bytes: &[u8]) -> Vec<u8> {
+        let mut payload = format!(
+            "--{MULTIPART_BOUNDARY}\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+             Content-Type: {content_type}\r\n\r\n"
+        )
+        .into_bytes();
+        payload.extend_from_slice(bytes);
+        payload.extend_from_slice(format!("\r\n--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+        payload
+    }
 }
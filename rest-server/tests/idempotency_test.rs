@@ -0,0 +1,91 @@
+mod test_app_util;
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test};
+    use rest_server::rest::endpoints::l2_assets::{CreateAssetRequest, L1MintRequest};
+    use setup::data_gen::rand_pubkey_str;
+    use setup::TestEnvironmentCfg;
+
+    use crate::test_app_util;
+
+    async fn error_code(serv_resp: actix_web::dev::ServiceResponse) -> String {
+        let body = serv_resp.into_body().try_into_bytes().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["code"].as_str().unwrap().to_string()
+    }
+
+    fn create_asset_request(name: &str, owner: &str, idempotency_key: Option<&str>) -> actix_http::Request {
+        let mut req = test::TestRequest::post()
+            .uri("/asset")
+            .append_header(("x-api-key", "111"))
+            .set_json(CreateAssetRequest {
+                name: name.to_string(),
+                metadata_json: format!(r#"{{"name": "{name}", "image": "http://host/image.png"}}"#),
+                owner: owner.to_string(),
+                creator: owner.to_string(),
+                authority: owner.to_string(),
+                royalty_basis_points: 0,
+                collection: None,
+            });
+        if let Some(idempotency_key) = idempotency_key {
+            req = req.append_header(("idempotency-key", idempotency_key));
+        }
+        req.to_request()
+    }
+
+    #[actix_web::test]
+    async fn test_idempotent_asset_creation() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        // given
+        let owner = rand_pubkey_str();
+
+        // the retry gets the asset created by the first request
+        let serv_resp = test::call_service(&app, create_asset_request("name1", &owner, Some("key1"))).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+        let created = test_app_util::extract_asset_from_response(serv_resp);
+
+        let serv_resp = test::call_service(&app, create_asset_request("name1", &owner, Some("key1"))).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+        assert_eq!(serv_resp.headers().get("content-type").unwrap(), "application/json");
+        let replayed = test_app_util::extract_asset_from_response(serv_resp);
+        assert_eq!(replayed.id, created.id);
+
+        // the key cannot be reused for a different request
+        let serv_resp = test::call_service(&app, create_asset_request("name2", &owner, Some("key1"))).await;
+        assert_eq!(serv_resp.status(), StatusCode::CONFLICT);
+        assert_eq!(error_code(serv_resp).await, "idempotency_key_reused");
+
+        // failed request doesn't consume the key
+        let serv_resp = test::call_service(&app, create_asset_request("name3", "", Some("key2"))).await;
+        assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        let serv_resp = test::call_service(&app, create_asset_request("name3", &owner, Some("key2"))).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+        assert_ne!(test_app_util::extract_asset_from_response(serv_resp).id, created.id);
+
+        // requests without a key are not deduplicated
+        let serv_resp = test::call_service(&app, create_asset_request("name1", &owner, None)).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+        assert_ne!(test_app_util::extract_asset_from_response(serv_resp).id, created.id);
+    }
+
+    #[actix_web::test]
+    async fn test_idempotency_key_requires_api_key() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let app = test_app_util::init_web_app(&t_env).await;
+
+        let req = test::TestRequest::post()
+            .uri("/asset/mint")
+            .append_header(("idempotency-key", "key1"))
+            .set_json(L1MintRequest { tx: "tx".to_string(), callback: None })
+            .to_request();
+
+        let serv_resp = test::call_service(&app, req).await;
+        assert_eq!(serv_resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(error_code(serv_resp).await, "invalid_idempotency_key");
+    }
+}
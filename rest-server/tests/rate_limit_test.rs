@@ -0,0 +1,153 @@
+mod test_app_util;
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test};
+    use entities::api_key::Username;
+    use rest_server::rest::endpoints::l2_assets::{CreateAssetRequest, CreateAssetsBatchRequest};
+    use setup::data_gen::rand_pubkey_str;
+    use setup::TestEnvironmentCfg;
+    use util::config::UserLimitsCfg;
+    use util::rate_limiter::RateLimit;
+
+    use crate::test_app_util;
+
+    const METADATA_JSON: &str = r#"{"name": "name1", "image": "http://host/image.png"}"#;
+
+    async fn error_code(serv_resp: actix_web::dev::ServiceResponse) -> String {
+        let body = serv_resp.into_body().try_into_bytes().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["code"].as_str().unwrap().to_string()
+    }
+
+    fn new_asset(metadata_json: &str) -> CreateAssetRequest {
+        let pubkey = rand_pubkey_str();
+        CreateAssetRequest {
+            name: "name1".to_string(),
+            metadata_json: metadata_json.to_string(),
+            owner: pubkey.clone(),
+            creator: pubkey.clone(),
+            authority: pubkey,
+            royalty_basis_points: 0,
+            collection: None,
+        }
+    }
+
+    fn create_asset_request(api_key: &str) -> actix_http::Request {
+        test::TestRequest::post()
+            .uri("/asset")
+            .append_header(("x-api-key", api_key))
+            .set_json(new_asset(METADATA_JSON))
+            .to_request()
+    }
+
+    fn create_assets_batch_request(api_key: &str, assets: Vec<CreateAssetRequest>) -> actix_http::Request {
+        test::TestRequest::post()
+            .uri("/assets/batch")
+            .append_header(("x-api-key", api_key))
+            .set_json(CreateAssetsBatchRequest { assets })
+            .to_request()
+    }
+
+    #[actix_web::test]
+    async fn test_rate_limits_and_quotas() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let mut cfg = t_env.make_test_cfg().await;
+        cfg.rate_limits.enabled = true;
+        cfg.rate_limits.anonymous = RateLimit { requests_per_second: 0.01, burst: 1 };
+        cfg.rate_limits.users.insert(
+            Username::new("name1"),
+            UserLimitsCfg {
+                rate: RateLimit { requests_per_second: 100.0, burst: 100 },
+                daily_asset_creations: Some(1),
+                daily_mints: None,
+            },
+        );
+        cfg.rate_limits.users.insert(
+            Username::new("name2"),
+            UserLimitsCfg {
+                rate: RateLimit { requests_per_second: 0.01, burst: 1 },
+                daily_asset_creations: None,
+                daily_mints: None,
+            },
+        );
+        let app = test_app_util::init_web_app_with_cfg(cfg).await;
+
+        // anonymous requests are throttled by the client IP
+        let asset_path = format!("/asset/{}", rand_pubkey_str());
+        let serv_resp = test::call_service(&app, test::TestRequest::get().uri(&asset_path).to_request()).await;
+        assert_eq!(serv_resp.status(), StatusCode::NOT_FOUND);
+
+        let serv_resp = test::call_service(&app, test::TestRequest::get().uri(&asset_path).to_request()).await;
+        assert_eq!(serv_resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(serv_resp.headers().contains_key("retry-after"));
+        assert_eq!(error_code(serv_resp).await, "rate_limited");
+
+        // health check is not throttled
+        let serv_resp = test::call_service(&app, test::TestRequest::get().uri("/health").to_request()).await;
+        assert_eq!(serv_resp.status(), StatusCode::OK);
+
+        // requests with an API key are throttled by the user
+        let serv_resp = test::call_service(&app, create_asset_request("222")).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+
+        let serv_resp = test::call_service(&app, create_asset_request("222")).await;
+        assert_eq!(serv_resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(error_code(serv_resp).await, "rate_limited");
+
+        // daily quota of the user is exhausted after the first asset
+        let serv_resp = test::call_service(&app, create_asset_request("111")).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+
+        let serv_resp = test::call_service(&app, create_asset_request("111")).await;
+        assert_eq!(serv_resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(serv_resp.headers().contains_key("retry-after"));
+        assert_eq!(error_code(serv_resp).await, "quota_exceeded");
+
+        // users don't share the limits
+        let serv_resp = test::call_service(&app, create_asset_request("333")).await;
+        assert_eq!(serv_resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_batch_quota() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let mut cfg = t_env.make_test_cfg().await;
+        cfg.rate_limits.enabled = true;
+        cfg.rate_limits.users.insert(
+            Username::new("name1"),
+            UserLimitsCfg {
+                rate: RateLimit { requests_per_second: 100.0, burst: 100 },
+                daily_asset_creations: Some(2),
+                daily_mints: None,
+            },
+        );
+        let app = test_app_util::init_web_app_with_cfg(cfg).await;
+        let invalid_metadata_json = r#"{"image": "http://host/image.png"}"#;
+
+        // the batch doesn't fit into the quota, so none of its assets is created
+        let assets = vec![
+            new_asset(METADATA_JSON),
+            new_asset(METADATA_JSON),
+            new_asset(METADATA_JSON),
+        ];
+        let serv_resp = test::call_service(&app, create_assets_batch_request("111", assets)).await;
+        assert_eq!(serv_resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(error_code(serv_resp).await, "quota_exceeded");
+
+        // the assets with invalid metadata don't consume the quota
+        let assets = vec![
+            new_asset(METADATA_JSON),
+            new_asset(invalid_metadata_json),
+            new_asset(METADATA_JSON),
+        ];
+        let serv_resp = test::call_service(&app, create_assets_batch_request("111", assets)).await;
+        assert_eq!(serv_resp.status(), StatusCode::OK);
+
+        let serv_resp = test::call_service(&app, create_asset_request("111")).await;
+        assert_eq!(serv_resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(error_code(serv_resp).await, "quota_exceeded");
+    }
+}
@@ -3,19 +3,26 @@ use actix_http::body::MessageBody;
 use actix_http::Request;
 use actix_web::dev::ServiceResponse;
 use actix_web::{test, App, HttpServer};
+use entities::api_key::ApiKeyScope;
 use entities::dto::Asset;
+use rest_server::rest::endpoints::api_keys::{IssueApiKeyRequest, IssuedApiKeyResponse};
 use rest_server::rest::endpoints::l2_assets::MintStatusResponse;
 use rest_server::rest::web_app::AppState;
 use setup::TestEnvironment;
 use std::str::FromStr;
 use tracing::metadata::LevelFilter;
 use tracing_actix_web::TracingLogger;
+use util::config::Settings;
 
 pub async fn init_web_app(
     t_env: &TestEnvironment,
 ) -> impl actix_web::dev::Service<Request, Response = ServiceResponse, Error = actix_web::Error> {
-    let cfg = t_env.make_test_cfg().await;
+    init_web_app_with_cfg(t_env.make_test_cfg().await).await
+}
 
+pub async fn init_web_app_with_cfg(
+    cfg: Settings,
+) -> impl actix_web::dev::Service<Request, Response = ServiceResponse, Error = actix_web::Error> {
     let log_level = cfg.rest_server.log_level.clone();
     let log_level = LevelFilter::from_str(&log_level).unwrap_or_else(|_| panic!("Invalid 'log_level' {log_level}"));
 
@@ -23,7 +30,7 @@ pub async fn init_web_app(
 
     let app_state = AppState::create_app_state(&cfg).await;
 
-    let app = test::init_service(App::new().configure(app_state.make_endpoints(&cfg))).await;
+    let app = test::init_service(App::new().configure(app_state.make_endpoints())).await;
 
     app
 }
@@ -41,7 +48,7 @@ pub async fn init_app_as_web_server(t_env: &TestEnvironment) {
 
     let app = HttpServer::new(move || {
         App::new()
-            .configure(app_state.make_endpoints(&cfg))
+            .configure(app_state.make_endpoints())
             .wrap(TracingLogger::default())
     })
     .bind(host_and_port)
@@ -83,3 +90,27 @@ pub async fn extract_string_from_reqwest_response(serv_resp: reqwest::Response)
 pub async fn extract_mint_status_response_from_reqwest_response(serv_resp: reqwest::Response) -> MintStatusResponse {
     serv_resp.json::<MintStatusResponse>().await.unwrap()
 }
+
+/// Issues an API key with the admin key from the test config
+pub async fn issue_api_key(
+    app: &impl actix_web::dev::Service<Request, Response = ServiceResponse, Error = actix_web::Error>,
+    scopes: Vec<ApiKeyScope>,
+    restricted: bool,
+) -> IssuedApiKeyResponse {
+    let req = test::TestRequest::post()
+        .uri("/admin/api-keys")
+        .append_header(("x-api-key", "999"))
+        .set_json(IssueApiKeyRequest {
+            username: "name4".to_string(),
+            label: "test".to_string(),
+            scopes,
+            restricted,
+            expiry_timestamp: None,
+        })
+        .to_request();
+
+    let serv_resp = test::call_service(app, req).await;
+    assert_eq!(serv_resp.status(), actix_web::http::StatusCode::CREATED);
+
+    serde_json::from_slice(&serv_resp.into_body().try_into_bytes().unwrap()).unwrap()
+}
@@ -0,0 +1,163 @@
+mod test_app_util;
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test};
+    use rest_server::rest::endpoints::l2_assets::{CreateAssetRequest, TransferAssetRequest, UpdateAssetRequest};
+    use setup::TestEnvironmentCfg;
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use util::wallet_signature::{request_digest, wallet_signature_message};
+
+    use crate::test_app_util;
+
+    /// Headers of the request signed by the wallet, the signature expires in a minute
+    fn sign_request(wallet: &Keypair, method: &str, path: &str, body: &[u8], nonce: &str) -> Vec<(String, String)> {
+        let expiry = chrono::Utc::now().timestamp() + 60;
+        let message = wallet_signature_message(&request_digest(method, path, body), nonce, expiry);
+
+        vec![
+            ("x-wallet-signature".to_string(), wallet.sign_message(message.as_bytes()).to_string()),
+            ("x-wallet-signature-nonce".to_string(), nonce.to_string()),
+            ("x-wallet-signature-expiry".to_string(), expiry.to_string()),
+        ]
+    }
+
+    async fn error_code(serv_resp: actix_web::dev::ServiceResponse) -> String {
+        let body = serv_resp.into_body().try_into_bytes().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        body["code"].as_str().unwrap().to_string()
+    }
+
+    #[actix_web::test]
+    async fn test_wallet_signatures() {
+        // Prepare test env
+        let t_env = TestEnvironmentCfg::default().with_pg().with_s3().start().await;
+        let mut cfg = t_env.make_test_cfg().await;
+        cfg.rest_server.require_wallet_signatures = true;
+        let app = test_app_util::init_web_app_with_cfg(cfg).await;
+
+        // given
+        let owner = Keypair::new();
+        let authority = Keypair::new();
+        let another_wallet = Keypair::new();
+
+        // asset creation doesn't require signature
+        let asset = {
+            let req = test::TestRequest::post()
+                .uri("/asset")
+                .append_header(("x-api-key", "111"))
+                .set_json(CreateAssetRequest {
+                    name: "name1".to_string(),
+                    metadata_json: r#"{"name": "name1", "image": "http://host/image.png"}"#.to_string(),
+                    owner: owner.pubkey().to_string(),
+                    creator: owner.pubkey().to_string(),
+                    authority: authority.pubkey().to_string(),
+                    royalty_basis_points: 0,
+                    collection: None,
+                })
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::CREATED);
+            test_app_util::extract_asset_from_response(serv_resp)
+        };
+        let asset_path = format!("/asset/{}", asset.id);
+        let update_body =
+            serde_json::to_vec(&UpdateAssetRequest { name: Some("name2".to_string()), ..Default::default() }).unwrap();
+
+        // update without signature is rejected
+        {
+            let req = test::TestRequest::put()
+                .uri(&asset_path)
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", "application/json"))
+                .set_payload(update_body.clone())
+                .to_request();
+
+            let serv_resp = test::call_service(&app, req).await;
+            assert_eq!(serv_resp.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(error_code(serv_resp).await, "missing_wallet_signature");
+        }
+
+        // update signed by a wallet other than the authority is rejected
+        {
+            let mut req = test::TestRequest::put()
+                .uri(&asset_path)
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", "application/json"))
+                .set_payload(update_body.clone());
+            for header in sign_request(&another_wallet, "PUT", &asset_path, &update_body, "nonce1") {
+                req = req.append_header(header);
+            }
+
+            let serv_resp = test::call_service(&app, req.to_request()).await;
+            assert_eq!(serv_resp.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(error_code(serv_resp).await, "invalid_wallet_signature");
+        }
+
+        // update signed by the authority is applied, but cannot be replayed
+        {
+            let headers = sign_request(&authority, "PUT", &asset_path, &update_body, "nonce1");
+
+            for expected_status in [StatusCode::OK, StatusCode::UNAUTHORIZED] {
+                let mut req = test::TestRequest::put()
+                    .uri(&asset_path)
+                    .append_header(("x-api-key", "111"))
+                    .append_header(("content-type", "application/json"))
+                    .set_payload(update_body.clone());
+                for header in headers.clone() {
+                    req = req.append_header(header);
+                }
+
+                let serv_resp = test::call_service(&app, req.to_request()).await;
+                assert_eq!(serv_resp.status(), expected_status);
+            }
+        }
+
+        // signature covers the body
+        {
+            let tampered_body =
+                serde_json::to_vec(&UpdateAssetRequest { name: Some("name3".to_string()), ..Default::default() })
+                    .unwrap();
+
+            let mut req = test::TestRequest::put()
+                .uri(&asset_path)
+                .append_header(("x-api-key", "111"))
+                .append_header(("content-type", "application/json"))
+                .set_payload(tampered_body);
+            for header in sign_request(&authority, "PUT", &asset_path, &update_body, "nonce2") {
+                req = req.append_header(header);
+            }
+
+            let serv_resp = test::call_service(&app, req.to_request()).await;
+            assert_eq!(serv_resp.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // transfer has to be signed by the owner
+        {
+            let transfer_path = format!("{asset_path}/transfer");
+            let transfer_body = serde_json::to_vec(&TransferAssetRequest {
+                from: owner.pubkey().to_string(),
+                to: another_wallet.pubkey().to_string(),
+            })
+            .unwrap();
+
+            for (wallet, nonce, expected_status) in [
+                (&authority, "nonce3", StatusCode::UNAUTHORIZED),
+                (&owner, "nonce1", StatusCode::OK),
+            ] {
+                let mut req = test::TestRequest::post()
+                    .uri(&transfer_path)
+                    .append_header(("x-api-key", "111"))
+                    .append_header(("content-type", "application/json"))
+                    .set_payload(transfer_body.clone());
+                for header in sign_request(wallet, "POST", &transfer_path, &transfer_body, nonce) {
+                    req = req.append_header(header);
+                }
+
+                let serv_resp = test::call_service(&app, req.to_request()).await;
+                assert_eq!(serv_resp.status(), expected_status);
+            }
+        }
+    }
+}
@@ -1,34 +1,162 @@
-// use entities::api_key::ApiKeys;
-// use interfaces::api_key_provider::ApiKeysProvider;
-
-// #[derive(Debug, Clone)]
-// pub struct ApiKeysProviderFromMemory {
-//     api_keys: ApiKeys,
-// }
-
-// impl ApiKeysProviderFromMemory {
-//     pub fn new(api_keys: ApiKeys) -> Self {
-//         Self { api_keys }
-//     }
-// }
-
-// impl ApiKeysProvider for ApiKeysProviderFromMemory {
-//     fn get_api_keys(&self) -> ApiKeys {
-//         self.api_keys.clone()
-//     }
-// }
-
-// #[derive(Clone, Debug)]
-// pub struct ApiKeysProviderService<T: ApiKeysProvider>(T);
-
-// impl ApiKeysProviderService<ApiKeysProviderFromMemory> {
-//     pub fn from_memory(api_keys: ApiKeys) -> ApiKeysProviderService<ApiKeysProviderFromMemory> {
-//         Self(ApiKeysProviderFromMemory::new(api_keys))
-//     }
-// }
-
-// impl<T: ApiKeysProvider> ApiKeysProviderService<T> {
-//     pub fn get_api_keys(&self) -> ApiKeys {
-//         self.0.get_api_keys()
-//     }
-// }
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use entities::api_key::{ApiKeyInfo, ApiKeyOwner, ApiKeyScope, ApiKeys, Username};
+use interfaces::api_key_provider::ApiKeysProvider;
+use interfaces::api_key_storage::ApiKeyStorage;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use util::api_key::{generate_api_key, hash_api_key};
+use util::webhook_signature::derive_webhook_secret;
+
+/// Resolves API keys, both the keys from the config and the keys stored in the DB.
+/// The config keys are allowed to manage the assets, only the admin ones are allowed to manage the API keys.
+/// The stored keys are cached for `cache_ttl`, so revocation on another instance takes effect within it.
+pub struct ApiKeysProviderService {
+    admin_api_keys: ApiKeys,
+    rest_api_keys: ApiKeys,
+    api_key_storage: Arc<dyn ApiKeyStorage + Sync + Send>,
+    /// Key the webhook secrets of the stored keys are derived from
+    webhook_secret_key: Option<String>,
+    cache: Mutex<HashMap<String, (ApiKeyInfo, Instant)>>,
+    cache_ttl: Duration,
+}
+
+impl ApiKeysProviderService {
+    pub fn new(
+        admin_api_keys: ApiKeys,
+        rest_api_keys: ApiKeys,
+        api_key_storage: Arc<dyn ApiKeyStorage + Sync + Send>,
+        webhook_secret_key: Option<String>,
+        cache_ttl: Duration,
+    ) -> Self {
+        Self {
+            admin_api_keys,
+            rest_api_keys,
+            api_key_storage,
+            webhook_secret_key,
+            cache: Mutex::new(HashMap::new()),
+            cache_ttl,
+        }
+    }
+
+    /// Issues a new API key for the user.
+    /// Returns the key itself along with its info and webhook secret, neither is retrievable afterwards.
+    pub async fn issue_api_key(
+        &self,
+        username: &Username,
+        label: &str,
+        scopes: &[ApiKeyScope],
+        restricted: bool,
+        expiry_timestamp: Option<NaiveDateTime>,
+    ) -> anyhow::Result<(String, ApiKeyInfo, Option<String>)> {
+        let api_key = generate_api_key();
+        let key_hash = hash_api_key(&api_key);
+        let api_key_info = self
+            .api_key_storage
+            .save_api_key(&key_hash, username, label, scopes, restricted, expiry_timestamp)
+            .await?;
+        let webhook_secret = self
+            .webhook_secret_key
+            .as_ref()
+            .map(|secret_key| derive_webhook_secret(secret_key, &key_hash));
+
+        Ok((api_key, api_key_info, webhook_secret))
+    }
+
+    /// Stored keys are only able to register callbacks if their webhook secrets can be derived
+    pub fn has_webhook_secrets(&self) -> bool {
+        self.webhook_secret_key.is_some()
+    }
+
+    pub async fn list_api_keys(&self) -> anyhow::Result<Vec<ApiKeyInfo>> {
+        self.api_key_storage.list_api_keys().await
+    }
+
+    pub async fn revoke_api_key(&self, id: i64) -> anyhow::Result<Option<ApiKeyInfo>> {
+        let revoked = self.api_key_storage.revoke_api_key(id).await?;
+        self.cache
+            .lock()
+            .expect("API keys cache lock is poisoned")
+            .retain(|_, (api_key_info, _)| api_key_info.id != id);
+
+        Ok(revoked)
+    }
+
+    /// Assigns the authority of a newly created asset to the restricted key.
+    /// Returns `false` if the authority already belongs to another key.
+    pub async fn claim_authority(&self, owner: &ApiKeyOwner, authority: &str) -> anyhow::Result<bool> {
+        match owner.restricted_key_id {
+            Some(api_key_id) => self.api_key_storage.claim_authority(api_key_id, authority).await,
+            None => Ok(true),
+        }
+    }
+
+    /// Checks whether the key is allowed to modify an asset with the given authority,
+    /// a restricted key is only allowed to modify the assets whose authority it created.
+    pub async fn is_authority_allowed(&self, owner: &ApiKeyOwner, authority: &str) -> anyhow::Result<bool> {
+        match owner.restricted_key_id {
+            Some(api_key_id) => Ok(self.api_key_storage.find_authority_owner(authority).await? == Some(api_key_id)),
+            None => Ok(true),
+        }
+    }
+
+    async fn find_stored_api_key(&self, api_key: &str) -> anyhow::Result<Option<ApiKeyInfo>> {
+        let key_hash = hash_api_key(api_key);
+
+        if let Some((api_key_info, cached_at)) = self
+            .cache
+            .lock()
+            .expect("API keys cache lock is poisoned")
+            .get(&key_hash)
+        {
+            if cached_at.elapsed() < self.cache_ttl {
+                return Ok(Some(api_key_info.clone()));
+            }
+        }
+
+        // Only the known keys are cached, so random keys cannot bloat the cache
+        let api_key_info = self.api_key_storage.find_api_key(&key_hash).await?;
+        if let Some(api_key_info) = &api_key_info {
+            self.cache
+                .lock()
+                .expect("API keys cache lock is poisoned")
+                .insert(key_hash, (api_key_info.clone(), Instant::now()));
+        }
+
+        Ok(api_key_info)
+    }
+}
+
+#[async_trait]
+impl ApiKeysProvider for ApiKeysProviderService {
+    async fn get_api_key_owner(&self, api_key: &str) -> anyhow::Result<Option<ApiKeyOwner>> {
+        if let Some(username) = self.admin_api_keys.contains_api_key_then_get_username(api_key) {
+            return Ok(Some(ApiKeyOwner {
+                username,
+                scopes: ApiKeyScope::ALL.to_vec(),
+                restricted_key_id: None,
+                stored_key_id: None,
+            }));
+        }
+        if let Some(username) = self.rest_api_keys.contains_api_key_then_get_username(api_key) {
+            return Ok(Some(ApiKeyOwner {
+                username,
+                scopes: ApiKeyScope::ASSETS.to_vec(),
+                restricted_key_id: None,
+                stored_key_id: None,
+            }));
+        }
+
+        Ok(self
+            .find_stored_api_key(api_key)
+            .await?
+            .filter(|api_key_info| api_key_info.is_active(Utc::now().naive_utc()))
+            .map(|api_key_info| ApiKeyOwner {
+                username: api_key_info.username,
+                scopes: api_key_info.scopes,
+                restricted_key_id: api_key_info.restricted.then_some(api_key_info.id),
+                stored_key_id: Some(api_key_info.id),
+            }))
+    }
+}
@@ -1,31 +1,52 @@
-use crate::converter::get_metadata_uri_for_key;
+use crate::converter::{get_binary_uri_for_key, get_metadata_uri_for_key};
 use chrono::Utc;
 use entities::dto::AssetMintStatus;
-use entities::l2::{AssetSorting, L2Asset, PublicKey};
+use entities::l2::{
+    AssetPage, AssetPagination, AssetSearchQuery, AssetSorting, L2Asset, L2AssetTransfer, L2Collection,
+    MintConfirmationJob, PublicKey,
+};
+use entities::webhook::MintCallback;
+use futures::{stream, StreamExt};
 use interfaces::{
-    asset_service::{AssetService, L1MintError, L2AssetInfo},
+    asset_service::{
+        AssetBurnError, AssetService, AssetTransferError, AssetUpdateError, L1MintError, L2AssetInfo, L2CollectionInfo,
+        NewL2Asset,
+    },
     asset_storage::{AssetMetadataStorage, BlobStorage},
-    l1_service::{L1Service, ParsedMintIxInfo},
+    collection_storage::CollectionStorage,
+    l1_service::{L1Service, ParsedMintIxInfo, SignedMintTransaction},
     l2_storage::{Bip44DerivationSequence, DerivationValues, L2Storage, L2StorageError},
+    mint_job_storage::MintJobStorage,
+    webhook_storage::WebhookStorage,
 };
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use solana_sdk::{signer::Signer, transaction::Transaction};
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
-use std::{future::Future, str::FromStr};
-use tracing::{debug, error, info};
+use tracing::{error, info, warn};
 use util::publickey::PublicKeyExt;
-use util::{hd_wallet::HdWalletProducer, nft_json::validate_metadata_contains_uris};
+use util::{
+    hd_wallet::HdWalletProducer,
+    nft_json::{attach_binary_file, validate_metadata_json},
+};
+
+/// Max number of metadata files uploaded at once during the batch creation of assets
+const METADATA_UPLOAD_CONCURRENCY: usize = 16;
 
 #[derive(Clone)]
 pub struct AssetServiceImpl {
     pub wallet_producer: HdWalletProducer,
     pub derivation_sequence: Arc<dyn Bip44DerivationSequence + Sync + Send>,
     pub l2_storage: Arc<dyn L2Storage + Sync + Send>,
+    pub collection_storage: Arc<dyn CollectionStorage + Sync + Send>,
     pub asset_metadata_storage: Arc<dyn AssetMetadataStorage + Sync + Send>,
     pub blob_storage: Arc<dyn BlobStorage + Sync + Send>,
     pub l1_service: Arc<dyn L1Service + Sync + Send>,
+    pub webhook_storage: Arc<dyn WebhookStorage + Sync + Send>,
+    pub mint_job_storage: Arc<dyn MintJobStorage + Sync + Send>,
     pub metadata_server_base_url: String,
+    pub max_json_metadata_size: usize,
 }
 
 #[async_trait::async_trait]
@@ -40,7 +61,7 @@ impl AssetService for AssetServiceImpl {
         royalty_basis_points: u16,
         collection: Option<PublicKey>,
     ) -> anyhow::Result<L2AssetInfo> {
-        validate_metadata_contains_uris(metadata_json)?;
+        validate_metadata_json(metadata_json, self.max_json_metadata_size)?;
 
         let DerivationValues { account, address } = self.derivation_sequence.next_account_and_address().await?;
         let keypair = self.wallet_producer.make_hd_wallet(account, address);
@@ -64,59 +85,154 @@ impl AssetService for AssetServiceImpl {
             update_timestamp: utc_now,
             bip44_account_num: account,
             bip44_address_num: address,
+            burnt: false,
         };
 
         self.l2_storage.save(&asset).await?;
 
-        Ok(L2AssetInfo { asset, metadata: Some(metadata_json.to_string()) })
+        self.with_collection(asset, Some(metadata_json.to_string())).await
+    }
+
+    async fn create_assets_batch(&self, assets: Vec<NewL2Asset>) -> anyhow::Result<Vec<anyhow::Result<L2AssetInfo>>> {
+        let mut failures = Vec::new();
+        let mut valid_assets = Vec::with_capacity(assets.len());
+        for (index, new_asset) in assets.into_iter().enumerate() {
+            match validate_metadata_json(&new_asset.metadata_json, self.max_json_metadata_size) {
+                Ok(()) => valid_assets.push((index, new_asset)),
+                Err(e) => failures.push((index, e.into())),
+            }
+        }
+
+        // Derivation values are reserved only for the valid assets
+        let derivation_values = self
+            .derivation_sequence
+            .next_accounts_and_addresses(valid_assets.len())
+            .await?;
+        let utc_now = Utc::now().naive_local();
+
+        let uploads = stream::iter(valid_assets.into_iter().zip(derivation_values))
+            .map(|((index, new_asset), DerivationValues { account, address })| async move {
+                let keypair = self.wallet_producer.make_hd_wallet(account, address);
+                let asset = L2Asset {
+                    pubkey: keypair.pubkey().to_bytes(),
+                    name: new_asset.name,
+                    owner: new_asset.owner,
+                    creator: new_asset.creator,
+                    collection: new_asset.collection,
+                    authority: new_asset.authority,
+                    royalty_basis_points: new_asset.royalty_basis_points,
+                    create_timestamp: utc_now,
+                    update_timestamp: utc_now,
+                    bip44_account_num: account,
+                    bip44_address_num: address,
+                    burnt: false,
+                };
+                let uploaded = self
+                    .asset_metadata_storage
+                    .put_json(&asset.pubkey, &new_asset.metadata_json)
+                    .await;
+
+                (index, asset, new_asset.metadata_json, uploaded)
+            })
+            .buffer_unordered(METADATA_UPLOAD_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut uploaded_indexes = Vec::with_capacity(uploads.len());
+        let mut uploaded_assets = Vec::with_capacity(uploads.len());
+        for (index, asset, metadata_json, uploaded) in uploads {
+            match uploaded {
+                Ok(_) => {
+                    uploaded_indexes.push(index);
+                    uploaded_assets.push((asset, Some(metadata_json)));
+                }
+                Err(e) => failures.push((index, e)),
+            }
+        }
+
+        let assets_to_save = uploaded_assets
+            .iter()
+            .map(|(asset, _)| asset.clone())
+            .collect::<Vec<L2Asset>>();
+        self.l2_storage.save_batch(&assets_to_save).await?;
+
+        let mut results = uploaded_indexes
+            .into_iter()
+            .zip(self.with_collections(uploaded_assets).await?.into_iter().map(Ok))
+            .chain(failures.into_iter().map(|(index, e)| (index, Err(e))))
+            .collect::<Vec<(usize, anyhow::Result<L2AssetInfo>)>>();
+        results.sort_unstable_by_key(|(index, _)| *index);
+
+        Ok(results.into_iter().map(|(_, result)| result).collect())
     }
 
     async fn update_asset(
         &self,
         asset_pubkey: PublicKey,
+        authorized_authority: Option<&str>,
         metadata_json: Option<&str>,
-        owner: Option<String>,
         creator: Option<String>,
         authority: Option<String>,
         name: Option<&str>,
         collection: Option<Option<PublicKey>>,
     ) -> anyhow::Result<Option<L2AssetInfo>> {
-        if let Some(mut asset) = self.l2_storage.find(&asset_pubkey).await? {
-            let metadata = if let Some(v) = metadata_json {
-                self.asset_metadata_storage.put_json(&asset_pubkey, v).await?;
-                Some(v.to_string())
-            } else {
-                self.asset_metadata_storage.get_json(&asset_pubkey).await?
-            };
-            if let Some(v) = owner {
-                asset.owner = v;
-            };
-            if let Some(v) = creator {
-                asset.creator = v;
-            };
-            if let Some(v) = authority {
-                asset.authority = v;
-            };
-            if let Some(v) = name {
-                asset.name = v.to_string();
-            };
-            if let Some(v) = collection {
-                asset.collection = v;
-            };
+        let Some(mut asset) = self.l2_storage.find(&asset_pubkey).await? else {
+            return Ok(None);
+        };
+        if asset.burnt {
+            anyhow::bail!(AssetBurnError::Burnt);
+        }
+        if let Some(metadata_json) = metadata_json {
+            validate_metadata_json(metadata_json, self.max_json_metadata_size)?;
+        }
 
-            self.l2_storage.save(&asset).await?;
+        let current_authority = authorized_authority.unwrap_or(&asset.authority).to_string();
+        if asset.authority != current_authority {
+            anyhow::bail!(AssetUpdateError::AuthorityChanged(asset.authority));
+        }
+        if let Some(v) = creator {
+            asset.creator = v;
+        };
+        if let Some(v) = authority {
+            asset.authority = v;
+        };
+        if let Some(v) = name {
+            asset.name = v.to_string();
+        };
+        if let Some(v) = collection {
+            asset.collection = v;
+        };
+        asset.update_timestamp = Utc::now().naive_local();
+
+        if !self.l2_storage.update(&asset, &current_authority).await? {
+            // The asset has been changed since it was read, figuring out how
+            return match self.l2_storage.find(&asset_pubkey).await? {
+                None => Ok(None),
+                Some(asset) if asset.burnt => anyhow::bail!(AssetBurnError::Burnt),
+                Some(asset) if asset.authority != current_authority => {
+                    anyhow::bail!(AssetUpdateError::AuthorityChanged(asset.authority))
+                }
+                Some(_) => anyhow::bail!(AssetUpdateError::NotL2Asset),
+            };
+        }
 
-            Ok(Some(L2AssetInfo { asset, metadata }))
+        let metadata = if let Some(v) = metadata_json {
+            self.asset_metadata_storage.put_json(&asset_pubkey, v).await?;
+            Some(v.to_string())
         } else {
-            Ok(None)
-        }
+            self.asset_metadata_storage.get_json(&asset_pubkey).await?
+        };
+
+        self.with_collection(asset, metadata).await.map(Some)
     }
 
     async fn fetch_asset(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<L2AssetInfo>> {
         let metadata = self.asset_metadata_storage.get_json(&asset_pubkey).await?;
-        let asset_op = self.l2_storage.find(&asset_pubkey).await?;
+        let Some(asset) = self.l2_storage.find(&asset_pubkey).await? else {
+            return Ok(None);
+        };
 
-        Ok(asset_op.map(|asset| L2AssetInfo { asset, metadata }))
+        self.with_collection(asset, metadata).await.map(Some)
     }
 
     async fn fetch_assets(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<L2AssetInfo>> {
@@ -125,62 +241,263 @@ impl AssetService for AssetServiceImpl {
             self.asset_metadata_storage.get_json_batch(asset_pubkeys).await?,
         );
 
-        Ok(asset_op
-            .into_iter()
-            .zip(metadata)
-            .map(|(asset, metadata)| L2AssetInfo { asset, metadata })
-            .collect())
+        self.with_collections(asset_op.into_iter().zip(metadata).collect())
+            .await
+    }
+
+    async fn fetch_minted_asset_pubkeys(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<PublicKey>> {
+        self.l2_storage.find_minted(asset_pubkeys).await
+    }
+
+    async fn burn_asset(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<L2AssetInfo>> {
+        match self.l2_storage.burn(&asset_pubkey).await? {
+            None => Ok(None),
+            Some(AssetMintStatus::L2) => self.fetch_asset(asset_pubkey).await,
+            Some(AssetMintStatus::Minting) => anyhow::bail!(AssetBurnError::Minting),
+            Some(AssetMintStatus::L1_SOLANA) => anyhow::bail!(AssetBurnError::MintedOnL1),
+            Some(AssetMintStatus::Burnt) => anyhow::bail!(AssetBurnError::Burnt),
+        }
+    }
+
+    async fn create_collection(
+        &self,
+        metadata_json: &str,
+        name: &str,
+        authority: &str,
+    ) -> anyhow::Result<L2CollectionInfo> {
+        validate_metadata_json(metadata_json, self.max_json_metadata_size)?;
+
+        let DerivationValues { account, address } = self.derivation_sequence.next_account_and_address().await?;
+        let keypair = self.wallet_producer.make_hd_wallet(account, address);
+        let collection_pubkey = keypair.pubkey().to_bytes();
+
+        self.asset_metadata_storage
+            .put_json(&collection_pubkey, metadata_json)
+            .await?;
+
+        let utc_now = Utc::now().naive_local();
+
+        let collection = L2Collection {
+            pubkey: collection_pubkey,
+            name: name.to_string(),
+            authority: authority.to_string(),
+            create_timestamp: utc_now,
+            update_timestamp: utc_now,
+            bip44_account_num: account,
+            bip44_address_num: address,
+        };
+
+        self.collection_storage.save_collection(&collection).await?;
+
+        Ok(L2CollectionInfo { collection, metadata: Some(metadata_json.to_string()) })
+    }
+
+    async fn update_collection(
+        &self,
+        collection_pubkey: PublicKey,
+        metadata_json: Option<&str>,
+        name: Option<&str>,
+        authority: Option<String>,
+    ) -> anyhow::Result<Option<L2CollectionInfo>> {
+        let Some(mut collection) = self.collection_storage.find_collection(&collection_pubkey).await? else {
+            return Ok(None);
+        };
+
+        let metadata = if let Some(v) = metadata_json {
+            validate_metadata_json(v, self.max_json_metadata_size)?;
+            self.asset_metadata_storage.put_json(&collection_pubkey, v).await?;
+            Some(v.to_string())
+        } else {
+            self.asset_metadata_storage.get_json(&collection_pubkey).await?
+        };
+        if let Some(v) = name {
+            collection.name = v.to_string();
+        };
+        if let Some(v) = authority {
+            collection.authority = v;
+        };
+        collection.update_timestamp = Utc::now().naive_local();
+
+        self.collection_storage.save_collection(&collection).await?;
+
+        Ok(Some(L2CollectionInfo { collection, metadata }))
+    }
+
+    async fn fetch_collection(&self, collection_pubkey: PublicKey) -> anyhow::Result<Option<L2CollectionInfo>> {
+        let Some(collection) = self.collection_storage.find_collection(&collection_pubkey).await? else {
+            return Ok(None);
+        };
+        let metadata = self.asset_metadata_storage.get_json(&collection_pubkey).await?;
+
+        Ok(Some(L2CollectionInfo { collection, metadata }))
+    }
+
+    async fn transfer_asset(
+        &self,
+        asset_pubkey: PublicKey,
+        from: &str,
+        to: &str,
+        username: &str,
+    ) -> anyhow::Result<Option<L2AssetInfo>> {
+        if let Some(asset) = self.l2_storage.transfer(&asset_pubkey, from, to, username).await? {
+            let metadata = self.asset_metadata_storage.get_json(&asset_pubkey).await?;
+            return self.with_collection(asset, metadata).await.map(Some);
+        }
+
+        // The owner hasn't been changed, figuring out why
+        match self.l2_storage.find(&asset_pubkey).await? {
+            None => Ok(None),
+            Some(asset) if asset.burnt => anyhow::bail!(AssetBurnError::Burnt),
+            Some(asset) if asset.owner != from => anyhow::bail!(AssetTransferError::WrongOwner(asset.owner)),
+            Some(_) => anyhow::bail!(AssetTransferError::NotL2Asset),
+        }
+    }
+
+    async fn fetch_transfers(&self, asset_pubkey: PublicKey) -> anyhow::Result<Vec<L2AssetTransfer>> {
+        self.l2_storage.find_transfers(&asset_pubkey).await
     }
 
     async fn fetch_metadata(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<String>> {
         self.asset_metadata_storage.get_json(&asset_pubkey).await
     }
 
+    async fn upload_binary(
+        &self,
+        asset_pubkey: PublicKey,
+        bytes: Vec<u8>,
+        mime: &str,
+    ) -> anyhow::Result<Option<L2AssetInfo>> {
+        let Some(asset) = self.l2_storage.find(&asset_pubkey).await? else {
+            return Ok(None);
+        };
+        if asset.burnt {
+            anyhow::bail!(AssetBurnError::Burnt);
+        }
+
+        self.blob_storage.put_binary(&asset_pubkey, bytes, mime).await?;
+
+        let binary_uri = get_binary_uri_for_key(&self.metadata_server_base_url, asset_pubkey);
+        let current_metadata = self.asset_metadata_storage.get_json(&asset_pubkey).await?;
+        let metadata = attach_binary_file(current_metadata.as_deref(), &asset.name, &binary_uri, mime)?;
+
+        self.asset_metadata_storage.put_json(&asset_pubkey, &metadata).await?;
+
+        self.with_collection(asset, Some(metadata)).await.map(Some)
+    }
+
+    async fn fetch_binary(&self, asset_pubkey: PublicKey) -> anyhow::Result<Option<(Vec<u8>, String)>> {
+        self.blob_storage.get_binary(&asset_pubkey).await
+    }
+
     async fn fetch_assets_by_owner(
         &self,
         owner_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2AssetInfo>> {
-        let l2_assets = self
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_assets_page = self
             .l2_storage
-            .find_by_owner(&owner_pubkey, sorting, limit, before, after)
+            .find_by_owner(&owner_pubkey, sorting, pagination)
             .await?;
-        let l2_asset_pubkeys = l2_assets.iter().map(|asset| asset.pubkey).collect::<Vec<PublicKey>>();
-        let l2_assets_metadata = self.asset_metadata_storage.get_json_batch(&l2_asset_pubkeys).await?;
 
-        Ok(l2_assets
-            .into_iter()
-            .zip(l2_assets_metadata)
-            .map(|(asset, metadata)| L2AssetInfo { asset, metadata })
-            .collect())
+        self.with_metadata_and_collections(l2_assets_page).await
     }
 
     async fn fetch_assets_by_creator(
         &self,
         creator_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2AssetInfo>> {
-        let l2_assets = self
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_assets_page = self
             .l2_storage
-            .find_by_creator(&creator_pubkey, sorting, limit, before, after)
+            .find_by_creator(&creator_pubkey, sorting, pagination)
             .await?;
-        let l2_asset_pubkeys = l2_assets.iter().map(|asset| asset.pubkey).collect::<Vec<PublicKey>>();
-        let l2_assets_metadata = self.asset_metadata_storage.get_json_batch(&l2_asset_pubkeys).await?;
 
-        Ok(l2_assets
-            .into_iter()
-            .zip(l2_assets_metadata)
-            .map(|(asset, metadata)| L2AssetInfo { asset, metadata })
-            .collect())
+        self.with_metadata_and_collections(l2_assets_page).await
+    }
+
+    async fn fetch_assets_by_authority(
+        &self,
+        authority_pubkey: &str,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_assets_page = self
+            .l2_storage
+            .find_by_authority(authority_pubkey, sorting, pagination)
+            .await?;
+
+        self.with_metadata_and_collections(l2_assets_page).await
+    }
+
+    async fn fetch_assets_by_collection(
+        &self,
+        collection_pubkey: &PublicKey,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_assets_page = self
+            .l2_storage
+            .find_by_collection(collection_pubkey, sorting, pagination)
+            .await?;
+
+        self.with_metadata_and_collections(l2_assets_page).await
+    }
+
+    async fn search_assets(
+        &self,
+        query: &AssetSearchQuery,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_assets_page = self.l2_storage.search(query, sorting, pagination).await?;
+
+        self.with_metadata_and_collections(l2_assets_page).await
+    }
+
+    async fn create_mint_transaction(
+        &self,
+        asset_pubkey: PublicKey,
+        payer: PublicKey,
+    ) -> anyhow::Result<Option<Transaction>> {
+        let Some(l2_asset) = self.l2_storage.find(&asset_pubkey).await? else {
+            return Ok(None);
+        };
+
+        let (mint_status, _) = self.l2_storage.get_mint_status_and_signature(&asset_pubkey).await?;
+        match mint_status {
+            AssetMintStatus::L2 => {}
+            AssetMintStatus::Burnt => anyhow::bail!(AssetBurnError::Burnt),
+            AssetMintStatus::Minting | AssetMintStatus::L1_SOLANA => anyhow::bail!(L1MintError::NotUnlockedL2Asset),
+        }
+
+        let to_solana_pubkey = |pubkey: &str| {
+            Pubkey::from_str(pubkey)
+                .map(|pk| pk.to_bytes())
+                .map_err(|_| L1MintError::NotSolanaPubkey(pubkey.to_string()))
+        };
+
+        let mint_ix = ParsedMintIxInfo {
+            asset_pubkey,
+            authority: Some(to_solana_pubkey(&l2_asset.authority)?),
+            owner: Some(to_solana_pubkey(&l2_asset.owner)?),
+            payer,
+            collection: l2_asset.collection,
+            name: l2_asset.name.clone(),
+            uri: get_metadata_uri_for_key(&self.metadata_server_base_url, asset_pubkey),
+        };
+        self.validate_mint_transaction_data(&mint_ix, &l2_asset)?;
+
+        self.l1_service.create_mint_transaction(&mint_ix).await.map(Some)
     }
 
-    async fn execute_asset_l1_mint(&self, tx: Transaction, exec_sync: bool) -> anyhow::Result<()> {
+    async fn execute_asset_l1_mint(
+        &self,
+        mut tx: Transaction,
+        exec_sync: bool,
+        callback: Option<MintCallback>,
+    ) -> anyhow::Result<()> {
         let mint_ix = self.l1_service.parse_mint_transaction(&tx)?;
         let asset_pubkey = mint_ix.asset_pubkey;
 
@@ -201,29 +518,23 @@ impl AssetService for AssetServiceImpl {
             }
         };
 
-        let asset_kp = self
-            .wallet_producer
-            .make_hd_wallet(l2_asset.bip44_account_num, l2_asset.bip44_address_num);
-
-        let tx_signature = match self.l1_service.execute_mint_transaction(tx, &asset_kp, exec_sync).await {
-            Ok(signature) => signature,
+        // The mint job is started before the transaction is sent, as it may land even if sending
+        // or confirming it fails, so the mint is finalized or rolled back by the mint confirmation workers
+        let signed_tx = match self
+            .sign_and_start_mint_job(&mut tx, &l2_asset, callback.as_ref())
+            .await
+        {
+            Ok(signed_tx) => signed_tx,
             Err(e) => {
+                // Nothing has been sent yet, so the asset can be unlocked right away
                 self.l2_storage.mint_didnt_happen(&asset_pubkey).await?;
                 anyhow::bail!(e);
             }
         };
 
-        if !exec_sync {
-            self.l2_storage
-                .add_l1_asset(&asset_pubkey, &tx_signature.as_ref())
-                .await?;
-
-            Self::in_background(Self::await_for_mint_status_and_save_it(
-                tx_signature,
-                asset_pubkey,
-                self.l1_service.clone(),
-                self.l2_storage.clone(),
-            ));
+        if let Err(e) = self.l1_service.execute_mint_transaction(&tx, exec_sync).await {
+            warn!("Mint transaction '{}' hasn't been confirmed: {e:#}", signed_tx.signature);
+            anyhow::bail!(L1MintError::NotConfirmed(signed_tx.signature.to_string()));
         }
 
         Ok(())
@@ -238,8 +549,88 @@ impl AssetService for AssetServiceImpl {
 }
 
 impl AssetServiceImpl {
-    const AWAIT_TIME_TO_CALL_BLOCKCHAIN: Duration = Duration::from_secs(10);
-    const AMOUNT_OF_ATTEMPTS_TO_CALL_BLOCKCHAIN: u8 = 18;
+    /// Signs the mint transaction by the asset keypair and starts the job confirming it
+    async fn sign_and_start_mint_job(
+        &self,
+        tx: &mut Transaction,
+        l2_asset: &L2Asset,
+        callback: Option<&MintCallback>,
+    ) -> anyhow::Result<SignedMintTransaction> {
+        let asset_kp = self
+            .wallet_producer
+            .make_hd_wallet(l2_asset.bip44_account_num, l2_asset.bip44_address_num);
+        let signed_tx = self.l1_service.sign_mint_transaction(tx, &asset_kp).await?;
+
+        self.mint_job_storage
+            .start_mint_job(
+                &MintConfirmationJob {
+                    asset_pubkey: l2_asset.pubkey,
+                    signature: signed_tx.signature.as_ref().to_vec(),
+                    recent_blockhash: Some(signed_tx.recent_blockhash.to_bytes()),
+                    last_valid_block_height: Some(signed_tx.last_valid_block_height),
+                    attempts: 0,
+                },
+                callback,
+            )
+            .await?;
+
+        Ok(signed_tx)
+    }
+
+    /// Attaches L2 collection the asset belongs to
+    async fn with_collection(&self, asset: L2Asset, metadata: Option<String>) -> anyhow::Result<L2AssetInfo> {
+        let collection = match asset.collection {
+            Some(collection_pubkey) => self.fetch_collection(collection_pubkey).await?,
+            None => None,
+        };
+
+        Ok(L2AssetInfo { asset, metadata, collection })
+    }
+
+    /// Attaches metadata and L2 collections to the page of assets
+    async fn with_metadata_and_collections(&self, page: AssetPage<L2Asset>) -> anyhow::Result<AssetPage<L2AssetInfo>> {
+        let l2_asset_pubkeys = page.items.iter().map(|asset| asset.pubkey).collect::<Vec<PublicKey>>();
+        let l2_assets_metadata = self.asset_metadata_storage.get_json_batch(&l2_asset_pubkeys).await?;
+
+        Ok(AssetPage {
+            items: self
+                .with_collections(page.items.into_iter().zip(l2_assets_metadata).collect())
+                .await?,
+            total: page.total,
+        })
+    }
+
+    /// Attaches L2 collections the assets belong to, fetching each collection only once
+    async fn with_collections(&self, assets: Vec<(L2Asset, Option<String>)>) -> anyhow::Result<Vec<L2AssetInfo>> {
+        let mut collection_pubkeys = assets
+            .iter()
+            .filter_map(|(asset, _)| asset.collection)
+            .collect::<Vec<PublicKey>>();
+        collection_pubkeys.sort_unstable();
+        collection_pubkeys.dedup();
+
+        let collections = self
+            .collection_storage
+            .find_collections_batch(&collection_pubkeys)
+            .await?;
+        let found_pubkeys = collections.iter().map(|c| c.pubkey).collect::<Vec<PublicKey>>();
+        let collections_metadata = self.asset_metadata_storage.get_json_batch(&found_pubkeys).await?;
+
+        let collections = collections
+            .into_iter()
+            .zip(collections_metadata)
+            .map(|(collection, metadata)| (collection.pubkey, L2CollectionInfo { collection, metadata }))
+            .collect::<HashMap<PublicKey, L2CollectionInfo>>();
+
+        Ok(assets
+            .into_iter()
+            .map(|(asset, metadata)| L2AssetInfo {
+                collection: asset.collection.and_then(|c| collections.get(&c).cloned()),
+                asset,
+                metadata,
+            })
+            .collect())
+    }
 
     fn validate_mint_transaction_data(&self, mint_ix: &ParsedMintIxInfo, l2_asset: &L2Asset) -> anyhow::Result<()> {
         let expected_metadata_url = get_metadata_uri_for_key(&self.metadata_server_base_url, l2_asset.pubkey);
@@ -276,85 +667,18 @@ impl AssetServiceImpl {
         Ok(())
     }
 
-    /// This function processes assets that are in the `MINTING` status on startup.
-    /// It retrieves the public keys and signatures of the assets from the storage, parses the signatures,
-    /// and then starts background processing to await and save the mint status for each asset.
+    /// This function enqueues mint confirmation jobs for the assets that are in the `MINTING` status
+    /// but have no job, i.e. for the mints that had been sent before the jobs were introduced.
+    /// It is safe to run it concurrently from several processes.
     pub async fn process_minting_assets_on_startup(&self) -> anyhow::Result<()> {
-        let process_in_background = |(pubkey, signature): (PublicKey, Signature)| {
-            info!("Starting mint status processing for: '{pubkey}' asset.", pubkey = pubkey.to_string());
-            Self::in_background(Self::await_for_mint_status_and_save_it(
-                signature,
-                pubkey,
-                self.l1_service.clone(),
-                self.l2_storage.clone(),
-            ))
-        };
-
         info!("Starting mint status processing.");
 
-        self.l2_storage
-            .get_pubkeys_and_signatures_of_assets_in_minting_status()
-            .await?
-            .into_iter()
-            .filter_map(|(pubkey, signature)| Self::parse_signature(signature).map(|signature| (pubkey, signature)))
-            .for_each(process_in_background);
+        let added = self.mint_job_storage.add_missing_mint_jobs().await?;
+        info!("Added {added} mint confirmation jobs for assets in minting status.");
 
         Ok(())
     }
 
-    fn in_background<F>(future: F)
-    where
-        F: Future + Send + 'static,
-        F::Output: Send + 'static,
-    {
-        tokio::spawn(future);
-    }
-
-    async fn await_for_mint_status_and_save_it(
-        signature: Signature,
-        asset_pubkey: PublicKey,
-        solana_service: Arc<dyn L1Service + Sync + Send>,
-        l2_storage: Arc<dyn L2Storage + Sync + Send>,
-    ) -> anyhow::Result<()> {
-        let asset_pubkey_as_str = asset_pubkey.to_string();
-
-        for _ in 0..=Self::AMOUNT_OF_ATTEMPTS_TO_CALL_BLOCKCHAIN {
-            match solana_service.is_asset_minted(&signature).await {
-                Ok(true) => {
-                    info!("Successfully minted asset '{asset_pubkey_as_str}' in transaction '{signature}'.");
-                    return l2_storage
-                        .finalize_mint(&asset_pubkey)
-                        .await
-                        .inspect(|_| info!("Mint for '{asset_pubkey_as_str}' successfully persisted."))
-                        .inspect_err(|e| error!("Failed to finalize mint because: {e}!"));
-                }
-                Ok(false) => {
-                    info!("Failed to mint asset '{asset_pubkey_as_str}' in transaction '{signature}'.");
-                    return l2_storage
-                        .mint_didnt_happen(&asset_pubkey)
-                        .await
-                        .inspect(|_| info!("Mint for '{asset_pubkey_as_str}' successfully rolled back."))
-                        .inspect_err(|e| error!("Failed to rollback mint because: {e}!"));
-                }
-                Err(e) => {
-                    debug!("Waiting for mint of {asset_pubkey_as_str}; {e}");
-                    tokio::time::sleep(Self::AWAIT_TIME_TO_CALL_BLOCKCHAIN).await
-                }
-            }
-        }
-
-        info!(
-            "Amount of attempts to call blockchain reached the limit - {}; Rolling back mint.",
-            Self::AMOUNT_OF_ATTEMPTS_TO_CALL_BLOCKCHAIN
-        );
-
-        l2_storage
-            .mint_didnt_happen(&asset_pubkey)
-            .await
-            .inspect(|_| info!("Mint for '{asset_pubkey_as_str}' successfully rolled back."))
-            .inspect_err(|e| error!("Failed to rollback mint because: {e}!"))
-    }
-
     async fn is_asset_already_sent_to_mint(&self, asset_pubkey: &PublicKey) -> Option<Signature> {
         self.l2_storage
             .find_l1_asset_signature(asset_pubkey)
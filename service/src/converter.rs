@@ -1,7 +1,8 @@
 use entities::{
-    dto::{Asset, AssetExtended},
-    l2::{L2Asset, PublicKey},
+    dto::{Asset, AssetExtended, CollectionExtended},
+    l2::PublicKey,
 };
+use interfaces::asset_service::{L2AssetInfo, L2CollectionInfo};
 use util::publickey::PublicKeyExt;
 
 #[derive(Debug, Clone)]
@@ -10,12 +11,11 @@ pub struct AssetDtoConverter {
 }
 
 impl AssetDtoConverter {
-    pub fn to_response_asset_dto(&self, entity: &L2Asset, metadata: Option<String>) -> Asset {
-        let asset_ex = AssetExtended {
-            asset: entity.clone(),
-            metadata_uri: get_metadata_uri_for_key(&self.metadata_server_base_url, entity.pubkey),
-            royalty_basis_points: entity.royalty_basis_points,
-        };
+    pub fn to_response_asset_dto(&self, asset_info: L2AssetInfo) -> Asset {
+        let L2AssetInfo { asset, metadata, collection } = asset_info;
+
+        let metadata_uri = get_metadata_uri_for_key(&self.metadata_server_base_url, asset.pubkey);
+        let asset_ex = AssetExtended::new(asset, metadata_uri).with_collection(collection.map(to_collection_extended));
 
         let metadata_json_text = metadata.unwrap_or("{}".to_string());
         let metadata_json_value = serde_json::from_str(&metadata_json_text).unwrap_or_default();
@@ -24,6 +24,15 @@ impl AssetDtoConverter {
     }
 }
 
+pub fn to_collection_extended(collection_info: L2CollectionInfo) -> CollectionExtended {
+    let metadata = collection_info
+        .metadata
+        .and_then(|metadata| serde_json::from_str(&metadata).ok())
+        .unwrap_or_default();
+
+    CollectionExtended { collection: collection_info.collection, metadata }
+}
+
 pub fn get_metadata_uri_for_key_str(metadata_server_base_url: &str, public_key: &str) -> String {
     format!("{}/asset/{}/metadata.json", metadata_server_base_url, public_key)
 }
@@ -31,3 +40,11 @@ pub fn get_metadata_uri_for_key_str(metadata_server_base_url: &str, public_key:
 pub fn get_metadata_uri_for_key(metadata_server_base_url: &str, public_key: PublicKey) -> String {
     get_metadata_uri_for_key_str(metadata_server_base_url, public_key.to_bs58().as_str())
 }
+
+pub fn get_collection_metadata_uri_for_key(metadata_server_base_url: &str, public_key: PublicKey) -> String {
+    format!("{}/collection/{}/metadata.json", metadata_server_base_url, public_key.to_bs58())
+}
+
+pub fn get_binary_uri_for_key(metadata_server_base_url: &str, public_key: PublicKey) -> String {
+    format!("{}/asset/{}/binary", metadata_server_base_url, public_key.to_bs58())
+}
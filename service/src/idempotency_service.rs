@@ -0,0 +1,75 @@
+use chrono::Utc;
+use entities::api_key::Username;
+use interfaces::idempotency::{IdempotencyError, IdempotencyKeyReservation, IdempotencyStorage, IdempotentResponse};
+use std::sync::Arc;
+use std::time::Duration;
+
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// Makes the retries of the non-idempotent requests safe: the first successful response to the request
+/// made with an idempotency key is kept for `ttl` and replayed to the retries with the same key.
+/// The keys are scoped by the API key user.
+/// The key of a request that has neither completed nor been released within `reservation_lease`
+/// is considered abandoned, e.g. due to a restart, and can be reserved again.
+pub struct IdempotencyService {
+    storage: Arc<dyn IdempotencyStorage + Sync + Send>,
+    ttl: Duration,
+    reservation_lease: Duration,
+}
+
+impl IdempotencyService {
+    pub fn new(storage: Arc<dyn IdempotencyStorage + Sync + Send>, ttl: Duration, reservation_lease: Duration) -> Self {
+        Self { storage, ttl, reservation_lease }
+    }
+
+    /// Reserves the key for the request, so it has to be either completed or released afterwards.
+    /// Returns the stored response if the same request is already processed.
+    /// ## Args:
+    /// * `request_hash` - digest of the request, a retry with a different one is rejected
+    pub async fn begin(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        request_hash: &str,
+    ) -> anyhow::Result<Option<IdempotentResponse>> {
+        if idempotency_key.is_empty() || idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(IdempotencyError::Malformed(MAX_IDEMPOTENCY_KEY_LEN).into());
+        }
+
+        let now = Utc::now().naive_utc();
+        match self
+            .storage
+            .reserve_idempotency_key(
+                username,
+                idempotency_key,
+                request_hash,
+                now - self.ttl,
+                now - self.reservation_lease,
+            )
+            .await?
+        {
+            IdempotencyKeyReservation::Reserved => Ok(None),
+            IdempotencyKeyReservation::Existing { request_hash: stored_hash, .. } if stored_hash != request_hash => {
+                Err(IdempotencyError::KeyReused.into())
+            }
+            IdempotencyKeyReservation::Existing { response: Some(response), .. } => Ok(Some(response)),
+            IdempotencyKeyReservation::Existing { response: None, .. } => Err(IdempotencyError::InProgress.into()),
+        }
+    }
+
+    pub async fn complete(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        response: &IdempotentResponse,
+    ) -> anyhow::Result<()> {
+        self.storage
+            .save_idempotent_response(username, idempotency_key, response)
+            .await
+    }
+
+    /// Failed requests are not replayed, so the client can fix the cause and retry with the same key
+    pub async fn release(&self, username: &Username, idempotency_key: &str) -> anyhow::Result<()> {
+        self.storage.release_idempotency_key(username, idempotency_key).await
+    }
+}
@@ -0,0 +1,106 @@
+use entities::l1_reconciliation::detect_l1_drifts;
+use entities::l2::PublicKey;
+use interfaces::{l1_reconciliation_storage::L1ReconciliationStorage, l1_service::L1Service};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use util::config::L1ReconciliationCfg;
+use util::publickey::PublicKeyExt;
+
+use crate::converter::get_metadata_uri_for_key;
+
+/// Result of a full reconciliation pass
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct L1ReconciliationSummary {
+    pub checked: usize,
+    pub drifted: usize,
+}
+
+/// Periodically compares every asset that is not burnt with its mpl-core account on L1,
+/// and records the found discrepancies in [`L1ReconciliationStorage`]:
+/// * `L1_SOLANA` assets should have an account with the same owner, name and URI
+/// * `MINTING` assets should either have no account yet, or an account that matches the asset
+/// * `L2` assets should have no account
+pub struct L1Reconciler {
+    l1_service: Arc<dyn L1Service + Sync + Send>,
+    reconciliation_storage: Arc<dyn L1ReconciliationStorage + Sync + Send>,
+    metadata_server_base_url: String,
+    cfg: L1ReconciliationCfg,
+}
+
+impl L1Reconciler {
+    /// Max number of accounts Solana RPC returns in a single `getMultipleAccounts` call
+    const BATCH_SIZE: u32 = 100;
+
+    pub fn new(
+        l1_service: Arc<dyn L1Service + Sync + Send>,
+        reconciliation_storage: Arc<dyn L1ReconciliationStorage + Sync + Send>,
+        metadata_server_base_url: String,
+        cfg: L1ReconciliationCfg,
+    ) -> Self {
+        Self { l1_service, reconciliation_storage, metadata_server_base_url, cfg }
+    }
+
+    /// Reconciles assets in a loop, should be run in background.
+    pub async fn run(self) {
+        if !self.cfg.enabled {
+            info!("L1 reconciliation is disabled.");
+            return;
+        }
+
+        info!("Starting L1 reconciliation.");
+
+        loop {
+            match self.reconcile().await {
+                Ok(summary) => info!(
+                    "L1 reconciliation completed: {} assets checked, {} assets drifted.",
+                    summary.checked, summary.drifted
+                ),
+                Err(e) => error!("Failed to reconcile assets with L1: {e}"),
+            }
+            tokio::time::sleep(Duration::from_secs(self.cfg.interval_secs)).await;
+        }
+    }
+
+    /// Goes through all the assets, batch by batch, and replaces their drifts with the newly found ones.
+    pub async fn reconcile(&self) -> anyhow::Result<L1ReconciliationSummary> {
+        let mut summary = L1ReconciliationSummary::default();
+        let mut after: Option<PublicKey> = None;
+
+        loop {
+            let assets = self
+                .reconciliation_storage
+                .find_assets_to_reconcile(after, Self::BATCH_SIZE)
+                .await?;
+
+            let Some((last, _)) = assets.last() else {
+                break;
+            };
+            after = Some(last.pubkey);
+
+            let pubkeys = assets.iter().map(|(asset, _)| asset.pubkey).collect::<Vec<_>>();
+            let accounts = self.l1_service.get_asset_accounts(&pubkeys).await?;
+
+            let mut drifts = Vec::new();
+            for ((asset, status), account) in assets.iter().zip(&accounts) {
+                let expected_uri = get_metadata_uri_for_key(&self.metadata_server_base_url, asset.pubkey);
+                let asset_drifts = detect_l1_drifts(asset, status, &expected_uri, account.as_ref());
+
+                if !asset_drifts.is_empty() {
+                    warn!("Asset '{}' has drifted from L1: {asset_drifts:?}", asset.pubkey.to_string());
+                    summary.drifted += 1;
+                }
+                drifts.extend(asset_drifts);
+            }
+
+            self.reconciliation_storage.save_l1_drifts(&pubkeys, &drifts).await?;
+            summary.checked += assets.len();
+
+            if assets.len() < Self::BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+}
@@ -1,2 +1,9 @@
+pub mod api_key_provider_service;
 pub mod asset_service_impl;
 pub mod converter;
+pub mod idempotency_service;
+pub mod l1_reconciler;
+pub mod mint_confirmation_worker;
+pub mod rate_limit_service;
+pub mod wallet_signature_verifier;
+pub mod webhook_sender;
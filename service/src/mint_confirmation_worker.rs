@@ -0,0 +1,142 @@
+use entities::dto::AssetMintStatus;
+use entities::l2::MintConfirmationJob;
+use entities::webhook::MintStatusWebhook;
+use interfaces::{l1_service::L1Service, mint_job_storage::MintJobStorage};
+use solana_sdk::{hash::Hash, signature::Signature};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, error, info};
+use util::config::MintConfirmationCfg;
+use util::publickey::PublicKeyExt;
+
+/// Processes the jobs stored in [`MintJobStorage`], that await confirmation of L1 mint transactions.
+/// Jobs are claimed with `SELECT ... FOR UPDATE SKIP LOCKED`, so any number of workers,
+/// running in any number of processes, process every job exactly once.
+pub struct MintConfirmationWorker {
+    l1_service: Arc<dyn L1Service + Sync + Send>,
+    mint_job_storage: Arc<dyn MintJobStorage + Sync + Send>,
+    cfg: MintConfirmationCfg,
+}
+
+impl MintConfirmationWorker {
+    const BATCH_SIZE: u32 = 10;
+    /// Time a claimed job is hidden from other workers, should be enough to check the whole batch
+    const LEASE: Duration = Duration::from_secs(60);
+
+    pub fn new(
+        l1_service: Arc<dyn L1Service + Sync + Send>,
+        mint_job_storage: Arc<dyn MintJobStorage + Sync + Send>,
+        cfg: MintConfirmationCfg,
+    ) -> Self {
+        Self { l1_service, mint_job_storage, cfg }
+    }
+
+    /// Starts the configured number of workers in background.
+    pub fn start(self) {
+        info!("Starting {} mint confirmation workers.", self.cfg.workers);
+
+        let worker = Arc::new(self);
+        for _ in 0..worker.cfg.workers {
+            tokio::spawn(worker.clone().run());
+        }
+    }
+
+    async fn run(self: Arc<Self>) {
+        loop {
+            match self.process_due_jobs().await {
+                Ok(processed) if processed == Self::BATCH_SIZE as usize => continue,
+                Ok(_) => {}
+                Err(e) => error!("Failed to process mint confirmation jobs: {e}"),
+            }
+            tokio::time::sleep(Duration::from_secs(self.cfg.poll_interval_secs)).await;
+        }
+    }
+
+    /// Processes a batch of due jobs, returns the number of processed jobs.
+    pub async fn process_due_jobs(&self) -> anyhow::Result<usize> {
+        let jobs = self
+            .mint_job_storage
+            .claim_due_mint_jobs(Self::BATCH_SIZE, Self::LEASE)
+            .await?;
+
+        for job in &jobs {
+            if let Err(e) = self.process_job(job).await {
+                error!("Failed to process mint confirmation of '{}': {e}", job.asset_pubkey.to_string());
+            }
+        }
+
+        Ok(jobs.len())
+    }
+
+    async fn process_job(&self, job: &MintConfirmationJob) -> anyhow::Result<()> {
+        let asset_pubkey_as_str = job.asset_pubkey.to_string();
+        let signature = Signature::try_from(job.signature.as_slice())?;
+
+        if let Some(minted) = self.l1_service.get_mint_transaction_status(&signature).await? {
+            info!("Mint of asset '{asset_pubkey_as_str}' in transaction '{signature}' is completed, minted: {minted}.");
+            return self.complete(job, signature, minted).await;
+        }
+
+        debug!("Waiting for mint of {asset_pubkey_as_str} in transaction '{signature}'.");
+
+        if self.can_still_land(job).await? {
+            let retry_after = self.retry_delay(job.attempts + 1);
+            return self
+                .mint_job_storage
+                .reschedule_mint_job(&job.asset_pubkey, retry_after)
+                .await;
+        }
+
+        // The transaction might have landed right before its blockhash expired
+        match self.l1_service.get_mint_transaction_status(&signature).await? {
+            Some(minted) => self.complete(job, signature, minted).await,
+            None => {
+                info!(
+                    "Mint transaction '{signature}' cannot land anymore; Rolling back mint of '{asset_pubkey_as_str}'."
+                );
+                self.complete(job, signature, false).await
+            }
+        }
+    }
+
+    /// Checks whether the pending transaction can still land, i.e. its blockhash hasn't expired yet.
+    /// If the blockhash is unknown, the transaction is considered lost once the configured number of checks is reached.
+    async fn can_still_land(&self, job: &MintConfirmationJob) -> anyhow::Result<bool> {
+        let Some(blockhash) = job.recent_blockhash else {
+            return Ok(job.attempts + 1 < self.cfg.max_attempts);
+        };
+
+        if let Some(last_valid_block_height) = job.last_valid_block_height {
+            if self.l1_service.get_block_height().await? <= last_valid_block_height {
+                return Ok(true);
+            }
+        }
+
+        self.l1_service
+            .is_blockhash_valid(&Hash::new_from_array(blockhash))
+            .await
+    }
+
+    async fn complete(&self, job: &MintConfirmationJob, signature: Signature, minted: bool) -> anyhow::Result<()> {
+        let status = if minted { AssetMintStatus::L1_SOLANA } else { AssetMintStatus::L2 };
+        let webhook = MintStatusWebhook {
+            asset_pubkey: job.asset_pubkey.to_string(),
+            status,
+            signature: signature.to_string(),
+        };
+
+        self.mint_job_storage
+            .complete_mint_job(&job.asset_pubkey, minted, &serde_json::to_string(&webhook)?)
+            .await
+            .inspect(|_| info!("Mint for '{}' successfully completed, minted: {minted}.", webhook.asset_pubkey))
+    }
+
+    /// Delay before the next check, that is doubled with every check
+    fn retry_delay(&self, attempts: u32) -> Duration {
+        let delay = self
+            .cfg
+            .initial_retry_delay_secs
+            .saturating_mul(2u64.saturating_pow(attempts.saturating_sub(1)));
+        Duration::from_secs(delay.min(self.cfg.max_retry_delay_secs))
+    }
+}
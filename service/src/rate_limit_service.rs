@@ -0,0 +1,114 @@
+use chrono::{Days, Utc};
+use entities::api_key::Username;
+use interfaces::rate_limit::{ConsumedQuota, QuotaOperation, QuotaStorage, RateLimitError};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::error;
+use util::config::RateLimitsCfg;
+use util::rate_limiter::{client_ip, RateLimiter};
+
+/// Throttles the requests per API key user, or per client IP for the unauthenticated requests,
+/// and enforces the daily quotas of the users.
+/// The rate limits are kept in memory of each instance, while the quotas are shared via the DB.
+pub struct RateLimitService {
+    cfg: RateLimitsCfg,
+    rate_limiter: RateLimiter,
+    quota_storage: Arc<dyn QuotaStorage + Sync + Send>,
+}
+
+impl RateLimitService {
+    pub fn new(cfg: RateLimitsCfg, quota_storage: Arc<dyn QuotaStorage + Sync + Send>) -> Self {
+        Self { cfg, rate_limiter: RateLimiter::default(), quota_storage }
+    }
+
+    pub fn check_user(&self, username: &Username) -> Result<(), RateLimitError> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        self.rate_limiter
+            .try_acquire(&format!("user:{}", username.inner()), self.cfg.user_limits(username).rate)
+            .map_err(|retry_after| RateLimitError::TooManyRequests { retry_after })
+    }
+
+    /// Throttles the unauthenticated request by the client IP, see [`client_ip`] for how the client is identified
+    pub fn check_client_ip(&self, peer: Option<IpAddr>, forwarded_for: Option<&str>) -> Result<(), RateLimitError> {
+        if !self.cfg.enabled {
+            return Ok(());
+        }
+
+        let client_ip = client_ip(peer, forwarded_for, &self.cfg.trusted_proxies)
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        self.rate_limiter
+            .try_acquire(&format!("ip:{client_ip}"), self.cfg.anonymous)
+            .map_err(|retry_after| RateLimitError::TooManyRequests { retry_after })
+    }
+
+    /// Counts `count` operations against the daily quota of the user at once,
+    /// fails with `RateLimitError::QuotaExceeded` if the quota doesn't have room for all of them.
+    /// Returns the counted operations, so the failed ones can be refunded,
+    /// or `None` if the operation isn't limited for the user.
+    pub async fn consume_daily_quota(
+        &self,
+        username: &Username,
+        operation: QuotaOperation,
+        count: u32,
+    ) -> anyhow::Result<Option<ConsumedQuota>> {
+        if !self.cfg.enabled || count == 0 {
+            return Ok(None);
+        }
+
+        let user_limits = self.cfg.user_limits(username);
+        let limit = match operation {
+            QuotaOperation::AssetCreation => user_limits.daily_asset_creations,
+            QuotaOperation::Mint => user_limits.daily_mints,
+        };
+        let Some(limit) = limit else {
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        let today = now.date_naive();
+        if self
+            .quota_storage
+            .try_consume_daily_quota(username, operation, today, count, limit)
+            .await?
+        {
+            return Ok(Some(ConsumedQuota { username: username.clone(), operation, date: today, count }));
+        }
+
+        // Quotas are reset at the UTC midnight
+        let retry_after = today
+            .checked_add_days(Days::new(1))
+            .and_then(|tomorrow| tomorrow.and_hms_opt(0, 0, 0))
+            .and_then(|midnight| (midnight - now.naive_utc()).to_std().ok())
+            .unwrap_or(Duration::ZERO);
+
+        Err(RateLimitError::QuotaExceeded { operation, limit, retry_after }.into())
+    }
+
+    /// Refunds `count` of the consumed operations that have failed.
+    /// The refund is best effort, the failure is only logged, as the request has failed anyway.
+    pub async fn refund_daily_quota(&self, consumed: Option<&ConsumedQuota>, count: u32) {
+        let Some(consumed) = consumed else {
+            return;
+        };
+        if count == 0 {
+            return;
+        }
+
+        if let Err(e) = self
+            .quota_storage
+            .refund_daily_quota(&consumed.username, consumed.operation, consumed.date, count.min(consumed.count))
+            .await
+        {
+            error!(
+                "Failed to refund '{count}' of '{}' daily quota of '{}': {e:#}",
+                consumed.operation,
+                consumed.username.inner()
+            );
+        }
+    }
+}
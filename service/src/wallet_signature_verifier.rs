@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use interfaces::wallet_signature::{SignatureNonceStorage, WalletSignature, WalletSignatureError};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use util::wallet_signature::wallet_signature_message;
+
+const MAX_NONCE_LEN: usize = 64;
+
+/// Verifies that L2 mutations are signed by the wallet of the asset authority (or owner for transfers),
+/// so the API key holder cannot change the asset on behalf of the user.
+pub struct WalletSignatureVerifier {
+    nonce_storage: Arc<dyn SignatureNonceStorage + Sync + Send>,
+    required: bool,
+    max_ttl: Duration,
+}
+
+impl WalletSignatureVerifier {
+    pub fn new(nonce_storage: Arc<dyn SignatureNonceStorage + Sync + Send>, required: bool, max_ttl: Duration) -> Self {
+        Self { nonce_storage, required, max_ttl }
+    }
+
+    /// If not required, the signatures are only verified when provided
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Verifies the signature of the request by the signer, the nonce is consumed only if the signature is valid.
+    /// ## Args:
+    /// * `signer` - base58 encoded public key of the wallet that must sign the request
+    /// * `request_digest` - see `util::wallet_signature::request_digest`
+    pub async fn verify(
+        &self,
+        signer: &str,
+        request_digest: &str,
+        signature: Option<&WalletSignature>,
+    ) -> anyhow::Result<()> {
+        let Some(signature) = signature else {
+            if self.required {
+                return Err(WalletSignatureError::Missing(signer.to_string()).into());
+            }
+            return Ok(());
+        };
+
+        if signature.nonce.is_empty() || signature.nonce.len() > MAX_NONCE_LEN {
+            return Err(WalletSignatureError::Malformed(format!(
+                "nonce must be from 1 to {MAX_NONCE_LEN} characters long"
+            ))
+            .into());
+        }
+        let now = Utc::now().timestamp();
+        if signature.expiry <= now {
+            return Err(WalletSignatureError::Expired.into());
+        }
+        if signature.expiry - now > self.max_ttl.as_secs() as i64 {
+            return Err(WalletSignatureError::ExpiryTooFar(self.max_ttl.as_secs()).into());
+        }
+
+        let signer_pubkey =
+            Pubkey::from_str(signer).map_err(|_| WalletSignatureError::InvalidSigner(signer.to_string()))?;
+        let ed25519_signature = Signature::from_str(&signature.signature)
+            .map_err(|e| WalletSignatureError::Malformed(format!("signature is not base58 encoded: {e}")))?;
+        let message = wallet_signature_message(request_digest, &signature.nonce, signature.expiry);
+        if !ed25519_signature.verify(signer_pubkey.as_ref(), message.as_bytes()) {
+            return Err(WalletSignatureError::InvalidSignature.into());
+        }
+
+        let expiry = DateTime::from_timestamp(signature.expiry, 0)
+            .ok_or(WalletSignatureError::Expired)?
+            .naive_utc();
+        if !self
+            .nonce_storage
+            .save_signature_nonce(signer, &signature.nonce, expiry)
+            .await?
+        {
+            return Err(WalletSignatureError::ReplayedNonce.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::Context;
+use chrono::Utc;
+use entities::api_key::{Username, WebhookSecrets};
+use entities::webhook::WebhookDelivery;
+use interfaces::webhook_storage::WebhookStorage;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+use url::{Host, Url};
+use util::callback_url::{is_public_ip, parse_callback_url, CallbackUrlError};
+use util::config::WebhookCfg;
+use util::webhook_signature::{derive_webhook_secret, sign_webhook_payload};
+
+/// Header with the hex encoded HMAC-SHA256 signature of the webhook
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-spell-signature";
+/// Header with the unix timestamp the webhook has been signed at
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-spell-timestamp";
+
+/// Delivers webhooks stored in [`WebhookStorage`] to the clients' callback URLs.
+/// Failed deliveries are retried with exponential backoff, up to `max_attempts` times.
+/// Webhooks of the issued API keys are signed with the secrets derived from `webhook_secret_key`,
+/// webhooks of the keys from the config are signed with the secrets of their users.
+pub struct WebhookSender {
+    webhook_storage: Arc<dyn WebhookStorage + Sync + Send>,
+    webhook_secrets: WebhookSecrets,
+    webhook_secret_key: Option<String>,
+    http_client: reqwest::Client,
+    cfg: WebhookCfg,
+}
+
+impl WebhookSender {
+    const BATCH_SIZE: u32 = 20;
+
+    pub fn new(
+        webhook_storage: Arc<dyn WebhookStorage + Sync + Send>,
+        webhook_secrets: WebhookSecrets,
+        webhook_secret_key: Option<String>,
+        cfg: WebhookCfg,
+    ) -> anyhow::Result<Self> {
+        // Redirects aren't followed, since the redirect target could point to the internal network
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(cfg.request_timeout_secs))
+            .dns_resolver(Arc::new(PublicHostResolver))
+            .redirect(Policy::none())
+            .build()?;
+
+        Ok(Self { webhook_storage, webhook_secrets, webhook_secret_key, http_client, cfg })
+    }
+
+    /// Delivers due webhooks in a loop, should be run in background.
+    pub async fn run(self) {
+        info!("Starting webhook delivery.");
+
+        loop {
+            match self.deliver_due_webhooks().await {
+                Ok(delivered) if delivered == Self::BATCH_SIZE as usize => continue,
+                Ok(_) => {}
+                Err(e) => error!("Failed to deliver webhooks: {e}"),
+            }
+            tokio::time::sleep(Duration::from_secs(self.cfg.poll_interval_secs)).await;
+        }
+    }
+
+    /// Delivers a batch of due webhooks, returns the number of processed webhooks.
+    pub async fn deliver_due_webhooks(&self) -> anyhow::Result<usize> {
+        // Webhooks are sent sequentially, so the lease has to cover the whole batch
+        let lease = Duration::from_secs(self.cfg.request_timeout_secs * (Self::BATCH_SIZE as u64 + 1));
+        let webhooks = self.webhook_storage.claim_due_webhooks(Self::BATCH_SIZE, lease).await?;
+
+        for webhook in &webhooks {
+            match self.deliver(webhook).await {
+                Ok(()) => self.webhook_storage.mark_webhook_delivered(webhook.webhook_id).await?,
+                Err(e) => {
+                    let attempts = webhook.attempts + 1;
+                    let retry_after = (attempts < self.cfg.max_attempts).then(|| self.retry_delay(attempts));
+                    warn!(
+                        "Failed to deliver webhook '{}' to '{}', attempt {attempts}: {e:#}",
+                        webhook.webhook_id, webhook.url
+                    );
+                    self.webhook_storage
+                        .mark_webhook_failed(webhook.webhook_id, &format!("{e:#}"), retry_after)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(webhooks.len())
+    }
+
+    async fn deliver(&self, webhook: &WebhookDelivery) -> anyhow::Result<()> {
+        let secret = match &webhook.api_key_hash {
+            Some(api_key_hash) => {
+                let Some(secret_key) = &self.webhook_secret_key else {
+                    anyhow::bail!("No webhook secret key configured for the issued API keys");
+                };
+                derive_webhook_secret(secret_key, api_key_hash)
+            }
+            None => match self.webhook_secrets.get_secret(&Username::new(&webhook.username)) {
+                Some(secret) => secret.to_string(),
+                None => anyhow::bail!("No webhook secret configured for '{}'", webhook.username),
+            },
+        };
+
+        // IP hosts aren't resolved by the HTTP client, so they're checked here
+        let url = parse_callback_url(&webhook.url)?;
+        let timestamp = Utc::now().timestamp();
+        let signature = sign_webhook_payload(&secret, timestamp, &webhook.payload);
+
+        self.http_client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp)
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .body(webhook.payload.clone())
+            .send()
+            .await
+            .context("Failed to send webhook")?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Delay before the next attempt, that is doubled with every failed attempt
+    fn retry_delay(&self, attempts: u32) -> Duration {
+        let delay = self
+            .cfg
+            .initial_retry_delay_secs
+            .saturating_mul(2u64.saturating_pow(attempts.saturating_sub(1)));
+        Duration::from_secs(delay.min(self.cfg.max_retry_delay_secs))
+    }
+}
+
+/// Validates the callback URL and resolves its host, the URL is refused if any of the host addresses isn't public.
+pub async fn resolve_callback_url(callback: &str) -> Result<Url, CallbackUrlError> {
+    let url = parse_callback_url(callback)?;
+    if let Some(Host::Domain(domain)) = url.host() {
+        resolve_public_host(domain, url.port_or_known_default().unwrap_or_default()).await?;
+    }
+
+    Ok(url)
+}
+
+async fn resolve_public_host(host: &str, port: u16) -> Result<Vec<SocketAddr>, CallbackUrlError> {
+    let addrs = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| CallbackUrlError::Unresolved)?
+        .collect::<Vec<_>>();
+    if addrs.is_empty() {
+        return Err(CallbackUrlError::Unresolved);
+    }
+    if addrs.iter().any(|addr| !is_public_ip(addr.ip())) {
+        return Err(CallbackUrlError::NotPublicHost);
+    }
+
+    Ok(addrs)
+}
+
+/// DNS resolver of the webhook client, that refuses hosts resolving to non-public addresses.
+/// Checking the addresses the request is actually sent to protects from DNS rebinding
+/// between the callback registration and the webhook delivery.
+struct PublicHostResolver;
+
+impl Resolve for PublicHostResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = resolve_public_host(name.as_str(), 0).await?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
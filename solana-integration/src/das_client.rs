@@ -0,0 +1,119 @@
+use anyhow::Context;
+use entities::dto::Asset;
+use entities::l2::PublicKey;
+use interfaces::l1_asset_provider::L1AssetProvider;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::error;
+use util::publickey::PublicKeyExt;
+
+/// Client of an upstream DAS API, that serves assets minted on Solana.
+pub struct DasClient {
+    url: String,
+    http_client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
+impl DasClient {
+    pub fn new(url: &str, request_timeout: Duration) -> anyhow::Result<Self> {
+        let http_client = reqwest::Client::builder().timeout(request_timeout).build()?;
+
+        Ok(Self { url: url.to_string(), http_client })
+    }
+}
+
+#[async_trait::async_trait]
+impl L1AssetProvider for DasClient {
+    async fn get_assets(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<Option<Asset>>> {
+        if asset_pubkeys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = asset_pubkeys.iter().map(|pubkey| pubkey.to_bs58()).collect::<Vec<_>>();
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": "spell",
+            "method": "getAssetBatch",
+            "params": { "ids": ids },
+        });
+
+        let response: JsonRpcResponse<Vec<Option<Value>>> = self
+            .http_client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send DAS request!")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Failed to parse DAS response!")?;
+
+        parse_assets(response, asset_pubkeys.len())
+    }
+}
+
+/// Items are parsed one by one, so an asset the DTO can't represent doesn't fail the whole response,
+/// it's logged and treated as unknown instead.
+fn parse_assets(response: JsonRpcResponse<Vec<Option<Value>>>, expected: usize) -> anyhow::Result<Vec<Option<Asset>>> {
+    if let Some(error) = response.error {
+        anyhow::bail!("DAS request has failed: {error}");
+    }
+
+    let items = response.result.unwrap_or_default();
+    if items.len() != expected {
+        anyhow::bail!("DAS returned {} assets, expected {expected}", items.len());
+    }
+
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            let item = item?;
+            let id = item.get("id").cloned();
+            serde_json::from_value::<Asset>(item)
+                .inspect_err(|e| error!("Failed to parse DAS asset {id:?}: {e}"))
+                .ok()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GET_ASSET_BATCH_RESPONSE: &str = include_str!("../tests/data/das_get_asset_batch_response.json");
+
+    #[test]
+    fn test_parse_assets() {
+        let response = serde_json::from_str(GET_ASSET_BATCH_RESPONSE).unwrap();
+        let assets = parse_assets(response, 2).unwrap();
+
+        let asset = assets[0].as_ref().unwrap();
+        assert_eq!(asset.id, "FNiKANejAn3VguZYF6vGtHiWXEYWsoMLEiGh1xr6sB6F");
+        assert_eq!(asset.ownership.owner, "67vHA8qZGCJKw1UNGUJZME4MwEWDRGWzp7MGvsut43A8");
+        // plugins are passed through as the upstream serves them
+        assert_eq!(asset.plugins.as_ref().unwrap()["royalties"]["authority"]["type"], "UpdateAuthority");
+        assert!(assets[1].is_none());
+
+        let response = serde_json::from_str(GET_ASSET_BATCH_RESPONSE).unwrap();
+        assert!(parse_assets(response, 3).is_err());
+    }
+
+    #[test]
+    fn test_parse_malformed_asset() {
+        let mut response: JsonRpcResponse<Vec<Option<Value>>> = serde_json::from_str(GET_ASSET_BATCH_RESPONSE).unwrap();
+        let mut items = response.result.take().unwrap();
+        items.push(Some(serde_json::json!({ "id": "malformed", "interface": "MplCoreAsset" })));
+        response.result = Some(items);
+
+        let assets = parse_assets(response, 3).unwrap();
+        assert!(assets[0].is_some());
+        assert!(assets[2].is_none());
+    }
+}
@@ -1,10 +1,16 @@
 use anyhow::Context;
-use interfaces::l1_service::{L1MintTransactionError, L1Service, ParsedMintIxInfo};
+use entities::l1_reconciliation::L1AssetAccount;
+use entities::l2::PublicKey;
+use interfaces::l1_service::{L1MintTransactionError, L1Service, ParsedMintIxInfo, SignedMintTransaction};
 use std::sync::Arc;
 
-use mpl_core::instructions::CreateV1InstructionArgs;
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::instructions::{CreateV1Builder, CreateV1InstructionArgs};
 use solana_client::nonblocking::rpc_client::{self, RpcClient};
-use solana_sdk::signature::{Signature, Signer};
+use solana_sdk::clock::MAX_PROCESSING_AGE;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
 use solana_sdk::signer::keypair::Keypair;
 use solana_sdk::transaction::Transaction;
 use tracing::{error, info};
@@ -25,53 +31,135 @@ impl L1Service for SolanaService {
         parse_mint_transaction(tx)
     }
 
-    async fn execute_mint_transaction(
+    async fn create_mint_transaction(&self, mint_info: &ParsedMintIxInfo) -> anyhow::Result<Transaction> {
+        let recent_blockhash = self
+            .client
+            .get_latest_blockhash()
+            .await
+            .context("Failed to get latest blockhash!")?;
+
+        Ok(build_mint_transaction(mint_info, recent_blockhash))
+    }
+
+    async fn sign_mint_transaction(
         &self,
-        mut tx: Transaction,
+        tx: &mut Transaction,
         asset_keypair: &Keypair,
-        exec_sync: bool,
-    ) -> anyhow::Result<Signature> {
-        tx.try_sign(&[asset_keypair], tx.message.recent_blockhash)?;
+    ) -> anyhow::Result<SignedMintTransaction> {
+        let recent_blockhash = tx.message.recent_blockhash;
+        tx.try_sign(&[asset_keypair], recent_blockhash)?;
 
-        if exec_sync {
-            let signature = self.client.send_and_confirm_transaction(&tx).await?;
+        // The blockhash is valid for MAX_PROCESSING_AGE blocks since it has been produced,
+        // so the transaction cannot land later than MAX_PROCESSING_AGE blocks from now.
+        let last_valid_block_height = self.get_block_height().await? + MAX_PROCESSING_AGE as u64;
 
-            info!(
-                "Mint transaction '{signature}' for asset '{asset_pubkey}' successfully sent and confirmed!",
-                asset_pubkey = asset_keypair.pubkey().to_string()
-            );
+        Ok(SignedMintTransaction { signature: tx.signatures[0], recent_blockhash, last_valid_block_height })
+    }
 
-            Ok(signature)
+    async fn execute_mint_transaction(&self, tx: &Transaction, exec_sync: bool) -> anyhow::Result<()> {
+        if exec_sync {
+            let signature = self.client.send_and_confirm_transaction(tx).await?;
+            info!("Mint transaction '{signature}' successfully sent and confirmed!");
         } else {
-            let signature = self.client.send_transaction(&tx).await?;
-
-            info!(
-                "Mint transaction '{signature}' for asset '{asset_pubkey}' successfully sent!",
-                asset_pubkey = asset_keypair.pubkey().to_string()
-            );
-
-            Ok(signature)
+            let signature = self.client.send_transaction(tx).await?;
+            info!("Mint transaction '{signature}' successfully sent!");
         }
+
+        Ok(())
     }
 
     async fn is_asset_minted(&self, tx_signature: &Signature) -> anyhow::Result<bool> {
-        match self
+        self.get_mint_transaction_status(tx_signature)
+            .await?
+            .context("Corresponding transaction is being processed!")
+    }
+
+    async fn get_mint_transaction_status(&self, tx_signature: &Signature) -> anyhow::Result<Option<bool>> {
+        let status = self
             .client
             .get_signature_status(tx_signature)
             .await
-            .context("Failed to send RPC call!")?
-            .context("Corresponding transaction is being processed!")?
-        {
+            .context("Failed to send RPC call!")?;
+
+        Ok(status.map(|result| match result {
             Ok(_) => {
                 info!("Mint for '{tx_signature}' is successful.");
-                Ok(true)
+                true
             }
             Err(e) => {
                 error!("Mint for '{tx_signature}' has failed because: {e}!");
-                Ok(false)
+                false
             }
-        }
+        }))
+    }
+
+    async fn get_block_height(&self) -> anyhow::Result<u64> {
+        self.client.get_block_height().await.context("Failed to send RPC call!")
     }
+
+    async fn is_blockhash_valid(&self, blockhash: &Hash) -> anyhow::Result<bool> {
+        self.client
+            .is_blockhash_valid(blockhash, self.client.commitment())
+            .await
+            .context("Failed to send RPC call!")
+    }
+
+    async fn get_asset_accounts(&self, asset_pubkeys: &[PublicKey]) -> anyhow::Result<Vec<Option<L1AssetAccount>>> {
+        let pubkeys = asset_pubkeys
+            .iter()
+            .map(|pubkey| Pubkey::new_from_array(*pubkey))
+            .collect::<Vec<_>>();
+
+        let accounts = self
+            .client
+            .get_multiple_accounts(&pubkeys)
+            .await
+            .context("Failed to send RPC call!")?;
+
+        Ok(pubkeys
+            .iter()
+            .zip(accounts)
+            .map(|(pubkey, account)| {
+                account
+                    .filter(|account| account.owner == mpl_core::ID)
+                    .and_then(|account| parse_asset_account(pubkey, &account.data))
+            })
+            .collect())
+    }
+}
+
+/// Deserializes the base part of mpl-core asset account, plugins that follow it are ignored.
+fn parse_asset_account(pubkey: &Pubkey, data: &[u8]) -> Option<L1AssetAccount> {
+    BaseAssetV1::from_bytes(data)
+        .inspect_err(|e| error!("Account '{pubkey}' is not an mpl-core asset: {e}"))
+        .ok()
+        .map(|asset| L1AssetAccount {
+            pubkey: pubkey.to_bytes(),
+            owner: asset.owner.to_bytes(),
+            name: asset.name,
+            uri: asset.uri,
+        })
+}
+
+/// Builds the unsigned transaction that contains single Mint instruction,
+/// the asset keypair signs it only once it's sent for execution and the asset is locked for minting.
+fn build_mint_transaction(mint_info: &ParsedMintIxInfo, recent_blockhash: Hash) -> Transaction {
+    let payer = Pubkey::new_from_array(mint_info.payer);
+
+    let create_asset_ix = CreateV1Builder::new()
+        .asset(Pubkey::new_from_array(mint_info.asset_pubkey))
+        .payer(payer)
+        .name(mint_info.name.clone())
+        .uri(mint_info.uri.clone())
+        .authority(mint_info.authority.map(Pubkey::new_from_array))
+        .owner(mint_info.owner.map(Pubkey::new_from_array))
+        .collection(mint_info.collection.map(Pubkey::new_from_array))
+        .instruction();
+
+    let mut tx = Transaction::new_with_payer(&[create_asset_ix], Some(&payer));
+    tx.message.recent_blockhash = recent_blockhash;
+
+    tx
 }
 
 /// Extracts values from the tranaction that contains Mint instruction,
@@ -142,8 +230,7 @@ fn parse_mint_transaction(tx: &Transaction) -> anyhow::Result<ParsedMintIxInfo>
 #[cfg(test)]
 mod test {
     use super::*;
-    use mpl_core::instructions::CreateV1Builder;
-    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+    use solana_sdk::signature::Signer;
 
     #[test]
     fn test_parse_mint_transaction() {
@@ -175,4 +262,35 @@ mod test {
         assert_eq!(parsed.owner, Some(payer_kp.pubkey().to_bytes()));
         assert_eq!(parsed.collection, Some(collection.to_bytes()));
     }
+
+    #[test]
+    fn test_build_mint_transaction() {
+        let asset_kp = Keypair::new();
+        let payer_kp = Keypair::new();
+        let authority_kp = Keypair::new();
+
+        let mint_info = ParsedMintIxInfo {
+            asset_pubkey: asset_kp.pubkey().to_bytes(),
+            authority: Some(authority_kp.pubkey().to_bytes()),
+            owner: Some(payer_kp.pubkey().to_bytes()),
+            payer: payer_kp.pubkey().to_bytes(),
+            collection: Some(Pubkey::new_unique().to_bytes()),
+            name: "name1".to_string(),
+            uri: format!("http://localhost/asset/{}/metadata.json", asset_kp.pubkey()),
+        };
+        let recent_blockhash = Hash::new_unique();
+
+        let mut tx = build_mint_transaction(&mint_info, recent_blockhash);
+
+        assert_eq!(parse_mint_transaction(&tx).unwrap(), mint_info);
+        assert_eq!(tx.message.recent_blockhash, recent_blockhash);
+        assert!(!tx.is_signed());
+
+        // the asset keypair signs it only on execution
+        tx.partial_sign(&[&payer_kp, &authority_kp], recent_blockhash);
+        assert!(!tx.is_signed());
+        tx.partial_sign(&[&asset_kp], recent_blockhash);
+        assert!(tx.is_signed());
+        assert!(tx.verify().is_ok());
+    }
 }
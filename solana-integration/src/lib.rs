@@ -1 +1,2 @@
+pub mod das_client;
 pub mod l1_service_solana;
@@ -0,0 +1,144 @@
+use chrono::NaiveDateTime;
+use entities::api_key::{ApiKeyInfo, ApiKeyScope, Username};
+use interfaces::api_key_storage::ApiKeyStorage;
+use sqlx::{postgres::PgRow, QueryBuilder};
+
+use crate::l2_storage_pg::L2StoragePg;
+
+const API_KEY_COLUMNS: &str = r#"
+    api_key_id,
+    username,
+    label,
+    scopes,
+    restricted,
+    create_timestamp,
+    expiry_timestamp,
+    revocation_timestamp
+"#;
+
+#[async_trait::async_trait]
+impl ApiKeyStorage for L2StoragePg {
+    async fn save_api_key(
+        &self,
+        key_hash: &str,
+        username: &Username,
+        label: &str,
+        scopes: &[ApiKeyScope],
+        restricted: bool,
+        expiry_timestamp: Option<NaiveDateTime>,
+    ) -> anyhow::Result<ApiKeyInfo> {
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                INSERT INTO api_keys
+                (
+                    key_hash,
+                    username,
+                    label,
+                    scopes,
+                    restricted,
+                    expiry_timestamp
+                )
+            "#,
+        );
+        query_builder.push_values(std::iter::once(key_hash), |mut builder, key_hash| {
+            builder
+                .push_bind(key_hash)
+                .push_bind(username.inner())
+                .push_bind(label)
+                .push_bind(scopes.iter().map(ToString::to_string).collect::<Vec<_>>())
+                .push_bind(restricted)
+                .push_bind(expiry_timestamp);
+        });
+        query_builder.push(" RETURNING ").push(API_KEY_COLUMNS);
+
+        let row = query_builder.build().fetch_one(&self.pool).await?;
+
+        api_key_from_row(row)
+    }
+
+    async fn find_api_key(&self, key_hash: &str) -> anyhow::Result<Option<ApiKeyInfo>> {
+        QueryBuilder::new("SELECT ")
+            .push(API_KEY_COLUMNS)
+            .push(" FROM api_keys WHERE key_hash = ")
+            .push_bind(key_hash)
+            .build()
+            .fetch_optional(&self.pool)
+            .await?
+            .map(api_key_from_row)
+            .transpose()
+    }
+
+    async fn list_api_keys(&self) -> anyhow::Result<Vec<ApiKeyInfo>> {
+        QueryBuilder::new("SELECT ")
+            .push(API_KEY_COLUMNS)
+            .push(" FROM api_keys ORDER BY api_key_id")
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(api_key_from_row)
+            .collect()
+    }
+
+    async fn revoke_api_key(&self, id: i64) -> anyhow::Result<Option<ApiKeyInfo>> {
+        QueryBuilder::new(
+            r#"
+                UPDATE api_keys
+                SET revocation_timestamp = COALESCE(revocation_timestamp, NOW())
+                WHERE api_key_id =
+            "#,
+        )
+        .push_bind(id)
+        .push(" RETURNING ")
+        .push(API_KEY_COLUMNS)
+        .build()
+        .fetch_optional(&self.pool)
+        .await?
+        .map(api_key_from_row)
+        .transpose()
+    }
+
+    async fn claim_authority(&self, api_key_id: i64, authority: &str) -> anyhow::Result<bool> {
+        // Authorities of the existing assets and collections are not claimable,
+        // otherwise the key could take over the assets created by the other keys
+        let mut query_builder = QueryBuilder::new("INSERT INTO api_key_authorities (authority, api_key_id) SELECT ");
+        query_builder
+            .push_bind(authority)
+            .push(", ")
+            .push_bind(api_key_id)
+            .push(" WHERE NOT EXISTS (SELECT 1 FROM l2_assets_v1 WHERE asset_authority = ")
+            .push_bind(authority)
+            .push(") AND NOT EXISTS (SELECT 1 FROM l2_collections_v1 WHERE collection_authority = ")
+            .push_bind(authority)
+            .push(") ON CONFLICT (authority) DO NOTHING");
+        query_builder.build().execute(&self.pool).await?;
+
+        Ok(self.find_authority_owner(authority).await? == Some(api_key_id))
+    }
+
+    async fn find_authority_owner(&self, authority: &str) -> anyhow::Result<Option<i64>> {
+        QueryBuilder::new("SELECT api_key_id FROM api_key_authorities WHERE authority = ")
+            .push_bind(authority)
+            .build()
+            .fetch_optional(&self.pool)
+            .await?
+            .map(|row| L2StoragePg::try_get_from_row(&row, "api_key_id"))
+            .transpose()
+    }
+}
+
+fn api_key_from_row(row: PgRow) -> anyhow::Result<ApiKeyInfo> {
+    Ok(ApiKeyInfo {
+        id: L2StoragePg::try_get_from_row(&row, "api_key_id")?,
+        username: Username::new(L2StoragePg::try_get_from_row::<String>(&row, "username")?),
+        label: L2StoragePg::try_get_from_row(&row, "label")?,
+        scopes: L2StoragePg::try_get_from_row::<Vec<String>>(&row, "scopes")?
+            .iter()
+            .map(|scope| scope.parse().map_err(anyhow::Error::msg))
+            .collect::<anyhow::Result<_>>()?,
+        restricted: L2StoragePg::try_get_from_row(&row, "restricted")?,
+        create_timestamp: L2StoragePg::try_get_from_row(&row, "create_timestamp")?,
+        expiry_timestamp: L2StoragePg::try_get_from_row(&row, "expiry_timestamp")?,
+        revocation_timestamp: L2StoragePg::try_get_from_row(&row, "revocation_timestamp")?,
+    })
+}
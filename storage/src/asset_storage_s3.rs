@@ -5,6 +5,7 @@ use interfaces::asset_storage::{AssetMetadataStorage, BlobStorage};
 use std::sync::Arc;
 
 const MIME_JSON: &str = "application/json";
+const MIME_OCTET_STREAM: &str = "application/octet-stream";
 
 #[derive(Clone, Debug)]
 pub struct S3Storage {
@@ -91,7 +92,7 @@ impl BlobStorage for S3Storage {
         Ok(())
     }
 
-    async fn get_binary(&self, pubkey: &PublicKey) -> anyhow::Result<(Vec<u8>, String)> {
+    async fn get_binary(&self, pubkey: &PublicKey) -> anyhow::Result<Option<(Vec<u8>, String)>> {
         let key = make_binary_key(pubkey);
         let resp = self
             .s3_client
@@ -99,13 +100,17 @@ impl BlobStorage for S3Storage {
             .bucket(&self.asset_bucket)
             .key(key)
             .send()
-            .await?;
-
-        let bytes = resp.body.collect().await?.into_bytes().to_vec();
-
-        let mime = resp.content_type.unwrap_or("application/octet-stream".to_string());
+            .await;
 
-        Ok((bytes, mime))
+        match resp {
+            Ok(ok_resp) => {
+                let mime = ok_resp.content_type.unwrap_or(MIME_OCTET_STREAM.to_string());
+                let bytes = ok_resp.body.collect().await?.into_bytes().to_vec();
+                Ok(Some((bytes, mime)))
+            }
+            Err(SdkError::ServiceError(service_error)) if service_error.err().is_no_such_key() => Ok(None),
+            Err(e) => anyhow::bail!(e),
+        }
     }
 }
 
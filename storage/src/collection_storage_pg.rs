@@ -0,0 +1,123 @@
+use entities::l2::{L2Collection, PublicKey};
+use interfaces::collection_storage::CollectionStorage;
+use sqlx::{postgres::PgRow, QueryBuilder};
+use tracing::error;
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl CollectionStorage for L2StoragePg {
+    async fn save_collection(&self, collection: &L2Collection) -> anyhow::Result<()> {
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                INSERT INTO l2_collections_v1
+                (
+                    collection_pubkey,
+                    collection_name,
+                    collection_authority,
+                    collection_create_timestamp,
+                    collection_last_update_timestamp,
+                    bip44_account_num,
+                    bip44_address_num
+                )
+            "#,
+        );
+        query_builder.push_values(std::iter::once(collection), |mut builder, collection| {
+            builder
+                .push_bind(collection.pubkey)
+                .push_bind(&collection.name)
+                .push_bind(&collection.authority)
+                .push_bind(collection.create_timestamp)
+                .push_bind(collection.update_timestamp)
+                .push_bind(collection.bip44_account_num as i64)
+                .push_bind(collection.bip44_address_num as i64);
+        });
+        query_builder.push(
+            r#"
+                ON CONFLICT(collection_pubkey) DO UPDATE SET
+                collection_name = EXCLUDED.collection_name,
+                collection_authority = EXCLUDED.collection_authority,
+                collection_last_update_timestamp = EXCLUDED.collection_last_update_timestamp;
+            "#,
+        );
+
+        let _ = query_builder.build().execute(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn find_collection(&self, pubkey: &PublicKey) -> anyhow::Result<Option<L2Collection>> {
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                SELECT
+                    collection_pubkey,
+                    collection_name,
+                    collection_authority,
+                    collection_create_timestamp,
+                    collection_last_update_timestamp,
+                    bip44_account_num,
+                    bip44_address_num
+                FROM l2_collections_v1
+                WHERE collection_pubkey =
+            "#,
+        );
+
+        query_builder.push_bind(pubkey);
+
+        query_builder
+            .build()
+            .fetch_optional(&self.pool)
+            .await?
+            .map(collection_from_row)
+            .transpose()
+            .inspect_err(|e| error!("CollectionStorage error: {e}"))
+    }
+
+    async fn find_collections_batch(&self, pubkeys: &[PublicKey]) -> anyhow::Result<Vec<L2Collection>> {
+        if pubkeys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                SELECT
+                    collection_pubkey,
+                    collection_name,
+                    collection_authority,
+                    collection_create_timestamp,
+                    collection_last_update_timestamp,
+                    bip44_account_num,
+                    bip44_address_num
+                FROM l2_collections_v1
+                WHERE collection_pubkey IN(
+            "#,
+        );
+
+        let mut separated = query_builder.separated(", ");
+
+        for pubkey in pubkeys {
+            separated.push_bind(pubkey);
+        }
+
+        separated.push_unseparated(")");
+
+        query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(collection_from_row)
+            .collect::<Result<Vec<L2Collection>, _>>()
+    }
+}
+
+fn collection_from_row(row: PgRow) -> anyhow::Result<L2Collection> {
+    Ok(L2Collection {
+        pubkey: L2StoragePg::try_get_from_row(&row, "collection_pubkey")?,
+        name: L2StoragePg::try_get_from_row(&row, "collection_name")?,
+        authority: L2StoragePg::try_get_from_row(&row, "collection_authority")?,
+        create_timestamp: L2StoragePg::try_get_from_row(&row, "collection_create_timestamp")?,
+        update_timestamp: L2StoragePg::try_get_from_row(&row, "collection_last_update_timestamp")?,
+        bip44_account_num: L2StoragePg::try_get_from_row::<i64>(&row, "bip44_account_num")? as u32,
+        bip44_address_num: L2StoragePg::try_get_from_row::<i64>(&row, "bip44_address_num")? as u32,
+    })
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveDateTime;
+use entities::api_key::Username;
+use interfaces::idempotency::{IdempotencyKeyReservation, IdempotencyStorage, IdempotentResponse};
+use sqlx::QueryBuilder;
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl IdempotencyStorage for L2StoragePg {
+    async fn reserve_idempotency_key(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        request_hash: &str,
+        expired_before: NaiveDateTime,
+        abandoned_before: NaiveDateTime,
+    ) -> anyhow::Result<IdempotencyKeyReservation> {
+        let mut tx = self.pool.begin().await?;
+
+        QueryBuilder::new("DELETE FROM idempotency_keys WHERE username = ")
+            .push_bind(username.inner())
+            .push(" AND (create_timestamp < ")
+            .push_bind(expired_before)
+            .push(" OR (response_status IS NULL AND create_timestamp < ")
+            .push_bind(abandoned_before)
+            .push("))")
+            .build()
+            .execute(&mut tx)
+            .await?;
+
+        let mut query_builder =
+            QueryBuilder::new("INSERT INTO idempotency_keys (username, idempotency_key, request_hash) ");
+        query_builder.push_values(std::iter::once(idempotency_key), |mut builder, idempotency_key| {
+            builder
+                .push_bind(username.inner())
+                .push_bind(idempotency_key)
+                .push_bind(request_hash);
+        });
+        query_builder.push(" ON CONFLICT (username, idempotency_key) DO NOTHING");
+        let reserved = query_builder.build().execute(&mut tx).await?.rows_affected() == 1;
+
+        let reservation = if reserved {
+            IdempotencyKeyReservation::Reserved
+        } else {
+            let row = QueryBuilder::new(
+                r#"
+                    SELECT request_hash, response_status, response_content_type, response_body
+                    FROM idempotency_keys WHERE username =
+                "#,
+            )
+            .push_bind(username.inner())
+            .push(" AND idempotency_key = ")
+            .push_bind(idempotency_key)
+            .build()
+            .fetch_one(&mut tx)
+            .await?;
+
+            let response_status: Option<i16> = Self::try_get_from_row(&row, "response_status")?;
+            let response = match response_status {
+                Some(status) => Some(IdempotentResponse {
+                    status: status as u16,
+                    content_type: Self::try_get_from_row(&row, "response_content_type")?,
+                    body: Self::try_get_from_row::<Option<Vec<u8>>>(&row, "response_body")?.unwrap_or_default(),
+                }),
+                None => None,
+            };
+
+            IdempotencyKeyReservation::Existing {
+                request_hash: Self::try_get_from_row(&row, "request_hash")?,
+                response,
+            }
+        };
+
+        tx.commit().await?;
+
+        Ok(reservation)
+    }
+
+    async fn save_idempotent_response(
+        &self,
+        username: &Username,
+        idempotency_key: &str,
+        response: &IdempotentResponse,
+    ) -> anyhow::Result<()> {
+        QueryBuilder::new("UPDATE idempotency_keys SET response_status = ")
+            .push_bind(response.status as i16)
+            .push(", response_content_type = ")
+            .push_bind(response.content_type.as_deref())
+            .push(", response_body = ")
+            .push_bind(response.body.as_slice())
+            .push(" WHERE username = ")
+            .push_bind(username.inner())
+            .push(" AND idempotency_key = ")
+            .push_bind(idempotency_key)
+            .build()
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn release_idempotency_key(&self, username: &Username, idempotency_key: &str) -> anyhow::Result<()> {
+        QueryBuilder::new("DELETE FROM idempotency_keys WHERE response_status IS NULL AND username = ")
+            .push_bind(username.inner())
+            .push(" AND idempotency_key = ")
+            .push_bind(idempotency_key)
+            .build()
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use entities::dto::AssetMintStatus as EntityAssetMintStatus;
+use entities::l1_reconciliation::{L1Drift, L1DriftKind as EntityL1DriftKind};
+use entities::l2::{L2Asset, PublicKey};
+use interfaces::l1_reconciliation_storage::L1ReconciliationStorage;
+use sqlx::{postgres::PgRow, QueryBuilder};
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl L1ReconciliationStorage for L2StoragePg {
+    async fn find_assets_to_reconcile(
+        &self,
+        after: Option<PublicKey>,
+        limit: u32,
+    ) -> anyhow::Result<Vec<(L2Asset, EntityAssetMintStatus)>> {
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                SELECT
+                    asset_pubkey,
+                    asset_name,
+                    asset_owner,
+                    asset_creator,
+                    asset_collection,
+                    asset_authority,
+                    royalty_basis_points,
+                    asset_create_timestamp,
+                    asset_last_update_timestamp,
+                    bip44_account_num,
+                    bip44_address_num,
+                    current_state
+                FROM l2_assets_v1
+                WHERE current_state != 'BURNT'
+            "#,
+        );
+
+        if let Some(after) = after {
+            query_builder.push(" AND asset_pubkey > ").push_bind(after);
+        }
+
+        query_builder
+            .push(" ORDER BY asset_pubkey LIMIT ")
+            .push_bind(limit as i64)
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|row| {
+                let status = Self::status_from_row(&row)?.into();
+                Ok((Self::asset_from_row(row)?, status))
+            })
+            .collect()
+    }
+
+    async fn save_l1_drifts(&self, checked: &[PublicKey], drifts: &[L1Drift]) -> anyhow::Result<()> {
+        if checked.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        let mut query_builder = QueryBuilder::new("DELETE FROM l1_reconciliation WHERE asset_pubkey IN(");
+        let mut separated = query_builder.separated(", ");
+        for pubkey in checked {
+            separated.push_bind(pubkey);
+        }
+        separated.push_unseparated(")");
+
+        query_builder.build().execute(&mut tx).await?;
+
+        if !drifts.is_empty() {
+            let mut query_builder = QueryBuilder::new(
+                r#"
+                    INSERT INTO l1_reconciliation
+                    (
+                        asset_pubkey,
+                        drift_kind,
+                        db_value,
+                        chain_value
+                    )
+                "#,
+            );
+            query_builder.push_values(drifts, |mut builder, drift| {
+                builder
+                    .push_bind(drift.asset_pubkey)
+                    .push_bind(L1DriftKind::from(drift.kind))
+                    .push_bind(&drift.db_value)
+                    .push_bind(&drift.chain_value);
+            });
+            query_builder.push(" ON CONFLICT(asset_pubkey, drift_kind) DO NOTHING");
+
+            query_builder.build().execute(&mut tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_l1_drifts(&self, asset_pubkey: &PublicKey) -> anyhow::Result<Vec<L1Drift>> {
+        QueryBuilder::new(
+            r#"
+                SELECT asset_pubkey, drift_kind, db_value, chain_value
+                FROM l1_reconciliation
+                WHERE asset_pubkey =
+            "#,
+        )
+        .push_bind(asset_pubkey)
+        .push(" ORDER BY drift_kind")
+        .build()
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(drift_from_row)
+        .collect()
+    }
+}
+
+fn drift_from_row(row: PgRow) -> anyhow::Result<L1Drift> {
+    Ok(L1Drift {
+        asset_pubkey: L2StoragePg::try_get_from_row(&row, "asset_pubkey")?,
+        kind: L2StoragePg::try_get_from_row::<L1DriftKind>(&row, "drift_kind")?.into(),
+        db_value: L2StoragePg::try_get_from_row(&row, "db_value")?,
+        chain_value: L2StoragePg::try_get_from_row(&row, "chain_value")?,
+    })
+}
+
+#[derive(sqlx::Type)]
+#[sqlx(type_name = "l1_drift_kind", rename_all = "SCREAMING_SNAKE_CASE")]
+enum L1DriftKind {
+    MissingOnChain,
+    OwnerMismatch,
+    NameMismatch,
+    UriMismatch,
+    MintedButL2,
+}
+
+impl From<EntityL1DriftKind> for L1DriftKind {
+    fn from(kind: EntityL1DriftKind) -> Self {
+        match kind {
+            EntityL1DriftKind::MissingOnChain => Self::MissingOnChain,
+            EntityL1DriftKind::OwnerMismatch => Self::OwnerMismatch,
+            EntityL1DriftKind::NameMismatch => Self::NameMismatch,
+            EntityL1DriftKind::UriMismatch => Self::UriMismatch,
+            EntityL1DriftKind::MintedButL2 => Self::MintedButL2,
+        }
+    }
+}
+
+impl From<L1DriftKind> for EntityL1DriftKind {
+    fn from(kind: L1DriftKind) -> Self {
+        match kind {
+            L1DriftKind::MissingOnChain => Self::MissingOnChain,
+            L1DriftKind::OwnerMismatch => Self::OwnerMismatch,
+            L1DriftKind::NameMismatch => Self::NameMismatch,
+            L1DriftKind::UriMismatch => Self::UriMismatch,
+            L1DriftKind::MintedButL2 => Self::MintedButL2,
+        }
+    }
+}
@@ -1,20 +1,26 @@
 use anyhow::Context;
 use entities::dto::AssetMintStatus as EntityAssetMintStatus;
-use entities::l2::{AssetSortBy, AssetSortDirection, AssetSorting, L2Asset, PublicKey};
+use entities::l2::{
+    AssetPage, AssetPagination, AssetSearchQuery, AssetSortBy, AssetSortDirection, AssetSorting, L2Asset,
+    L2AssetTransfer, PublicKey, SearchConditionType,
+};
 use interfaces::l2_storage::{Bip44DerivationSequence, DerivationValues, L2Storage};
 use sqlx::{
     postgres::{PgConnectOptions, PgPoolOptions, PgRow},
-    query, ConnectOptions, PgExecutor, PgPool, Postgres, QueryBuilder, Row,
+    query, ConnectOptions, Encode, PgExecutor, PgPool, Postgres, QueryBuilder, Row, Type,
 };
 use std::str::FromStr;
 use std::thread::{sleep, spawn};
 use std::time::Duration;
 use tracing::log::LevelFilter;
 use tracing::{error, info, warn};
-use util::base64_encode_decode::decode_timestamp_and_asset_pubkey;
+use util::base64_encode_decode::decode_asset_cursor;
 use util::config::DatabaseCfg;
 use util::publickey::PublicKeyExt;
 
+/// Postgres allows up to 65535 bind parameters per query, an asset takes 11 of them
+const MAX_ASSETS_PER_INSERT: usize = 65535 / 11;
+
 pub struct L2StoragePg {
     pub pool: PgPool,
 }
@@ -72,6 +78,51 @@ impl L2Storage for L2StoragePg {
         Ok(())
     }
 
+    async fn save_batch(&self, assets: &[L2Asset]) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for chunk in assets.chunks(MAX_ASSETS_PER_INSERT) {
+            let mut query_builder = QueryBuilder::new(
+                r#"
+                    INSERT INTO l2_assets_v1
+                    (
+                        asset_pubkey,
+                        asset_name,
+                        asset_owner,
+                        asset_creator,
+                        asset_collection,
+                        asset_authority,
+                        royalty_basis_points,
+                        asset_create_timestamp,
+                        asset_last_update_timestamp,
+                        bip44_account_num,
+                        bip44_address_num
+                    )
+                "#,
+            );
+            query_builder.push_values(chunk, |mut builder, asset| {
+                builder
+                    .push_bind(asset.pubkey)
+                    .push_bind(&asset.name)
+                    .push_bind(&asset.owner)
+                    .push_bind(&asset.creator)
+                    .push_bind(asset.collection)
+                    .push_bind(&asset.authority)
+                    .push_bind(asset.royalty_basis_points as i16)
+                    .push_bind(asset.create_timestamp)
+                    .push_bind(asset.update_timestamp)
+                    .push_bind(asset.bip44_account_num as i64)
+                    .push_bind(asset.bip44_address_num as i64);
+            });
+
+            query_builder.build().execute(&mut tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     async fn find(&self, pubkey: &PublicKey) -> anyhow::Result<Option<L2Asset>> {
         let mut query_builder = QueryBuilder::new(
             r#"
@@ -86,7 +137,8 @@ impl L2Storage for L2StoragePg {
                     asset_create_timestamp,
                     asset_last_update_timestamp,
                     bip44_account_num,
-                    bip44_address_num
+                    bip44_address_num,
+                    current_state
                 FROM l2_assets_v1
                 WHERE current_state != 'L1_SOLANA' AND asset_pubkey =
             "#,
@@ -117,7 +169,8 @@ impl L2Storage for L2StoragePg {
                     asset_last_update_timestamp,
                     royalty_basis_points,
                     bip44_account_num,
-                    bip44_address_num
+                    bip44_address_num,
+                    current_state
                 FROM l2_assets_v1
                 WHERE current_state != 'L1_SOLANA' AND asset_pubkey IN(
             "#,
@@ -140,30 +193,148 @@ impl L2Storage for L2StoragePg {
             .collect::<Result<Vec<L2Asset>, _>>()
     }
 
+    async fn find_minted(&self, pubkeys: &[PublicKey]) -> anyhow::Result<Vec<PublicKey>> {
+        if pubkeys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut query_builder = QueryBuilder::new(
+            r#"
+                SELECT asset_pubkey
+                FROM l2_assets_v1
+                WHERE current_state = 'L1_SOLANA' AND asset_pubkey IN(
+            "#,
+        );
+
+        let mut separated = query_builder.separated(", ");
+
+        for pubkey in pubkeys {
+            separated.push_bind(pubkey);
+        }
+
+        separated.push_unseparated(")");
+
+        query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(|row| Self::try_get_from_row(row, "asset_pubkey"))
+            .collect()
+    }
+
     async fn find_by_owner(
         &self,
         owner_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2Asset>> {
-        self.find_by("asset_owner", owner_pubkey, sorting, limit, before, after)
-            .await
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        self.find_by("asset_owner", owner_pubkey, sorting, pagination).await
     }
 
     async fn find_by_creator(
         &self,
         creator_pubkey: &str,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2Asset>> {
-        self.find_by("asset_creator", creator_pubkey, sorting, limit, before, after)
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        self.find_by("asset_creator", creator_pubkey, sorting, pagination).await
+    }
+
+    async fn find_by_authority(
+        &self,
+        authority_pubkey: &str,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        self.find_by("asset_authority", authority_pubkey, sorting, pagination)
+            .await
+    }
+
+    async fn find_by_collection(
+        &self,
+        collection_pubkey: &PublicKey,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        self.find_by("asset_collection", collection_pubkey, sorting, pagination)
+            .await
+    }
+
+    async fn search(
+        &self,
+        query: &AssetSearchQuery,
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        self.fetch_page(|query_builder| Self::add_search_conditions(query_builder, query), sorting, pagination)
             .await
     }
 
+    async fn update(&self, asset: &L2Asset, authority: &str) -> anyhow::Result<bool> {
+        let mut query_builder = QueryBuilder::new("UPDATE l2_assets_v1 SET asset_name = ");
+        query_builder
+            .push_bind(&asset.name)
+            .push(", asset_creator = ")
+            .push_bind(&asset.creator)
+            .push(", asset_collection = ")
+            .push_bind(asset.collection)
+            .push(", asset_authority = ")
+            .push_bind(&asset.authority)
+            .push(", asset_last_update_timestamp = ")
+            .push_bind(asset.update_timestamp)
+            .push(" WHERE current_state = 'L2' AND asset_authority = ")
+            .push_bind(authority)
+            .push(" AND asset_pubkey = ")
+            .push_bind(asset.pubkey);
+
+        let result = query_builder.build().execute(&self.pool).await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn transfer(
+        &self,
+        asset_pubkey: &PublicKey,
+        from: &str,
+        to: &str,
+        username: &str,
+    ) -> anyhow::Result<Option<L2Asset>> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(asset) = Self::change_owner(asset_pubkey, from, to, &mut tx).await? else {
+            return Ok(None);
+        };
+        Self::add_transfer(asset_pubkey, from, to, username, &mut tx).await?;
+
+        tx.commit().await?;
+
+        Ok(Some(asset))
+    }
+
+    async fn find_transfers(&self, asset_pubkey: &PublicKey) -> anyhow::Result<Vec<L2AssetTransfer>> {
+        QueryBuilder::new(
+            r#"
+                SELECT
+                    asset_pubkey,
+                    from_owner,
+                    to_owner,
+                    transfer_timestamp,
+                    api_key_username
+                FROM l2_asset_transfers
+                WHERE asset_pubkey =
+            "#,
+        )
+        .push_bind(asset_pubkey)
+        .push(" ORDER BY transfer_timestamp ASC, transfer_id ASC")
+        .build()
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(Self::transfer_from_row)
+        .collect()
+    }
+
     async fn lock_asset_before_minting(&self, asset_pubkey: &PublicKey) -> anyhow::Result<bool> {
         let update_result = QueryBuilder::new(
             r#"
@@ -184,22 +355,7 @@ impl L2Storage for L2StoragePg {
     }
 
     async fn add_l1_asset(&self, asset_pubkey: &PublicKey, tx_signature: &[u8]) -> anyhow::Result<()> {
-        query(
-            r#"
-                INSERT INTO asset_minting_status
-                (
-                    asset_pubkey,
-                    signature
-                )
-                VALUES ($1, $2)
-                "#,
-        )
-        .bind(asset_pubkey)
-        .bind(tx_signature)
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        Self::add_l1_asset(asset_pubkey, tx_signature, &self.pool).await
     }
 
     async fn finalize_mint(&self, asset_pubkey: &PublicKey) -> anyhow::Result<()> {
@@ -224,6 +380,22 @@ impl L2Storage for L2StoragePg {
         Ok(())
     }
 
+    async fn burn(&self, asset_pubkey: &PublicKey) -> anyhow::Result<Option<EntityAssetMintStatus>> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(status) = Self::lock_asset_state(asset_pubkey, &mut tx).await? else {
+            return Ok(None);
+        };
+
+        if let AssetMintStatus::L2 = status {
+            Self::burn_l2_asset(asset_pubkey, &mut tx).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(Some(status.into()))
+    }
+
     async fn get_mint_status_and_signature(
         &self,
         asset_pubkey: &PublicKey,
@@ -232,7 +404,7 @@ impl L2Storage for L2StoragePg {
 
         let status = Self::get_asset_mint_status(asset_pubkey, &mut tx).await?.into();
 
-        if let EntityAssetMintStatus::L2 = status {
+        if let EntityAssetMintStatus::L2 | EntityAssetMintStatus::Burnt = status {
             return Ok((status, None));
         }
 
@@ -290,7 +462,33 @@ impl L2StoragePg {
         }
     }
 
-    async fn finalize_mint_of_l2_asset(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+    pub(crate) async fn add_l1_asset(
+        asset_pubkey: &PublicKey,
+        tx_signature: &[u8],
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        query(
+            r#"
+                INSERT INTO asset_minting_status
+                (
+                    asset_pubkey,
+                    signature
+                )
+                VALUES ($1, $2)
+                "#,
+        )
+        .bind(asset_pubkey)
+        .bind(tx_signature)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn finalize_mint_of_l2_asset(
+        asset_pubkey: &PublicKey,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<()> {
         QueryBuilder::new(
             r#"
             UPDATE l2_assets_v1
@@ -306,7 +504,7 @@ impl L2StoragePg {
         Ok(())
     }
 
-    async fn finalize_mint_of_asset_minting_status(
+    pub(crate) async fn finalize_mint_of_asset_minting_status(
         asset_pubkey: &PublicKey,
         executor: impl PgExecutor<'_>,
     ) -> anyhow::Result<()> {
@@ -325,7 +523,10 @@ impl L2StoragePg {
         Ok(())
     }
 
-    async fn cancel_l2_minting(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+    pub(crate) async fn cancel_l2_minting(
+        asset_pubkey: &PublicKey,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<()> {
         QueryBuilder::new(
             r#"
                     UPDATE l2_assets_v1
@@ -341,7 +542,10 @@ impl L2StoragePg {
         Ok(())
     }
 
-    async fn cancel_l1_minting(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+    pub(crate) async fn cancel_l1_minting(
+        asset_pubkey: &PublicKey,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<()> {
         QueryBuilder::new(
             r#"
                     UPDATE asset_minting_status
@@ -357,6 +561,116 @@ impl L2StoragePg {
         Ok(())
     }
 
+    async fn change_owner(
+        asset_pubkey: &PublicKey,
+        from: &str,
+        to: &str,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<Option<L2Asset>> {
+        let mut query_builder = QueryBuilder::new(
+            r#"
+            UPDATE l2_assets_v1
+            SET asset_last_update_timestamp = NOW(), asset_owner =
+        "#,
+        );
+        query_builder
+            .push_bind(to)
+            .push(" WHERE current_state = 'L2' AND asset_owner = ")
+            .push_bind(from)
+            .push(" AND asset_pubkey = ")
+            .push_bind(asset_pubkey)
+            .push(
+                r#"
+            RETURNING
+                asset_pubkey,
+                asset_name,
+                asset_owner,
+                asset_creator,
+                asset_collection,
+                asset_authority,
+                royalty_basis_points,
+                asset_create_timestamp,
+                asset_last_update_timestamp,
+                bip44_account_num,
+                bip44_address_num,
+                current_state
+        "#,
+            );
+
+        query_builder
+            .build()
+            .fetch_optional(executor)
+            .await?
+            .map(Self::asset_from_row)
+            .transpose()
+    }
+
+    async fn add_transfer(
+        asset_pubkey: &PublicKey,
+        from: &str,
+        to: &str,
+        username: &str,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<()> {
+        query(
+            r#"
+                INSERT INTO l2_asset_transfers
+                (
+                    asset_pubkey,
+                    from_owner,
+                    to_owner,
+                    api_key_username
+                )
+                VALUES ($1, $2, $3, $4)
+                "#,
+        )
+        .bind(asset_pubkey)
+        .bind(from)
+        .bind(to)
+        .bind(username)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn lock_asset_state(
+        asset_pubkey: &PublicKey,
+        executor: impl PgExecutor<'_>,
+    ) -> anyhow::Result<Option<AssetMintStatus>> {
+        QueryBuilder::new(
+            r#"
+            SELECT
+                current_state
+            FROM l2_assets_v1
+            WHERE asset_pubkey =
+        "#,
+        )
+        .push_bind(asset_pubkey)
+        .push(" FOR UPDATE")
+        .build()
+        .fetch_optional(executor)
+        .await?
+        .map(|row| Self::status_from_row(&row))
+        .transpose()
+    }
+
+    async fn burn_l2_asset(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+        QueryBuilder::new(
+            r#"
+            UPDATE l2_assets_v1
+            SET current_state = 'BURNT', asset_last_update_timestamp = NOW()
+            WHERE current_state = 'L2' AND asset_pubkey =
+        "#,
+        )
+        .push_bind(asset_pubkey)
+        .build()
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
     async fn find_l1_asset_signature(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> Option<Vec<u8>> {
         QueryBuilder::new(
             r#"
@@ -391,7 +705,7 @@ impl L2StoragePg {
         .fetch_one(executor)
         .await?;
 
-        Self::status_from_row(state_row)
+        Self::status_from_row(&state_row)
     }
 
     async fn get_pubkeys_and_signatures_of_assets_in_minting_status(
@@ -429,15 +743,81 @@ impl L2StoragePg {
         Ok(pubkeys_to_signature)
     }
 
-    async fn find_by(
+    async fn find_by<'a, T>(
         &self,
         column_name: &str,
-        primary_key: &str,
+        primary_key: T,
         sorting: &AssetSorting,
-        limit: u32,
-        before: Option<&str>,
-        after: Option<&str>,
-    ) -> anyhow::Result<Vec<L2Asset>> {
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>>
+    where
+        T: 'a + Encode<'a, Postgres> + Type<Postgres> + Send + Copy,
+    {
+        self.fetch_page(
+            |query_builder| {
+                query_builder.push(column_name).push(" = ").push_bind(primary_key);
+            },
+            sorting,
+            pagination,
+        )
+        .await
+    }
+
+    /// Builds the search conditions, combined with AND or OR, depending on the query condition type.
+    fn add_search_conditions<'a>(query_builder: &mut QueryBuilder<'a, Postgres>, query: &'a AssetSearchQuery) {
+        let separator = match query.condition_type {
+            SearchConditionType::All => " AND ",
+            SearchConditionType::Any => " OR ",
+        };
+
+        query_builder.push("(");
+        let mut conditions = query_builder.separated(separator);
+
+        if let Some(owner) = &query.owner {
+            conditions.push("asset_owner = ").push_bind_unseparated(owner);
+        }
+        if let Some(creator) = &query.creator {
+            conditions.push("asset_creator = ").push_bind_unseparated(creator);
+        }
+        if let Some(authority) = &query.authority {
+            conditions.push("asset_authority = ").push_bind_unseparated(authority);
+        }
+        if let Some(collection) = &query.collection {
+            conditions.push("asset_collection = ").push_bind_unseparated(collection);
+        }
+        if let Some(name) = &query.name {
+            conditions
+                .push("asset_name ILIKE ")
+                .push_bind_unseparated(format!("%{}%", escape_like_pattern(name)));
+        }
+        if let Some(burnt) = query.burnt {
+            conditions.push(if burnt { "current_state = 'BURNT'" } else { "current_state != 'BURNT'" });
+        }
+        if let Some(state) = &query.state {
+            conditions
+                .push("current_state = ")
+                .push_bind_unseparated(AssetMintStatus::from(state.clone()));
+        }
+
+        if query.is_empty() {
+            conditions.push("TRUE");
+        }
+        query_builder.push(")");
+    }
+
+    /// Fetches the page of assets matching the conditions, together with the total number of matching assets.
+    /// The conditions are pushed twice: into the query that counts the assets, and into the one that selects the page.
+    async fn fetch_page<'a>(
+        &self,
+        push_conditions: impl Fn(&mut QueryBuilder<'a, Postgres>),
+        sorting: &AssetSorting,
+        pagination: &AssetPagination,
+    ) -> anyhow::Result<AssetPage<L2Asset>> {
+        let mut count_query_builder = QueryBuilder::new("SELECT COUNT(*) AS total FROM l2_assets_v1 WHERE ");
+        push_conditions(&mut count_query_builder);
+        let total_row = count_query_builder.build().fetch_one(&self.pool).await?;
+        let total = Self::try_get_from_row::<i64>(&total_row, "total")?;
+
         let mut query_builder = QueryBuilder::new(
             r#"
                 SELECT
@@ -451,15 +831,20 @@ impl L2StoragePg {
                     asset_create_timestamp,
                     asset_last_update_timestamp,
                     bip44_account_num,
-                    bip44_address_num
+                    bip44_address_num,
+                    current_state
                 FROM l2_assets_v1
-                WHERE current_state != 'L1_SOLANA' AND
+                WHERE
             "#,
         );
+        push_conditions(&mut query_builder);
 
-        query_builder.push(column_name).push(" = ").push_bind(primary_key);
-
-        Self::add_timestamp_and_pubkey_comparison(&mut query_builder, &sorting, before, after)?;
+        // Cursors are ignored if the page is requested by its number
+        let (before, after) = match pagination.page {
+            Some(_) => (None, None),
+            None => (pagination.before.as_deref(), pagination.after.as_deref()),
+        };
+        Self::add_timestamp_and_pubkey_comparison(&mut query_builder, sorting, before, after)?;
 
         let is_order_reversed = before.is_some() && after.is_none();
 
@@ -475,25 +860,25 @@ impl L2StoragePg {
             .push(", asset_pubkey ")
             .push(direction);
 
-        query_builder.push(" LIMIT ").push_bind(limit as i64);
-
-        match is_order_reversed {
-            true => query_builder
-                .build()
-                .fetch_all(&self.pool)
-                .await?
-                .into_iter()
-                .map(Self::asset_from_row)
-                .rev()
-                .collect::<Result<Vec<L2Asset>, _>>(),
-            false => query_builder
-                .build()
-                .fetch_all(&self.pool)
-                .await?
-                .into_iter()
-                .map(Self::asset_from_row)
-                .collect::<Result<Vec<L2Asset>, _>>(),
+        query_builder.push(" LIMIT ").push_bind(pagination.limit as i64);
+
+        if let Some(offset) = pagination.offset() {
+            query_builder.push(" OFFSET ").push_bind(offset as i64);
         }
+
+        let mut assets = query_builder
+            .build()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(Self::asset_from_row)
+            .collect::<Result<Vec<L2Asset>, _>>()?;
+
+        if is_order_reversed {
+            assets.reverse();
+        }
+
+        Ok(AssetPage { items: assets, total: total as u64 })
     }
 
     fn add_timestamp_and_pubkey_comparison(
@@ -543,7 +928,10 @@ impl L2StoragePg {
         order_field: &AssetSortBy,
         query_builder: &mut QueryBuilder<'_, Postgres>,
     ) -> anyhow::Result<()> {
-        let (timestamp, pubkey) = decode_timestamp_and_asset_pubkey(key)?;
+        let (cursor_sort_by, timestamp, pubkey) = decode_asset_cursor(key)?;
+        if cursor_sort_by != *order_field {
+            anyhow::bail!("Cursor was made for sorting by {cursor_sort_by:?}, not by {order_field:?}");
+        }
 
         let order_field = order_field.to_string();
         let comparison = comparison.to_string();
@@ -569,15 +957,15 @@ impl L2StoragePg {
         Ok(())
     }
 
-    fn status_from_row(row: PgRow) -> anyhow::Result<AssetMintStatus> {
-        Self::try_get_from_row::<AssetMintStatus>(&row, "current_state")
+    pub(crate) fn status_from_row(row: &PgRow) -> anyhow::Result<AssetMintStatus> {
+        Self::try_get_from_row::<AssetMintStatus>(row, "current_state")
     }
 
     fn signature_from_row(row: PgRow) -> Option<Vec<u8>> {
         Self::try_get_from_row(&row, "signature").ok()
     }
 
-    fn asset_from_row(row: PgRow) -> anyhow::Result<L2Asset> {
+    pub(crate) fn asset_from_row(row: PgRow) -> anyhow::Result<L2Asset> {
         Ok(L2Asset {
             pubkey: Self::try_get_from_row(&row, "asset_pubkey")?,
             name: Self::try_get_from_row(&row, "asset_name")?,
@@ -590,10 +978,21 @@ impl L2StoragePg {
             update_timestamp: Self::try_get_from_row(&row, "asset_last_update_timestamp")?,
             bip44_account_num: Self::try_get_from_row::<i64>(&row, "bip44_account_num")? as u32,
             bip44_address_num: Self::try_get_from_row::<i64>(&row, "bip44_address_num")? as u32,
+            burnt: matches!(Self::status_from_row(&row)?, AssetMintStatus::Burnt),
+        })
+    }
+
+    fn transfer_from_row(row: PgRow) -> anyhow::Result<L2AssetTransfer> {
+        Ok(L2AssetTransfer {
+            asset_pubkey: Self::try_get_from_row(&row, "asset_pubkey")?,
+            from: Self::try_get_from_row(&row, "from_owner")?,
+            to: Self::try_get_from_row(&row, "to_owner")?,
+            timestamp: Self::try_get_from_row(&row, "transfer_timestamp")?,
+            username: Self::try_get_from_row(&row, "api_key_username")?,
         })
     }
 
-    fn try_get_from_row<'a, T>(row: &'a PgRow, index: &str) -> anyhow::Result<T>
+    pub(crate) fn try_get_from_row<'a, T>(row: &'a PgRow, index: &str) -> anyhow::Result<T>
     where
         T: sqlx::Decode<'a, Postgres> + sqlx::Type<Postgres>,
     {
@@ -605,11 +1004,23 @@ impl L2StoragePg {
 
 #[derive(sqlx::Type)]
 #[sqlx(type_name = "asset_state", rename_all = "UPPERCASE")]
-enum AssetMintStatus {
+pub(crate) enum AssetMintStatus {
     L2,
     Minting,
     #[allow(non_camel_case_types)]
     L1_SOLANA,
+    Burnt,
+}
+
+impl From<EntityAssetMintStatus> for AssetMintStatus {
+    fn from(status: EntityAssetMintStatus) -> Self {
+        match status {
+            EntityAssetMintStatus::L2 => Self::L2,
+            EntityAssetMintStatus::Minting => Self::Minting,
+            EntityAssetMintStatus::L1_SOLANA => Self::L1_SOLANA,
+            EntityAssetMintStatus::Burnt => Self::Burnt,
+        }
+    }
 }
 
 impl Into<EntityAssetMintStatus> for AssetMintStatus {
@@ -618,6 +1029,7 @@ impl Into<EntityAssetMintStatus> for AssetMintStatus {
             Self::L2 => EntityAssetMintStatus::L2,
             Self::Minting => EntityAssetMintStatus::Minting,
             Self::L1_SOLANA => EntityAssetMintStatus::L1_SOLANA,
+            Self::Burnt => EntityAssetMintStatus::Burnt,
         }
     }
 }
@@ -642,12 +1054,46 @@ impl Bip44DerivationSequence for L2StoragePg {
 
         Ok(DerivationValues { account, address })
     }
+
+    async fn next_accounts_and_addresses(&self, count: usize) -> anyhow::Result<Vec<DerivationValues>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let rows: Vec<Bip44Row> = sqlx::query_as(
+            r#"
+            SELECT nextval('l2_bip44_sequence') as seq_val FROM generate_series(1, $1)
+        "#,
+        )
+        .bind(count as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|Bip44Row { seq_val }| {
+                let (account, address) = i64_to_u32s(seq_val);
+                DerivationValues { account, address }
+            })
+            .collect())
+    }
+}
+
+/// Escapes the wildcards of LIKE pattern, so the text is matched literally
+fn escape_like_pattern(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
 }
 
 fn i64_to_u32s(a: i64) -> (u32, u32) {
     ((a as u64 >> 32) as u32, (a & 0xffffffff) as u32)
 }
 
+#[test]
+fn test_escape_like_pattern() {
+    assert_eq!(escape_like_pattern("name"), "name");
+    assert_eq!(escape_like_pattern("100%_\\"), "100\\%\\_\\\\");
+}
+
 #[test]
 fn test_i64_to_u32s() {
     assert_eq!((0, 0), i64_to_u32s(0));
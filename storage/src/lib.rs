@@ -1,2 +1,10 @@
+pub mod api_key_storage_pg;
 pub mod asset_storage_s3;
+pub mod collection_storage_pg;
+pub mod idempotency_storage_pg;
+pub mod l1_reconciliation_storage_pg;
 pub mod l2_storage_pg;
+pub mod mint_job_storage_pg;
+pub mod quota_storage_pg;
+pub mod signature_nonce_storage_pg;
+pub mod webhook_storage_pg;
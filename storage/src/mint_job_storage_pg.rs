@@ -0,0 +1,188 @@
+use entities::l2::{MintConfirmationJob, PublicKey};
+use entities::webhook::MintCallback;
+use interfaces::mint_job_storage::MintJobStorage;
+use sqlx::{postgres::PgRow, PgExecutor, QueryBuilder};
+use std::time::Duration;
+
+use crate::l2_storage_pg::L2StoragePg;
+use crate::webhook_storage_pg::{save_mint_callback, schedule_mint_webhook};
+
+#[async_trait::async_trait]
+impl MintJobStorage for L2StoragePg {
+    async fn add_mint_job(&self, job: &MintConfirmationJob) -> anyhow::Result<()> {
+        add_mint_job(job, &self.pool).await
+    }
+
+    async fn start_mint_job(&self, job: &MintConfirmationJob, callback: Option<&MintCallback>) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        Self::add_l1_asset(&job.asset_pubkey, &job.signature, &mut tx).await?;
+        if let Some(callback) = callback {
+            save_mint_callback(&job.asset_pubkey, callback, &mut tx).await?;
+        }
+        add_mint_job(job, &mut tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn add_missing_mint_jobs(&self) -> anyhow::Result<u64> {
+        let insert_result = QueryBuilder::new(
+            r#"
+                INSERT INTO mint_confirmation_jobs
+                (
+                    asset_pubkey,
+                    signature
+                )
+                SELECT asset_pubkey, signature
+                FROM asset_minting_status
+                WHERE current_state = 'MINTING'
+                ON CONFLICT(asset_pubkey) DO NOTHING
+            "#,
+        )
+        .build()
+        .execute(&self.pool)
+        .await?;
+
+        Ok(insert_result.rows_affected())
+    }
+
+    async fn claim_due_mint_jobs(&self, limit: u32, lease: Duration) -> anyhow::Result<Vec<MintConfirmationJob>> {
+        QueryBuilder::new(
+            r#"
+                UPDATE mint_confirmation_jobs
+                SET next_attempt_timestamp = NOW() + make_interval(secs =>
+            "#,
+        )
+        .push_bind(lease.as_secs_f64())
+        .push(
+            r#"
+                )
+                WHERE asset_pubkey IN (
+                    SELECT asset_pubkey
+                    FROM mint_confirmation_jobs
+                    WHERE next_attempt_timestamp <= NOW()
+                    ORDER BY next_attempt_timestamp
+                    LIMIT
+            "#,
+        )
+        .push_bind(limit as i64)
+        .push(
+            r#"
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING
+                    asset_pubkey,
+                    signature,
+                    recent_blockhash,
+                    last_valid_block_height,
+                    attempts
+            "#,
+        )
+        .build()
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(mint_job_from_row)
+        .collect()
+    }
+
+    async fn reschedule_mint_job(&self, asset_pubkey: &PublicKey, retry_after: Duration) -> anyhow::Result<()> {
+        QueryBuilder::new(
+            r#"
+                UPDATE mint_confirmation_jobs
+                SET attempts = attempts + 1, next_attempt_timestamp = NOW() + make_interval(secs =>
+            "#,
+        )
+        .push_bind(retry_after.as_secs_f64())
+        .push(") WHERE asset_pubkey = ")
+        .push_bind(asset_pubkey)
+        .build()
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn complete_mint_job(
+        &self,
+        asset_pubkey: &PublicKey,
+        minted: bool,
+        webhook_payload: &str,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        if minted {
+            Self::finalize_mint_of_l2_asset(asset_pubkey, &mut tx).await?;
+            Self::finalize_mint_of_asset_minting_status(asset_pubkey, &mut tx).await?;
+        } else {
+            Self::cancel_l2_minting(asset_pubkey, &mut tx).await?;
+            Self::cancel_l1_minting(asset_pubkey, &mut tx).await?;
+        }
+        delete_mint_job(asset_pubkey, &mut tx).await?;
+        schedule_mint_webhook(asset_pubkey, webhook_payload, &mut tx).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+async fn add_mint_job(job: &MintConfirmationJob, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+    let mut query_builder = QueryBuilder::new(
+        r#"
+            INSERT INTO mint_confirmation_jobs
+            (
+                asset_pubkey,
+                signature,
+                recent_blockhash,
+                last_valid_block_height,
+                attempts
+            )
+        "#,
+    );
+    query_builder.push_values(std::iter::once(job), |mut builder, job| {
+        builder
+            .push_bind(job.asset_pubkey)
+            .push_bind(&job.signature)
+            .push_bind(job.recent_blockhash)
+            .push_bind(job.last_valid_block_height.map(|height| height as i64))
+            .push_bind(job.attempts as i32);
+    });
+    query_builder.push(
+        r#"
+            ON CONFLICT(asset_pubkey) DO UPDATE SET
+            signature = EXCLUDED.signature,
+            recent_blockhash = EXCLUDED.recent_blockhash,
+            last_valid_block_height = EXCLUDED.last_valid_block_height,
+            attempts = EXCLUDED.attempts,
+            next_attempt_timestamp = NOW();
+        "#,
+    );
+
+    query_builder.build().execute(executor).await?;
+
+    Ok(())
+}
+
+async fn delete_mint_job(asset_pubkey: &PublicKey, executor: impl PgExecutor<'_>) -> anyhow::Result<()> {
+    QueryBuilder::new("DELETE FROM mint_confirmation_jobs WHERE asset_pubkey = ")
+        .push_bind(asset_pubkey)
+        .build()
+        .execute(executor)
+        .await?;
+
+    Ok(())
+}
+
+fn mint_job_from_row(row: PgRow) -> anyhow::Result<MintConfirmationJob> {
+    Ok(MintConfirmationJob {
+        asset_pubkey: L2StoragePg::try_get_from_row(&row, "asset_pubkey")?,
+        signature: L2StoragePg::try_get_from_row(&row, "signature")?,
+        recent_blockhash: L2StoragePg::try_get_from_row(&row, "recent_blockhash")?,
+        last_valid_block_height: L2StoragePg::try_get_from_row::<Option<i64>>(&row, "last_valid_block_height")?
+            .map(|height| height as u64),
+        attempts: L2StoragePg::try_get_from_row::<i32>(&row, "attempts")? as u32,
+    })
+}
@@ -0,0 +1,66 @@
+use chrono::NaiveDate;
+use entities::api_key::Username;
+use interfaces::rate_limit::{QuotaOperation, QuotaStorage};
+use sqlx::QueryBuilder;
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl QuotaStorage for L2StoragePg {
+    async fn try_consume_daily_quota(
+        &self,
+        username: &Username,
+        operation: QuotaOperation,
+        date: NaiveDate,
+        count: u32,
+        limit: u32,
+    ) -> anyhow::Result<bool> {
+        if count > limit {
+            return Ok(false);
+        }
+
+        let mut query_builder =
+            QueryBuilder::new("INSERT INTO daily_quota_usage (username, operation, usage_date, usage_count) ");
+        query_builder.push_values(std::iter::once(username), |mut builder, username| {
+            builder
+                .push_bind(username.inner())
+                .push_bind(operation.as_str())
+                .push_bind(date)
+                .push_bind(count as i32);
+        });
+        // The row is not updated if the limit would be exceeded, so concurrent requests cannot exceed it
+        query_builder
+            .push(
+                r#"
+                    ON CONFLICT (username, operation, usage_date)
+                    DO UPDATE SET usage_count = daily_quota_usage.usage_count + EXCLUDED.usage_count
+                    WHERE daily_quota_usage.usage_count + EXCLUDED.usage_count <=
+                "#,
+            )
+            .push_bind(limit as i32);
+
+        Ok(query_builder.build().execute(&self.pool).await?.rows_affected() == 1)
+    }
+
+    async fn refund_daily_quota(
+        &self,
+        username: &Username,
+        operation: QuotaOperation,
+        date: NaiveDate,
+        count: u32,
+    ) -> anyhow::Result<()> {
+        QueryBuilder::new("UPDATE daily_quota_usage SET usage_count = GREATEST(usage_count - ")
+            .push_bind(count as i32)
+            .push(", 0) WHERE username = ")
+            .push_bind(username.inner())
+            .push(" AND operation = ")
+            .push_bind(operation.as_str())
+            .push(" AND usage_date = ")
+            .push_bind(date)
+            .build()
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDateTime;
+use interfaces::wallet_signature::SignatureNonceStorage;
+use sqlx::QueryBuilder;
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl SignatureNonceStorage for L2StoragePg {
+    async fn save_signature_nonce(&self, signer: &str, nonce: &str, expiry: NaiveDateTime) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        // Expired signatures are rejected anyway, so their nonces are not needed anymore
+        QueryBuilder::new("DELETE FROM wallet_signature_nonces WHERE expiry_timestamp < NOW() AND signer = ")
+            .push_bind(signer)
+            .build()
+            .execute(&mut tx)
+            .await?;
+
+        let mut query_builder =
+            QueryBuilder::new("INSERT INTO wallet_signature_nonces (signer, nonce, expiry_timestamp) ");
+        query_builder.push_values(std::iter::once(nonce), |mut builder, nonce| {
+            builder.push_bind(signer).push_bind(nonce).push_bind(expiry);
+        });
+        query_builder.push(" ON CONFLICT (signer, nonce) DO NOTHING");
+        let saved = query_builder.build().execute(&mut tx).await?.rows_affected() == 1;
+
+        tx.commit().await?;
+
+        Ok(saved)
+    }
+}
@@ -0,0 +1,189 @@
+use entities::l2::PublicKey;
+use entities::webhook::{MintCallback, WebhookDelivery};
+use interfaces::webhook_storage::WebhookStorage;
+use sqlx::{postgres::PgRow, PgExecutor, QueryBuilder};
+use std::time::Duration;
+
+use crate::l2_storage_pg::L2StoragePg;
+
+#[async_trait::async_trait]
+impl WebhookStorage for L2StoragePg {
+    async fn save_mint_callback(&self, asset_pubkey: &PublicKey, callback: &MintCallback) -> anyhow::Result<()> {
+        save_mint_callback(asset_pubkey, callback, &self.pool).await
+    }
+
+    async fn schedule_mint_webhook(&self, asset_pubkey: &PublicKey, payload: &str) -> anyhow::Result<()> {
+        schedule_mint_webhook(asset_pubkey, payload, &self.pool).await
+    }
+
+    async fn claim_due_webhooks(&self, limit: u32, lease: Duration) -> anyhow::Result<Vec<WebhookDelivery>> {
+        QueryBuilder::new(
+            r#"
+                UPDATE webhook_deliveries
+                SET next_attempt_timestamp = NOW() + make_interval(secs =>
+            "#,
+        )
+        .push_bind(lease.as_secs_f64())
+        .push(
+            r#"
+                )
+                WHERE webhook_id IN (
+                    SELECT webhook_id
+                    FROM webhook_deliveries
+                    WHERE next_attempt_timestamp <= NOW()
+                    ORDER BY next_attempt_timestamp
+                    LIMIT
+            "#,
+        )
+        .push_bind(limit as i64)
+        .push(
+            r#"
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING
+                    webhook_id,
+                    callback_url,
+                    payload,
+                    api_key_username,
+                    (SELECT key_hash FROM api_keys WHERE api_keys.api_key_id = webhook_deliveries.api_key_id)
+                        AS api_key_hash,
+                    attempts
+            "#,
+        )
+        .build()
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(webhook_from_row)
+        .collect()
+    }
+
+    async fn mark_webhook_delivered(&self, webhook_id: i64) -> anyhow::Result<()> {
+        QueryBuilder::new(
+            r#"
+                UPDATE webhook_deliveries
+                SET delivered_timestamp = NOW(), next_attempt_timestamp = NULL, attempts = attempts + 1
+                WHERE webhook_id =
+            "#,
+        )
+        .push_bind(webhook_id)
+        .build()
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_webhook_failed(
+        &self,
+        webhook_id: i64,
+        error: &str,
+        retry_after: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let mut query_builder =
+            QueryBuilder::new("UPDATE webhook_deliveries SET attempts = attempts + 1, last_error = ");
+        query_builder.push_bind(error);
+
+        match retry_after {
+            Some(retry_after) => query_builder
+                .push(", next_attempt_timestamp = NOW() + make_interval(secs => ")
+                .push_bind(retry_after.as_secs_f64())
+                .push(")"),
+            None => query_builder.push(", next_attempt_timestamp = NULL"),
+        };
+
+        query_builder
+            .push(" WHERE webhook_id = ")
+            .push_bind(webhook_id)
+            .build()
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn save_mint_callback(
+    asset_pubkey: &PublicKey,
+    callback: &MintCallback,
+    executor: impl PgExecutor<'_>,
+) -> anyhow::Result<()> {
+    let mut query_builder = QueryBuilder::new(
+        r#"
+            INSERT INTO mint_callbacks
+            (
+                asset_pubkey,
+                callback_url,
+                api_key_username,
+                api_key_id
+            )
+        "#,
+    );
+    query_builder.push_values(std::iter::once(callback), |mut builder, callback| {
+        builder
+            .push_bind(asset_pubkey)
+            .push_bind(&callback.url)
+            .push_bind(&callback.username)
+            .push_bind(callback.api_key_id);
+    });
+    query_builder.push(
+        r#"
+            ON CONFLICT(asset_pubkey) DO UPDATE SET
+            callback_url = EXCLUDED.callback_url,
+            api_key_username = EXCLUDED.api_key_username,
+            api_key_id = EXCLUDED.api_key_id;
+        "#,
+    );
+
+    query_builder.build().execute(executor).await?;
+
+    Ok(())
+}
+
+pub(crate) async fn schedule_mint_webhook(
+    asset_pubkey: &PublicKey,
+    payload: &str,
+    executor: impl PgExecutor<'_>,
+) -> anyhow::Result<()> {
+    QueryBuilder::new(
+        r#"
+            WITH callback AS (
+                DELETE FROM mint_callbacks
+                WHERE asset_pubkey =
+        "#,
+    )
+    .push_bind(asset_pubkey)
+    .push(
+        r#"
+                RETURNING callback_url, api_key_username, api_key_id
+            )
+            INSERT INTO webhook_deliveries
+            (
+                callback_url,
+                payload,
+                api_key_username,
+                api_key_id,
+                next_attempt_timestamp
+            )
+            SELECT callback_url,
+        "#,
+    )
+    .push_bind(payload)
+    .push(", api_key_username, api_key_id, NOW() FROM callback")
+    .build()
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+fn webhook_from_row(row: PgRow) -> anyhow::Result<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        webhook_id: L2StoragePg::try_get_from_row(&row, "webhook_id")?,
+        url: L2StoragePg::try_get_from_row(&row, "callback_url")?,
+        payload: L2StoragePg::try_get_from_row(&row, "payload")?,
+        username: L2StoragePg::try_get_from_row(&row, "api_key_username")?,
+        api_key_hash: L2StoragePg::try_get_from_row(&row, "api_key_hash")?,
+        attempts: L2StoragePg::try_get_from_row::<i32>(&row, "attempts")? as u32,
+    })
+}
@@ -1,10 +1,22 @@
 use chrono::NaiveDateTime;
-use entities::l2::L2Asset;
+use entities::api_key::{ApiKeyScope, Username};
+use entities::dto::AssetMintStatus;
+use entities::l1_reconciliation::{L1Drift, L1DriftKind};
+use entities::l2::{L2Asset, L2Collection, MintConfirmationJob};
+use entities::webhook::MintCallback;
+use interfaces::api_key_storage::ApiKeyStorage;
+use interfaces::collection_storage::CollectionStorage;
+use interfaces::idempotency::{IdempotencyKeyReservation, IdempotencyStorage, IdempotentResponse};
+use interfaces::l1_reconciliation_storage::L1ReconciliationStorage;
 use interfaces::l2_storage::Bip44DerivationSequence;
 use interfaces::l2_storage::DerivationValues;
 use interfaces::l2_storage::L2Storage;
+use interfaces::mint_job_storage::MintJobStorage;
+use interfaces::rate_limit::{QuotaOperation, QuotaStorage};
+use interfaces::webhook_storage::WebhookStorage;
 use setup::data_gen::rand_pubkey_str;
 use setup::{data_gen::rand_pubkey, TestEnvironment};
+use std::time::Duration;
 use storage::l2_storage_pg::L2StoragePg;
 
 #[tokio::test]
@@ -27,6 +39,7 @@ async fn test_save_fetch() {
         update_timestamp: NaiveDateTime::default(),
         bip44_account_num: 1,
         bip44_address_num: 1,
+        burnt: false,
     };
 
     storage.save(&asset).await.unwrap();
@@ -36,6 +49,495 @@ async fn test_save_fetch() {
     assert_eq!(fetched.unwrap(), asset);
 }
 
+#[tokio::test]
+async fn test_update() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+    storage.save(&asset).await.unwrap();
+
+    let updated = L2Asset { name: "name2".to_string(), authority: rand_pubkey_str(), ..asset.clone() };
+
+    // the asset is updated only if it still has the expected authority
+    assert!(!storage.update(&updated, &rand_pubkey_str()).await.unwrap());
+    assert_eq!(storage.find(&asset.pubkey).await.unwrap().unwrap(), asset);
+
+    assert!(storage.update(&updated, &asset.authority).await.unwrap());
+    assert_eq!(storage.find(&asset.pubkey).await.unwrap().unwrap(), updated);
+    assert!(!storage.update(&updated, &asset.authority).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_save_batch() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let assets = (0..3)
+        .map(|i| L2Asset {
+            pubkey: rand_pubkey(),
+            name: format!("name{i}"),
+            owner: rand_pubkey_str(),
+            creator: rand_pubkey_str(),
+            collection: None,
+            authority: rand_pubkey_str(),
+            royalty_basis_points: i,
+            create_timestamp: NaiveDateTime::default(),
+            update_timestamp: NaiveDateTime::default(),
+            bip44_account_num: 0,
+            bip44_address_num: i as u32,
+            burnt: false,
+        })
+        .collect::<Vec<_>>();
+
+    storage.save_batch(&assets).await.unwrap();
+    storage.save_batch(&[]).await.unwrap();
+
+    for asset in &assets {
+        assert_eq!(storage.find(&asset.pubkey).await.unwrap().as_ref(), Some(asset));
+    }
+
+    // already existing asset fails the whole batch
+    let new_asset = L2Asset { pubkey: rand_pubkey(), ..assets[0].clone() };
+    assert!(storage
+        .save_batch(&[new_asset.clone(), assets[1].clone()])
+        .await
+        .is_err());
+    assert!(storage.find(&new_asset.pubkey).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_burn() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+
+    storage.save(&asset).await.unwrap();
+
+    assert_eq!(storage.burn(&asset.pubkey).await.unwrap(), Some(AssetMintStatus::L2));
+    assert!(storage.find(&asset.pubkey).await.unwrap().unwrap().burnt);
+
+    // burnt asset can be neither burnt again nor minted
+    assert_eq!(storage.burn(&asset.pubkey).await.unwrap(), Some(AssetMintStatus::Burnt));
+    assert!(!storage.lock_asset_before_minting(&asset.pubkey).await.unwrap());
+
+    assert_eq!(storage.burn(&rand_pubkey()).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_transfer() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let owner_1 = rand_pubkey_str();
+    let owner_2 = rand_pubkey_str();
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: owner_1.clone(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+
+    storage.save(&asset).await.unwrap();
+
+    let transferred = storage
+        .transfer(&asset.pubkey, &owner_1, &owner_2, "user1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(transferred.owner, owner_2);
+    assert_eq!(storage.find(&asset.pubkey).await.unwrap().unwrap().owner, owner_2);
+
+    // the owner has already been changed, so the same transfer is refused
+    assert!(storage
+        .transfer(&asset.pubkey, &owner_1, &owner_2, "user1")
+        .await
+        .unwrap()
+        .is_none());
+
+    storage
+        .transfer(&asset.pubkey, &owner_2, &owner_1, "user2")
+        .await
+        .unwrap()
+        .unwrap();
+
+    let transfers = storage.find_transfers(&asset.pubkey).await.unwrap();
+    assert_eq!(
+        transfers
+            .iter()
+            .map(|t| (t.from.as_str(), t.to.as_str(), t.username.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (owner_1.as_str(), owner_2.as_str(), "user1"),
+            (owner_2.as_str(), owner_1.as_str(), "user2")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_save_fetch_collection() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let mut collection = L2Collection {
+        pubkey: rand_pubkey(),
+        name: "collection".to_string(),
+        authority: rand_pubkey_str(),
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+    };
+
+    storage.save_collection(&collection).await.unwrap();
+    assert_eq!(storage.find_collection(&collection.pubkey).await.unwrap().unwrap(), collection);
+
+    collection.name = "collection2".to_string();
+    storage.save_collection(&collection).await.unwrap();
+    assert_eq!(
+        storage
+            .find_collections_batch(&[collection.pubkey, rand_pubkey()])
+            .await
+            .unwrap(),
+        vec![collection]
+    );
+}
+
+#[tokio::test]
+async fn test_mint_webhooks() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let asset_pubkey = rand_pubkey();
+    let callback = MintCallback {
+        url: "http://localhost/callback".to_string(),
+        username: "name1".to_string(),
+        api_key_id: None,
+    };
+    storage.save_mint_callback(&asset_pubkey, &callback).await.unwrap();
+
+    // no callback registered for the asset
+    storage.schedule_mint_webhook(&rand_pubkey(), "{}").await.unwrap();
+
+    storage
+        .schedule_mint_webhook(&asset_pubkey, r#"{"status":"l1_solana"}"#)
+        .await
+        .unwrap();
+    // callback is notified only once
+    storage
+        .schedule_mint_webhook(&asset_pubkey, r#"{"status":"l2"}"#)
+        .await
+        .unwrap();
+
+    let webhooks = storage.claim_due_webhooks(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].url, callback.url);
+    assert_eq!(webhooks[0].username, callback.username);
+    assert_eq!(webhooks[0].payload, r#"{"status":"l1_solana"}"#);
+    assert_eq!(webhooks[0].attempts, 0);
+
+    // claimed webhook is not due until the lease expires
+    assert!(storage
+        .claim_due_webhooks(10, Duration::from_secs(60))
+        .await
+        .unwrap()
+        .is_empty());
+
+    storage
+        .mark_webhook_failed(webhooks[0].webhook_id, "connection refused", Some(Duration::ZERO))
+        .await
+        .unwrap();
+    let webhooks = storage.claim_due_webhooks(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].attempts, 1);
+
+    storage.mark_webhook_delivered(webhooks[0].webhook_id).await.unwrap();
+    assert!(storage.claim_due_webhooks(10, Duration::ZERO).await.unwrap().is_empty());
+    assert_eq!(webhooks[0].api_key_hash, None);
+
+    // webhook of the issued API key carries the key hash its secret is derived from
+    let api_key = storage
+        .save_api_key("hash1", &Username::new("name1"), "label1", &ApiKeyScope::ALL, false, None)
+        .await
+        .unwrap();
+    let callback = MintCallback { api_key_id: Some(api_key.id), ..callback };
+    storage.save_mint_callback(&asset_pubkey, &callback).await.unwrap();
+    storage.schedule_mint_webhook(&asset_pubkey, "{}").await.unwrap();
+
+    let webhooks = storage.claim_due_webhooks(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].api_key_hash.as_deref(), Some("hash1"));
+}
+
+#[tokio::test]
+async fn test_mint_confirmation_jobs() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+    let signature = vec![1u8; 64];
+
+    storage.save(&asset).await.unwrap();
+    assert!(storage.lock_asset_before_minting(&asset.pubkey).await.unwrap());
+    storage.add_l1_asset(&asset.pubkey, &signature).await.unwrap();
+
+    // mint sent before the jobs were introduced
+    assert_eq!(storage.add_missing_mint_jobs().await.unwrap(), 1);
+    assert_eq!(storage.add_missing_mint_jobs().await.unwrap(), 0);
+
+    let job = MintConfirmationJob {
+        asset_pubkey: asset.pubkey,
+        signature: signature.clone(),
+        recent_blockhash: Some([2u8; 32]),
+        last_valid_block_height: Some(1000),
+        attempts: 0,
+    };
+    storage.add_mint_job(&job).await.unwrap();
+
+    assert_eq!(storage.claim_due_mint_jobs(10, Duration::from_secs(60)).await.unwrap(), vec![job.clone()]);
+    // claimed job is processed by a single worker
+    assert!(storage
+        .claim_due_mint_jobs(10, Duration::from_secs(60))
+        .await
+        .unwrap()
+        .is_empty());
+
+    storage
+        .reschedule_mint_job(&asset.pubkey, Duration::ZERO)
+        .await
+        .unwrap();
+    let jobs = storage.claim_due_mint_jobs(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].attempts, 1);
+
+    let callback = MintCallback {
+        url: "http://localhost/callback".to_string(),
+        username: "name1".to_string(),
+        api_key_id: None,
+    };
+    storage.save_mint_callback(&asset.pubkey, &callback).await.unwrap();
+
+    storage
+        .complete_mint_job(&asset.pubkey, true, r#"{"status":"l1_solana"}"#)
+        .await
+        .unwrap();
+
+    let (status, _) = storage.get_mint_status_and_signature(&asset.pubkey).await.unwrap();
+    assert_eq!(status, AssetMintStatus::L1_SOLANA);
+    assert_eq!(storage.find_minted(&[asset.pubkey, rand_pubkey()]).await.unwrap(), vec![asset.pubkey]);
+    assert!(storage
+        .claim_due_mint_jobs(10, Duration::ZERO)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let webhooks = storage.claim_due_webhooks(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].payload, r#"{"status":"l1_solana"}"#);
+}
+
+#[tokio::test]
+async fn test_start_mint_job() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+    storage.save(&asset).await.unwrap();
+    assert!(storage.lock_asset_before_minting(&asset.pubkey).await.unwrap());
+
+    let job = MintConfirmationJob {
+        asset_pubkey: asset.pubkey,
+        signature: vec![1u8; 64],
+        recent_blockhash: Some([2u8; 32]),
+        last_valid_block_height: Some(1000),
+        attempts: 0,
+    };
+    let callback = MintCallback {
+        url: "http://host/callback".to_string(),
+        username: "name1".to_string(),
+        api_key_id: None,
+    };
+    storage.start_mint_job(&job, Some(&callback)).await.unwrap();
+
+    let (status, signature) = storage.get_mint_status_and_signature(&asset.pubkey).await.unwrap();
+    assert_eq!(status, AssetMintStatus::Minting);
+    assert_eq!(signature, Some(job.signature.clone()));
+    assert_eq!(storage.claim_due_mint_jobs(10, Duration::from_secs(60)).await.unwrap(), vec![job]);
+
+    storage
+        .complete_mint_job(&asset.pubkey, false, r#"{"status":"l2"}"#)
+        .await
+        .unwrap();
+
+    let webhooks = storage.claim_due_webhooks(10, Duration::from_secs(60)).await.unwrap();
+    assert_eq!(webhooks.len(), 1);
+    assert_eq!(webhooks[0].url, callback.url);
+}
+
+#[tokio::test]
+async fn test_l1_reconciliation() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let make_asset = |pubkey| L2Asset {
+        pubkey,
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: rand_pubkey_str(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+    let l2_asset = make_asset([1u8; 32]);
+    let minted_asset = make_asset([2u8; 32]);
+    let burnt_asset = make_asset([3u8; 32]);
+
+    for asset in [&l2_asset, &minted_asset, &burnt_asset] {
+        storage.save(asset).await.unwrap();
+    }
+    assert!(storage.lock_asset_before_minting(&minted_asset.pubkey).await.unwrap());
+    storage.add_l1_asset(&minted_asset.pubkey, &[1u8; 64]).await.unwrap();
+    storage
+        .complete_mint_job(&minted_asset.pubkey, true, "{}")
+        .await
+        .unwrap();
+    storage.burn(&burnt_asset.pubkey).await.unwrap();
+
+    // minted assets are reconciled too, burnt ones are not
+    let assets = storage.find_assets_to_reconcile(None, 10).await.unwrap();
+    assert_eq!(
+        assets,
+        vec![
+            (l2_asset.clone(), AssetMintStatus::L2),
+            (minted_asset.clone(), AssetMintStatus::L1_SOLANA)
+        ]
+    );
+    let assets = storage
+        .find_assets_to_reconcile(Some(l2_asset.pubkey), 10)
+        .await
+        .unwrap();
+    assert_eq!(assets, vec![(minted_asset.clone(), AssetMintStatus::L1_SOLANA)]);
+
+    let drifts = vec![
+        L1Drift {
+            asset_pubkey: l2_asset.pubkey,
+            kind: L1DriftKind::MintedButL2,
+            db_value: None,
+            chain_value: None,
+        },
+        L1Drift {
+            asset_pubkey: minted_asset.pubkey,
+            kind: L1DriftKind::NameMismatch,
+            db_value: Some("name".to_string()),
+            chain_value: Some("name2".to_string()),
+        },
+    ];
+    storage
+        .save_l1_drifts(&[l2_asset.pubkey, minted_asset.pubkey], &drifts)
+        .await
+        .unwrap();
+    assert_eq!(storage.find_l1_drifts(&l2_asset.pubkey).await.unwrap(), vec![drifts[0].clone()]);
+    assert_eq!(storage.find_l1_drifts(&minted_asset.pubkey).await.unwrap(), vec![drifts[1].clone()]);
+
+    // drifts of the checked assets are replaced
+    storage.save_l1_drifts(&[minted_asset.pubkey], &[]).await.unwrap();
+    assert_eq!(storage.find_l1_drifts(&l2_asset.pubkey).await.unwrap(), vec![drifts[0].clone()]);
+    assert!(storage.find_l1_drifts(&minted_asset.pubkey).await.unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_bip44_sequences() {
     let test_env = TestEnvironment::builder().with_pg().start().await;
@@ -47,4 +549,267 @@ async fn test_bip44_sequences() {
     assert_eq!(sut.next_account_and_address().await.unwrap(), DerivationValues { account: 0, address: 1 });
     assert_eq!(sut.next_account_and_address().await.unwrap(), DerivationValues { account: 0, address: 2 });
     assert_eq!(sut.next_account_and_address().await.unwrap(), DerivationValues { account: 0, address: 3 });
+
+    assert_eq!(
+        sut.next_accounts_and_addresses(2).await.unwrap(),
+        vec![
+            DerivationValues { account: 0, address: 4 },
+            DerivationValues { account: 0, address: 5 }
+        ]
+    );
+    assert!(sut.next_accounts_and_addresses(0).await.unwrap().is_empty());
+    assert_eq!(sut.next_account_and_address().await.unwrap(), DerivationValues { account: 0, address: 6 });
+}
+
+#[tokio::test]
+async fn test_api_keys() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let username = Username::new("name1");
+    let expiry = NaiveDateTime::parse_from_str("2099-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+    let saved = storage
+        .save_api_key("hash1", &username, "label1", &[ApiKeyScope::AssetsCreate], true, Some(expiry))
+        .await
+        .unwrap();
+    assert_eq!(saved.username, username);
+    assert_eq!(saved.label, "label1");
+    assert_eq!(saved.scopes, vec![ApiKeyScope::AssetsCreate]);
+    assert!(saved.restricted);
+    assert_eq!(saved.expiry_timestamp, Some(expiry));
+    assert!(saved.revocation_timestamp.is_none());
+
+    // hashes are unique
+    assert!(storage
+        .save_api_key("hash1", &username, "label2", &ApiKeyScope::ALL, false, None)
+        .await
+        .is_err());
+    let other = storage
+        .save_api_key("hash2", &username, "label2", &ApiKeyScope::ALL, false, None)
+        .await
+        .unwrap();
+    assert_eq!(other.scopes, ApiKeyScope::ALL.to_vec());
+
+    assert_eq!(storage.find_api_key("hash1").await.unwrap(), Some(saved.clone()));
+    assert!(storage.find_api_key("unknown").await.unwrap().is_none());
+    assert_eq!(storage.list_api_keys().await.unwrap().len(), 2);
+
+    let revoked = storage.revoke_api_key(saved.id).await.unwrap().unwrap();
+    assert!(revoked.revocation_timestamp.is_some());
+    assert!(!revoked.is_active(chrono::Utc::now().naive_utc()));
+    assert_eq!(storage.find_api_key("hash1").await.unwrap(), Some(revoked.clone()));
+
+    // revoking again keeps the original revocation timestamp
+    assert_eq!(storage.revoke_api_key(saved.id).await.unwrap(), Some(revoked));
+    assert!(storage.revoke_api_key(-1).await.unwrap().is_none());
+
+    // every authority belongs to a single key
+    assert!(storage.claim_authority(saved.id, "authority1").await.unwrap());
+    assert!(storage.claim_authority(saved.id, "authority1").await.unwrap());
+    assert!(!storage.claim_authority(other.id, "authority1").await.unwrap());
+    assert_eq!(storage.find_authority_owner("authority1").await.unwrap(), Some(saved.id));
+    assert!(storage.find_authority_owner("authority2").await.unwrap().is_none());
+
+    // authority of the existing assets cannot be claimed
+    let asset = L2Asset {
+        pubkey: rand_pubkey(),
+        name: "name".to_string(),
+        owner: rand_pubkey_str(),
+        creator: rand_pubkey_str(),
+        collection: None,
+        authority: "authority2".to_string(),
+        royalty_basis_points: 0,
+        create_timestamp: NaiveDateTime::default(),
+        update_timestamp: NaiveDateTime::default(),
+        bip44_account_num: 1,
+        bip44_address_num: 1,
+        burnt: false,
+    };
+    storage.save(&asset).await.unwrap();
+    assert!(!storage.claim_authority(saved.id, "authority2").await.unwrap());
+    assert!(storage.find_authority_owner("authority2").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_daily_quota() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let username = Username::new("name1");
+    let day1 = chrono::NaiveDate::from_ymd_opt(2024, 11, 17).unwrap();
+    let day2 = day1.succ_opt().unwrap();
+
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::Mint, day1, 1, 2)
+        .await
+        .unwrap());
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::Mint, day1, 1, 2)
+        .await
+        .unwrap());
+    assert!(!storage
+        .try_consume_daily_quota(&username, QuotaOperation::Mint, day1, 1, 2)
+        .await
+        .unwrap());
+
+    // quota is counted per operation, user and day
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day1, 1, 2)
+        .await
+        .unwrap());
+    assert!(storage
+        .try_consume_daily_quota(&Username::new("name2"), QuotaOperation::Mint, day1, 1, 2)
+        .await
+        .unwrap());
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::Mint, day2, 1, 2)
+        .await
+        .unwrap());
+
+    assert!(!storage
+        .try_consume_daily_quota(&username, QuotaOperation::Mint, day2, 1, 0)
+        .await
+        .unwrap());
+
+    // several operations are counted at once, only if all of them fit into the quota
+    assert!(!storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 3, 2)
+        .await
+        .unwrap());
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 2, 3)
+        .await
+        .unwrap());
+    assert!(!storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 2, 3)
+        .await
+        .unwrap());
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 1, 3)
+        .await
+        .unwrap());
+
+    // refunded operations free the quota, the usage doesn't go below zero
+    storage
+        .refund_daily_quota(&username, QuotaOperation::AssetCreation, day2, 2)
+        .await
+        .unwrap();
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 2, 3)
+        .await
+        .unwrap());
+    storage
+        .refund_daily_quota(&username, QuotaOperation::AssetCreation, day2, 10)
+        .await
+        .unwrap();
+    assert!(storage
+        .try_consume_daily_quota(&username, QuotaOperation::AssetCreation, day2, 3, 3)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_idempotency_keys() {
+    let test_env = TestEnvironment::builder().with_pg().start().await;
+
+    let db_config = test_env.database_cfg().await;
+
+    let storage = L2StoragePg::new_from_cfg(&db_config).await.unwrap();
+
+    let username = Username::new("name1");
+    let expired_before = NaiveDateTime::parse_from_str("2000-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    let response = IdempotentResponse {
+        status: 201,
+        content_type: Some("application/json".to_string()),
+        body: b"{}".to_vec(),
+    };
+
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key1", "hash1", expired_before, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Reserved
+    );
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key1", "hash2", expired_before, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Existing { request_hash: "hash1".to_string(), response: None }
+    );
+
+    storage
+        .save_idempotent_response(&username, "key1", &response)
+        .await
+        .unwrap();
+    // completed key is not released
+    storage.release_idempotency_key(&username, "key1").await.unwrap();
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key1", "hash1", expired_before, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Existing { request_hash: "hash1".to_string(), response: Some(response.clone()) }
+    );
+
+    // keys are scoped by the user
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&Username::new("name2"), "key1", "hash1", expired_before, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Reserved
+    );
+
+    // released key can be reserved again
+    storage
+        .reserve_idempotency_key(&username, "key2", "hash1", expired_before, expired_before)
+        .await
+        .unwrap();
+    storage.release_idempotency_key(&username, "key2").await.unwrap();
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key2", "hash2", expired_before, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Reserved
+    );
+
+    // expired keys are dropped
+    let now = chrono::Utc::now().naive_utc() + chrono::Duration::minutes(1);
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key1", "hash2", now, expired_before)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Reserved
+    );
+
+    // abandoned reservations are dropped, unlike the completed keys
+    storage
+        .save_idempotent_response(&username, "key1", &response)
+        .await
+        .unwrap();
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key1", "hash2", expired_before, now)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Existing { request_hash: "hash2".to_string(), response: Some(response) }
+    );
+    assert_eq!(
+        storage
+            .reserve_idempotency_key(&username, "key2", "hash1", expired_before, now)
+            .await
+            .unwrap(),
+        IdempotencyKeyReservation::Reserved
+    );
 }
@@ -6,7 +6,10 @@ use pg::PgContainer;
 use s3::S3Container;
 use std::net::Ipv4Addr;
 use test_validator_runner::{SolanaProcess, TestValidatorRunner};
-use util::config::{DatabaseCfg, JsonRpc, ObjStorageCfg, RestServerCfg, SecretCfg, SecretsCfg, Settings, SolanaCfg};
+use util::config::{
+    DasFallbackCfg, DatabaseCfg, JsonRpc, L1ReconciliationCfg, MintConfirmationCfg, ObjStorageCfg, RateLimitsCfg,
+    RestServerCfg, SecretCfg, SecretsCfg, Settings, SolanaCfg, WebhookCfg,
+};
 
 pub mod data_gen;
 pub mod pg;
@@ -113,16 +116,36 @@ impl TestEnvironment {
                 host: Ipv4Addr::LOCALHOST,
                 log_level: "DEBUG".to_string(),
                 base_url: "http://localhost".to_string(),
+                api_key_cache_ttl_secs: 60,
+                require_wallet_signatures: false,
+                wallet_signature_max_ttl_secs: 300,
+                idempotency_key_ttl_secs: 86400,
+                idempotency_reservation_lease_secs: 600,
+                max_asset_batch_size: 100,
             },
             database: self.database_cfg().await,
             obj_storage: self.obj_storage_cfg().await,
             env: "it".to_string(),
-            json_rpc_server: JsonRpc { port: 8081, host: Ipv4Addr::LOCALHOST, log_level: "DEBUG".to_string() },
+            json_rpc_server: JsonRpc {
+                port: 8081,
+                host: Ipv4Addr::LOCALHOST,
+                log_level: "DEBUG".to_string(),
+                max_batch_size: 100,
+                max_batch_concurrency: 10,
+            },
             solana,
             secrets: SecretsCfg {
                 master_mnemonic: SecretCfg::Plain("".to_string()),
                 rest_api_keys: SecretCfg::Plain("111:name1;222:name2;333:name3".to_string()),
+                admin_api_keys: Some(SecretCfg::Plain("999:admin".to_string())),
+                webhook_secrets: Some(SecretCfg::Plain("name1:secret1;name2:secret2;name3:secret3".to_string())),
+                webhook_secret_key: Some(SecretCfg::Plain("webhook-secret-key".to_string())),
             },
+            webhooks: WebhookCfg::default(),
+            mint_confirmation: MintConfirmationCfg::default(),
+            l1_reconciliation: L1ReconciliationCfg { enabled: false, ..Default::default() },
+            das_fallback: DasFallbackCfg::default(),
+            rate_limits: RateLimitsCfg { enabled: false, ..Default::default() },
         }
     }
 }
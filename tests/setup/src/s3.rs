@@ -73,5 +73,7 @@ pub async fn obj_storage_cfg(node: &ContainerAsync<LocalStack>) -> ObjStorageCfg
         session_token: None,
         bucket_for_json_metadata: BUCKET.to_string(),
         bucket_for_binary_assets: BUCKET.to_string(),
+        max_binary_asset_size: 1024 * 1024,
+        max_json_metadata_size: 64 * 1024,
     }
 }
@@ -0,0 +1,32 @@
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+const API_KEY_BYTES: usize = 32;
+
+/// Generates a new random API key, base58 encoded.
+pub fn generate_api_key() -> String {
+    let mut bytes = [0u8; API_KEY_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    bs58::encode(bytes).into_string()
+}
+
+/// Hex encoded SHA-256 of the API key, that is stored instead of the key itself.
+/// The keys are random, so a plain hash is enough to make the stored value useless for an attacker.
+pub fn hash_api_key(api_key: &str) -> String {
+    hex::encode(Sha256::digest(api_key.as_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash_api_key() {
+        assert_eq!(hash_api_key("111"), "f6e0a1e2ac41945a9aa7ff8a8aaa0cebc12a3bcc981a929ad5cf810a090e11ae");
+
+        let api_key = generate_api_key();
+        assert_ne!(api_key, generate_api_key());
+        assert_eq!(hash_api_key(&api_key), hash_api_key(&api_key));
+        assert_ne!(hash_api_key(&api_key), api_key);
+    }
+}
@@ -3,38 +3,55 @@ use base64::engine::general_purpose::STANDARD_NO_PAD;
 use base64::engine::GeneralPurpose;
 use base64::Engine;
 use chrono::{DateTime, NaiveDateTime};
-use entities::l2::PublicKey;
+use entities::l2::{AssetSortBy, PublicKey};
 use std::mem::size_of;
 
 const BASE64_ENGINE: GeneralPurpose = STANDARD_NO_PAD;
 
-pub fn decode_timestamp_and_asset_pubkey(encoded_key: &str) -> anyhow::Result<(NaiveDateTime, PublicKey)> {
-    let key = BASE64_ENGINE
-        .decode(encoded_key)
-        .context("Failed to decode base64 string")?;
+const SORT_KEY_SIZE: usize = size_of::<u8>();
+const TIMESTAMP_SIZE: usize = size_of::<i64>();
+const CURSOR_SIZE: usize = SORT_KEY_SIZE + TIMESTAMP_SIZE + size_of::<PublicKey>();
 
-    if key.len() < 8 {
-        bail!("Invalid key: Not enough data for a timestamp (requires at least 8 bytes)");
+/// Decodes the cursor made by [`encode_asset_cursor`].
+/// Returns the sort key the cursor was made for, together with the timestamp and the pubkey of the asset.
+pub fn decode_asset_cursor(cursor: &str) -> anyhow::Result<(AssetSortBy, NaiveDateTime, PublicKey)> {
+    let key = BASE64_ENGINE.decode(cursor).context("Failed to decode base64 string")?;
+
+    if key.len() != CURSOR_SIZE {
+        bail!("Invalid cursor: Expected {CURSOR_SIZE} bytes, got {}", key.len());
     }
 
-    let timestamp_millis = i64::from_be_bytes(key[0..8].try_into()?);
+    let sort_by = match key[0] {
+        0 => AssetSortBy::Created,
+        1 => AssetSortBy::Updated,
+        sort_key => bail!("Invalid cursor: Unknown sort key {sort_key}"),
+    };
+
+    let timestamp_micros = i64::from_be_bytes(key[SORT_KEY_SIZE..SORT_KEY_SIZE + TIMESTAMP_SIZE].try_into()?);
 
-    let timestamp = DateTime::from_timestamp_millis(timestamp_millis)
+    let timestamp = DateTime::from_timestamp_micros(timestamp_micros)
         .context("Invalid timestamp: Could not parse into NaiveDateTime")?
         .naive_utc();
 
-    let pubkey = PublicKey::try_from(&key[8..]).context("Failed to parse public key from remaining bytes")?;
+    let pubkey = PublicKey::try_from(&key[SORT_KEY_SIZE + TIMESTAMP_SIZE..])
+        .context("Failed to parse public key from remaining bytes")?;
 
-    Ok((timestamp, pubkey))
+    Ok((sort_by, timestamp, pubkey))
 }
 
-pub fn encode_timestamp_and_asset_pubkey(date: NaiveDateTime, pubkey: PublicKey) -> String {
-    let timestamp_size = size_of::<i64>();
-    let pubkey_size = size_of::<PublicKey>();
+/// Encodes the position of the asset in the list sorted by `sort_by`,
+/// where `timestamp` is the asset timestamp matching the sort key.
+pub fn encode_asset_cursor(sort_by: &AssetSortBy, timestamp: NaiveDateTime, pubkey: PublicKey) -> String {
+    let sort_key: u8 = match sort_by {
+        AssetSortBy::Created => 0,
+        AssetSortBy::Updated => 1,
+    };
 
-    let mut vec = Vec::with_capacity(timestamp_size + pubkey_size);
+    let mut vec = Vec::with_capacity(CURSOR_SIZE);
 
-    vec.extend_from_slice(&date.and_utc().timestamp_millis().to_be_bytes());
+    vec.push(sort_key);
+    // Postgres stores timestamps with microsecond precision, so the cursor should keep all of it
+    vec.extend_from_slice(&timestamp.and_utc().timestamp_micros().to_be_bytes());
     vec.extend_from_slice(&pubkey);
 
     BASE64_ENGINE.encode(vec)
@@ -42,19 +59,38 @@ pub fn encode_timestamp_and_asset_pubkey(date: NaiveDateTime, pubkey: PublicKey)
 
 #[cfg(test)]
 mod test {
-    use crate::base64_encode_decode::{decode_timestamp_and_asset_pubkey, encode_timestamp_and_asset_pubkey};
+    use crate::base64_encode_decode::{decode_asset_cursor, encode_asset_cursor};
     use crate::publickey::PublicKeyExt;
-    use chrono::NaiveDateTime;
-    use entities::l2::PublicKey;
+    use chrono::{NaiveDateTime, Utc};
+    use entities::l2::{AssetSortBy, PublicKey};
 
     #[test]
-    fn encode_and_decode_timestamp_and_asset_pubkey_test() {
+    fn encode_and_decode_asset_cursor_test() {
         let input_date = NaiveDateTime::default();
         let input_pubkey = PublicKey::new_unique();
-        let encoded = encode_timestamp_and_asset_pubkey(input_date, input_pubkey);
-        let (time, pubkey) = decode_timestamp_and_asset_pubkey(&encoded).unwrap();
+        let encoded = encode_asset_cursor(&AssetSortBy::Updated, input_date, input_pubkey);
+        let (sort_by, time, pubkey) = decode_asset_cursor(&encoded).unwrap();
 
+        assert_eq!(sort_by, AssetSortBy::Updated);
         assert_eq!(time, input_date);
         assert_eq!(pubkey, input_pubkey);
     }
+
+    #[test]
+    fn asset_cursor_keeps_microseconds_test() {
+        let input_date = Utc::now().naive_utc();
+        let encoded = encode_asset_cursor(&AssetSortBy::Created, input_date, PublicKey::new_unique());
+        let (sort_by, time, _) = decode_asset_cursor(&encoded).unwrap();
+
+        assert_eq!(sort_by, AssetSortBy::Created);
+        assert_eq!(time.and_utc().timestamp_micros(), input_date.and_utc().timestamp_micros());
+    }
+
+    #[test]
+    fn decode_invalid_asset_cursor_test() {
+        let encoded = encode_asset_cursor(&AssetSortBy::Created, NaiveDateTime::default(), PublicKey::new_unique());
+
+        assert!(decode_asset_cursor(&encoded[..encoded.len() - 4]).is_err());
+        assert!(decode_asset_cursor("not a cursor").is_err());
+    }
 }
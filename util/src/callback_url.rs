@@ -0,0 +1,98 @@
+use std::net::IpAddr;
+use thiserror::Error;
+use url::{Host, Url};
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum CallbackUrlError {
+    #[error("callback is not a valid HTTP URL")]
+    Invalid,
+    #[error("callback host cannot be resolved")]
+    Unresolved,
+    #[error("callback host is not a public address")]
+    NotPublicHost,
+}
+
+/// Parses the callback URL of a webhook, only HTTP(S) URLs are accepted.
+/// Localhost and non-public IP hosts are refused, so the webhooks can't be used to reach the internal network.
+/// Domain hosts still have to be resolved and checked with [`is_public_ip`] before sending the webhook.
+pub fn parse_callback_url(callback: &str) -> Result<Url, CallbackUrlError> {
+    let url = Url::parse(callback).map_err(|_| CallbackUrlError::Invalid)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(CallbackUrlError::Invalid);
+    }
+
+    match url.host() {
+        None => Err(CallbackUrlError::Invalid),
+        Some(Host::Ipv4(ip)) if !is_public_ip(ip.into()) => Err(CallbackUrlError::NotPublicHost),
+        Some(Host::Ipv6(ip)) if !is_public_ip(ip.into()) => Err(CallbackUrlError::NotPublicHost),
+        Some(Host::Domain(domain))
+            if domain.eq_ignore_ascii_case("localhost") || domain.to_ascii_lowercase().ends_with(".localhost") =>
+        {
+            Err(CallbackUrlError::NotPublicHost)
+        }
+        Some(_) => Ok(url),
+    }
+}
+
+/// Whether the address is reachable from the public internet,
+/// i.e. it's not a loopback, private, link-local, shared or unspecified address.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            // 100.64.0.0/10 is shared by the carrier-grade NATs
+            let is_shared = first == 100 && (second & 0b1100_0000) == 64;
+
+            !(ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || is_shared)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(ip) = ip.to_ipv4_mapped() {
+                return is_public_ip(ip.into());
+            }
+            let first_segment = ip.segments()[0];
+            let is_unique_local = (first_segment & 0xfe00) == 0xfc00;
+            let is_link_local = (first_segment & 0xffc0) == 0xfe80;
+
+            !(ip.is_unspecified() || ip.is_loopback() || is_unique_local || is_link_local)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_callback_url() {
+        assert!(parse_callback_url("https://example.com/webhook").is_ok());
+        assert!(parse_callback_url("http://8.8.8.8:8080/webhook").is_ok());
+
+        assert_eq!(parse_callback_url("ftp://example.com").unwrap_err(), CallbackUrlError::Invalid);
+        assert_eq!(parse_callback_url("not a url").unwrap_err(), CallbackUrlError::Invalid);
+
+        for callback in [
+            "http://localhost:8080/webhook",
+            "http://api.LOCALHOST/webhook",
+            "http://127.0.0.1/webhook",
+            "http://0x7f.1/webhook",
+            "http://0.0.0.0/webhook",
+            "http://10.1.2.3/webhook",
+            "http://172.16.0.1/webhook",
+            "http://192.168.1.1/webhook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/webhook",
+            "http://[::1]/webhook",
+            "http://[::]/webhook",
+            "http://[fd00::1]/webhook",
+            "http://[fe80::1]/webhook",
+            "http://[::ffff:127.0.0.1]/webhook",
+        ] {
+            assert_eq!(parse_callback_url(callback).unwrap_err(), CallbackUrlError::NotPublicHost, "{callback}");
+        }
+    }
+}
@@ -2,13 +2,14 @@
 //!
 //! All the application configurations should be set in corresponding
 //! TOML file in `config` directory.
+use crate::rate_limiter::RateLimit;
 use crate::str_util::{mask_creds, mask_url_passwd};
 use aws_config::{BehaviorVersion, Region};
 use config::{Config, ConfigError, Environment, File};
-use entities::api_key::{ApiKey, ApiKeys, Username};
+use entities::api_key::{ApiKey, ApiKeys, Username, WebhookSecrets};
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 use std::{
     fmt,
     path::{Path, PathBuf},
@@ -17,6 +18,39 @@ use std::{
 const DEFAULT_CONFIG_FILE_PREFIX: &str = "config";
 const DEFAULT_CONFIG_FILE_NAME: &str = "default.toml";
 
+const DEFAULT_MAX_BINARY_ASSET_SIZE: usize = 10 * 1024 * 1024;
+const DEFAULT_MAX_JSON_METADATA_SIZE: usize = 64 * 1024;
+
+const DEFAULT_WEBHOOK_MAX_ATTEMPTS: u32 = 10;
+const DEFAULT_WEBHOOK_INITIAL_RETRY_DELAY_SECS: u64 = 5;
+const DEFAULT_WEBHOOK_MAX_RETRY_DELAY_SECS: u64 = 60 * 60;
+const DEFAULT_WEBHOOK_REQUEST_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_WEBHOOK_POLL_INTERVAL_SECS: u64 = 5;
+
+const DEFAULT_MINT_CONFIRMATION_WORKERS: usize = 2;
+const DEFAULT_MINT_CONFIRMATION_MAX_ATTEMPTS: u32 = 18;
+const DEFAULT_MINT_CONFIRMATION_INITIAL_RETRY_DELAY_SECS: u64 = 2;
+const DEFAULT_MINT_CONFIRMATION_MAX_RETRY_DELAY_SECS: u64 = 30;
+const DEFAULT_MINT_CONFIRMATION_POLL_INTERVAL_SECS: u64 = 1;
+
+const DEFAULT_L1_RECONCILIATION_ENABLED: bool = true;
+const DEFAULT_L1_RECONCILIATION_INTERVAL_SECS: u64 = 60 * 60;
+
+const DEFAULT_DAS_FALLBACK_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+const DEFAULT_RATE_LIMITS_ENABLED: bool = true;
+const DEFAULT_ANONYMOUS_RATE_LIMIT: RateLimit = RateLimit { requests_per_second: 10.0, burst: 20 };
+const DEFAULT_USER_RATE_LIMIT: RateLimit = RateLimit { requests_per_second: 20.0, burst: 40 };
+
+const DEFAULT_JSON_RPC_MAX_BATCH_SIZE: usize = 100;
+const DEFAULT_JSON_RPC_MAX_BATCH_CONCURRENCY: usize = 10;
+
+const DEFAULT_API_KEY_CACHE_TTL_SECS: u64 = 60;
+const DEFAULT_WALLET_SIGNATURE_MAX_TTL_SECS: u64 = 5 * 60;
+const DEFAULT_IDEMPOTENCY_KEY_TTL_SECS: u64 = 24 * 60 * 60;
+const DEFAULT_IDEMPOTENCY_RESERVATION_LEASE_SECS: u64 = 10 * 60;
+const DEFAULT_MAX_ASSET_BATCH_SIZE: usize = 100;
+
 const API_KEYS_SEPARATOR: char = ';';
 const API_KEY_TO_NAME_SEPARATOR: char = ':';
 
@@ -39,6 +73,26 @@ pub struct RestServerCfg {
     pub host: Ipv4Addr,
     pub log_level: String,
     pub base_url: String, // e.g. https://spell-backend:8080
+    /// How long the API keys stored in the DB are cached, i.e. the delay before a revoked key is rejected
+    #[serde(default = "default_api_key_cache_ttl_secs")]
+    pub api_key_cache_ttl_secs: u64,
+    /// Requires the asset mutations to be signed by the wallet of the asset authority (or owner for transfers),
+    /// otherwise the signature is only verified if provided
+    #[serde(default)]
+    pub require_wallet_signatures: bool,
+    /// How far ahead the expiry of a wallet signature can be
+    #[serde(default = "default_wallet_signature_max_ttl_secs")]
+    pub wallet_signature_max_ttl_secs: u64,
+    /// How long the responses to the requests with an `Idempotency-Key` are replayed to the retries
+    #[serde(default = "default_idempotency_key_ttl_secs")]
+    pub idempotency_key_ttl_secs: u64,
+    /// How long the request with an `Idempotency-Key` is considered in progress,
+    /// the key of a request that hasn't completed by then (e.g. due to a restart) can be reused
+    #[serde(default = "default_idempotency_reservation_lease_secs")]
+    pub idempotency_reservation_lease_secs: u64,
+    /// Max number of assets created by a single `POST /assets/batch` request
+    #[serde(default = "default_max_asset_batch_size")]
+    pub max_asset_batch_size: usize,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -46,6 +100,12 @@ pub struct JsonRpc {
     pub port: u16,
     pub host: Ipv4Addr,
     pub log_level: String,
+    /// Max number of calls in a single batch request
+    #[serde(default = "default_json_rpc_max_batch_size")]
+    pub max_batch_size: usize,
+    /// Max number of calls of a single batch request that are processed concurrently
+    #[serde(default = "default_json_rpc_max_batch_concurrency")]
+    pub max_batch_concurrency: usize,
 }
 
 #[derive(Deserialize, Clone)]
@@ -57,6 +117,12 @@ pub struct ObjStorageCfg {
     pub session_token: Option<String>,
     pub bucket_for_json_metadata: String,
     pub bucket_for_binary_assets: String,
+    /// Max size in bytes of a binary file (image, video, etc.) that can be uploaded for an asset
+    #[serde(default = "default_max_binary_asset_size")]
+    pub max_binary_asset_size: usize,
+    /// Max size in bytes of an NFT metadata JSON document
+    #[serde(default = "default_max_json_metadata_size")]
+    pub max_json_metadata_size: usize,
 }
 
 impl fmt::Debug for ObjStorageCfg {
@@ -68,6 +134,8 @@ impl fmt::Debug for ObjStorageCfg {
             .field("secret_access_key", &self.secret_access_key.as_ref().map(|s| mask_creds(s)))
             .field("session_token", &self.session_token.as_ref().map(|s| mask_creds(s)))
             .field("bucket_for_json_metadata", &self.bucket_for_json_metadata)
+            .field("max_binary_asset_size", &self.max_binary_asset_size)
+            .field("max_json_metadata_size", &self.max_json_metadata_size)
             .finish()
     }
 }
@@ -113,6 +181,145 @@ impl fmt::Debug for DatabaseCfg {
     }
 }
 
+/// Delivery settings of the webhooks sent to the clients' callback URLs
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WebhookCfg {
+    /// Max number of delivery attempts, after which the webhook is given up
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled with every next attempt
+    pub initial_retry_delay_secs: u64,
+    /// Upper bound of the delay between retries
+    pub max_retry_delay_secs: u64,
+    pub request_timeout_secs: u64,
+    /// How often the storage is checked for webhooks due for delivery
+    pub poll_interval_secs: u64,
+}
+
+impl Default for WebhookCfg {
+    fn default() -> Self {
+        WebhookCfg {
+            max_attempts: DEFAULT_WEBHOOK_MAX_ATTEMPTS,
+            initial_retry_delay_secs: DEFAULT_WEBHOOK_INITIAL_RETRY_DELAY_SECS,
+            max_retry_delay_secs: DEFAULT_WEBHOOK_MAX_RETRY_DELAY_SECS,
+            request_timeout_secs: DEFAULT_WEBHOOK_REQUEST_TIMEOUT_SECS,
+            poll_interval_secs: DEFAULT_WEBHOOK_POLL_INTERVAL_SECS,
+        }
+    }
+}
+
+/// Settings of the workers that await confirmation of L1 mint transactions
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MintConfirmationCfg {
+    /// Number of workers processing the mint confirmation jobs
+    pub workers: usize,
+    /// Number of confirmation checks after which the mint is rolled back,
+    /// used only if the blockhash of the mint transaction is unknown
+    pub max_attempts: u32,
+    /// Delay before the second check, doubled with every next check
+    pub initial_retry_delay_secs: u64,
+    /// Upper bound of the delay between checks
+    pub max_retry_delay_secs: u64,
+    /// How often the storage is checked for due jobs
+    pub poll_interval_secs: u64,
+}
+
+impl Default for MintConfirmationCfg {
+    fn default() -> Self {
+        MintConfirmationCfg {
+            workers: DEFAULT_MINT_CONFIRMATION_WORKERS,
+            max_attempts: DEFAULT_MINT_CONFIRMATION_MAX_ATTEMPTS,
+            initial_retry_delay_secs: DEFAULT_MINT_CONFIRMATION_INITIAL_RETRY_DELAY_SECS,
+            max_retry_delay_secs: DEFAULT_MINT_CONFIRMATION_MAX_RETRY_DELAY_SECS,
+            poll_interval_secs: DEFAULT_MINT_CONFIRMATION_POLL_INTERVAL_SECS,
+        }
+    }
+}
+
+/// Settings of the periodic reconciliation of assets with their L1 state
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct L1ReconciliationCfg {
+    pub enabled: bool,
+    /// Delay between the end of one reconciliation and the start of the next one
+    pub interval_secs: u64,
+}
+
+impl Default for L1ReconciliationCfg {
+    fn default() -> Self {
+        L1ReconciliationCfg {
+            enabled: DEFAULT_L1_RECONCILIATION_ENABLED,
+            interval_secs: DEFAULT_L1_RECONCILIATION_INTERVAL_SECS,
+        }
+    }
+}
+
+/// Settings of the upstream DAS API, that serves the assets minted on L1 from the JSON-RPC server
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DasFallbackCfg {
+    /// Minted assets are not served if the URL is not set
+    pub url: Option<String>,
+    pub request_timeout_secs: u64,
+}
+
+impl Default for DasFallbackCfg {
+    fn default() -> Self {
+        DasFallbackCfg { url: None, request_timeout_secs: DEFAULT_DAS_FALLBACK_REQUEST_TIMEOUT_SECS }
+    }
+}
+
+/// Throttling of the REST and JSON-RPC requests
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitsCfg {
+    pub enabled: bool,
+    /// Limit of the unauthenticated requests, per client IP
+    pub anonymous: RateLimit,
+    /// Limits of the users that are not listed in `users`
+    pub default_user: UserLimitsCfg,
+    /// Limits of the requests made with the API keys of the user, by the name of the user
+    pub users: HashMap<Username, UserLimitsCfg>,
+    /// Addresses of the reverse proxies, whose `X-Forwarded-For` entries identify the client.
+    /// Required if the rate limits are enabled, as the JSON-RPC server identifies the clients only by the header.
+    pub trusted_proxies: Vec<IpAddr>,
+}
+
+impl RateLimitsCfg {
+    pub fn user_limits(&self, username: &Username) -> &UserLimitsCfg {
+        self.users.get(username).unwrap_or(&self.default_user)
+    }
+}
+
+impl Default for RateLimitsCfg {
+    fn default() -> Self {
+        RateLimitsCfg {
+            enabled: DEFAULT_RATE_LIMITS_ENABLED,
+            anonymous: DEFAULT_ANONYMOUS_RATE_LIMIT,
+            default_user: UserLimitsCfg::default(),
+            users: HashMap::new(),
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct UserLimitsCfg {
+    pub rate: RateLimit,
+    /// Max number of assets created per UTC day, unlimited if not set
+    pub daily_asset_creations: Option<u32>,
+    /// Max number of mint transactions submitted per UTC day, unlimited if not set
+    pub daily_mints: Option<u32>,
+}
+
+impl Default for UserLimitsCfg {
+    fn default() -> Self {
+        UserLimitsCfg { rate: DEFAULT_USER_RATE_LIMIT, daily_asset_creations: None, daily_mints: None }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Hash, PartialEq, Eq)]
 pub enum SecretCfg {
     Plain(String),
@@ -123,7 +330,20 @@ pub enum SecretCfg {
 #[derive(Debug, Deserialize, Clone)]
 pub struct SecretsCfg {
     pub master_mnemonic: SecretCfg,
+    /// API keys allowed to manage the assets in `key:name;key:name` format,
+    /// the rest of the keys are issued via the admin endpoints and stored in the DB
     pub rest_api_keys: SecretCfg,
+    /// Admin API keys in `key:name;key:name` format, allowed to manage the assets and the API keys
+    #[serde(default)]
+    pub admin_api_keys: Option<SecretCfg>,
+    /// Secrets used to sign mint webhooks in `name:secret;name:secret` format,
+    /// where `name` is the name of the user of the API key from the config
+    #[serde(default)]
+    pub webhook_secrets: Option<SecretCfg>,
+    /// Key the webhook secrets of the API keys issued via the admin endpoints are derived from,
+    /// the issued keys cannot register callbacks if it isn't set
+    #[serde(default)]
+    pub webhook_secret_key: Option<SecretCfg>,
 }
 
 #[allow(unused)]
@@ -135,6 +355,16 @@ pub struct Settings {
     pub database: DatabaseCfg,
     pub solana: SolanaCfg,
     pub secrets: SecretsCfg,
+    #[serde(default)]
+    pub webhooks: WebhookCfg,
+    #[serde(default)]
+    pub mint_confirmation: MintConfirmationCfg,
+    #[serde(default)]
+    pub l1_reconciliation: L1ReconciliationCfg,
+    #[serde(default)]
+    pub das_fallback: DasFallbackCfg,
+    #[serde(default)]
+    pub rate_limits: RateLimitsCfg,
     pub env: String,
 }
 
@@ -187,6 +417,66 @@ impl Settings {
         let raw_string = resolve_value_source(&self.secrets.rest_api_keys);
         parse_api_key(&raw_string)
     }
+
+    pub fn admin_api_keys(&self) -> ApiKeys {
+        self.secrets
+            .admin_api_keys
+            .as_ref()
+            .map(|api_keys| parse_api_key(&resolve_value_source(api_keys)))
+            .unwrap_or_default()
+    }
+
+    pub fn webhook_secrets(&self) -> WebhookSecrets {
+        self.secrets
+            .webhook_secrets
+            .as_ref()
+            .map(|secrets| parse_webhook_secrets(&resolve_value_source(secrets)))
+            .unwrap_or_default()
+    }
+
+    pub fn webhook_secret_key(&self) -> Option<String> {
+        self.secrets
+            .webhook_secret_key
+            .as_ref()
+            .map(resolve_value_source)
+            .filter(|key| !key.is_empty())
+    }
+}
+
+fn default_max_binary_asset_size() -> usize {
+    DEFAULT_MAX_BINARY_ASSET_SIZE
+}
+
+fn default_max_json_metadata_size() -> usize {
+    DEFAULT_MAX_JSON_METADATA_SIZE
+}
+
+fn default_api_key_cache_ttl_secs() -> u64 {
+    DEFAULT_API_KEY_CACHE_TTL_SECS
+}
+
+fn default_wallet_signature_max_ttl_secs() -> u64 {
+    DEFAULT_WALLET_SIGNATURE_MAX_TTL_SECS
+}
+
+fn default_idempotency_key_ttl_secs() -> u64 {
+    DEFAULT_IDEMPOTENCY_KEY_TTL_SECS
+}
+
+fn default_idempotency_reservation_lease_secs() -> u64 {
+    DEFAULT_IDEMPOTENCY_RESERVATION_LEASE_SECS
+}
+
+fn default_max_asset_batch_size() -> usize {
+    DEFAULT_MAX_ASSET_BATCH_SIZE
+}
+
+fn default_json_rpc_max_batch_size() -> usize {
+    DEFAULT_JSON_RPC_MAX_BATCH_SIZE
+}
+
+fn default_json_rpc_max_batch_concurrency() -> usize {
+    DEFAULT_JSON_RPC_MAX_BATCH_CONCURRENCY
 }
 
 fn default_config_file_path(base_path: &str) -> PathBuf {
@@ -215,6 +505,7 @@ fn find_config_file(base_path: &str, name: &str) -> PathBuf {
 
 fn parse_api_key(raw: &str) -> ApiKeys {
     raw.split(API_KEYS_SEPARATOR)
+        .filter(|api_key_name| !api_key_name.is_empty())
         .map(|api_key_name| {
             api_key_name
                 .split_once(API_KEY_TO_NAME_SEPARATOR)
@@ -225,6 +516,19 @@ fn parse_api_key(raw: &str) -> ApiKeys {
         .into()
 }
 
+fn parse_webhook_secrets(raw: &str) -> WebhookSecrets {
+    raw.split(API_KEYS_SEPARATOR)
+        .filter(|name_secret| !name_secret.is_empty())
+        .map(|name_secret| {
+            name_secret
+                .split_once(API_KEY_TO_NAME_SEPARATOR)
+                .expect("No secret specified for webhook user.")
+        })
+        .map(|(name, secret)| (Username::new(name), secret.to_string()))
+        .collect::<HashMap<Username, String>>()
+        .into()
+}
+
 fn resolve_value_source(value_source: &SecretCfg) -> String {
     match value_source {
         SecretCfg::Plain(v) => v.to_owned(),
@@ -1,6 +1,11 @@
+pub mod api_key;
 pub mod base64_encode_decode;
+pub mod callback_url;
 pub mod config;
 pub mod hd_wallet;
 pub mod nft_json;
 pub mod publickey;
+pub mod rate_limiter;
 pub mod str_util;
+pub mod wallet_signature;
+pub mod webhook_signature;
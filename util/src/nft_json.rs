@@ -1,14 +1,308 @@
+use serde_json::{json, Map, Value};
 use thiserror::Error;
+use url::Url;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq)]
 pub enum JsonMetadataError {
-    #[error("Metadata contains no URIs")]
-    NoUri,
+    #[error("Metadata is not a valid JSON object")]
+    NotJsonObject,
+    #[error("Metadata size {size} bytes exceeds the limit of {max_size} bytes")]
+    TooLarge { size: usize, max_size: usize },
+    #[error("Metadata field '{0}' is missing or is not a non-empty string")]
+    MissingField(&'static str),
+    #[error("Metadata field '{0}' has invalid type")]
+    InvalidFieldType(&'static str),
+    #[error("properties.files[{index}].uri is not a valid URL: {uri}")]
+    InvalidFileUri { index: usize, uri: String },
+    #[error("attributes[{index}] must be an object with 'trait_type' and 'value'")]
+    InvalidAttribute { index: usize },
 }
 
-/// Validates JSON metadata document.
-/// Validate that uri is uri.
-pub fn validate_metadata_contains_uris(_json_metadata: &str) -> std::result::Result<(), JsonMetadataError> {
-    // TODO: not forget to implement
-    Ok(())
+/// All the violations of the NFT metadata standard found in a metadata document.
+#[derive(Debug, Error, PartialEq)]
+#[error("Invalid metadata JSON: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+pub struct InvalidMetadataError(pub Vec<JsonMetadataError>);
+
+/// Validates JSON metadata document against the Metaplex NFT metadata standard:
+/// * the document is a JSON object not larger than `max_size` bytes
+/// * `name` and `image` are present
+/// * `properties.files[*].uri` are valid URLs
+/// * `attributes` is an array of `trait_type`/`value` pairs
+///
+/// All the violations are collected, so the caller is able to report them at once.
+pub fn validate_metadata_json(json_metadata: &str, max_size: usize) -> std::result::Result<(), InvalidMetadataError> {
+    if json_metadata.len() > max_size {
+        return Err(InvalidMetadataError(vec![JsonMetadataError::TooLarge { size: json_metadata.len(), max_size }]));
+    }
+
+    let Ok(Value::Object(metadata)) = serde_json::from_str::<Value>(json_metadata) else {
+        return Err(InvalidMetadataError(vec![JsonMetadataError::NotJsonObject]));
+    };
+
+    let mut errors = Vec::new();
+
+    for field in ["name", "image"] {
+        if metadata
+            .get(field)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .is_empty()
+        {
+            errors.push(JsonMetadataError::MissingField(field));
+        }
+    }
+
+    match metadata.get("properties") {
+        None | Some(Value::Null) => {}
+        Some(Value::Object(properties)) => match properties.get("files") {
+            None | Some(Value::Null) => {}
+            Some(Value::Array(files)) => {
+                for (index, file) in files.iter().enumerate() {
+                    match file.get("uri").and_then(Value::as_str) {
+                        Some(uri) if Url::parse(uri).is_ok() => {}
+                        uri => errors.push(JsonMetadataError::InvalidFileUri {
+                            index,
+                            uri: uri.unwrap_or_default().to_string(),
+                        }),
+                    }
+                }
+            }
+            Some(_) => errors.push(JsonMetadataError::InvalidFieldType("properties.files")),
+        },
+        Some(_) => errors.push(JsonMetadataError::InvalidFieldType("properties")),
+    }
+
+    match metadata.get("attributes") {
+        None | Some(Value::Null) => {}
+        Some(Value::Array(attributes)) => {
+            for (index, attribute) in attributes.iter().enumerate() {
+                if !is_valid_attribute(attribute) {
+                    errors.push(JsonMetadataError::InvalidAttribute { index });
+                }
+            }
+        }
+        Some(_) => errors.push(JsonMetadataError::InvalidFieldType("attributes")),
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidMetadataError(errors))
+    }
+}
+
+/// Attribute is expected to look like `{"trait_type": "Background", "value": "Blue"}`,
+/// where value is a string, a number or a boolean.
+fn is_valid_attribute(attribute: &Value) -> bool {
+    let trait_type_valid = attribute.get("trait_type").is_some_and(Value::is_string);
+    let value_valid = attribute
+        .get("value")
+        .is_some_and(|v| v.is_string() || v.is_number() || v.is_boolean());
+    trait_type_valid && value_valid
+}
+
+/// Makes NFT metadata JSON point to the binary file (image, video, etc.) hosted by us:
+/// * `image` is set to the given URI for images, `animation_url` for the other media (video, audio, 3D models)
+/// * the file is put first into `properties.files` along with its MIME type,
+///   replacing the previous entry for the `image` or `animation_url` respectively
+///
+/// If there is no metadata yet, a minimal metadata document is created.
+///
+/// ## Args:
+/// * `metadata_json` - current NFT metadata JSON of the asset, if any
+/// * `name` - NFT asset name, used only if a new metadata document is created
+/// * `uri` - public URI of the binary file
+/// * `mime` - MIME type of the binary file
+pub fn attach_binary_file(
+    metadata_json: Option<&str>,
+    name: &str,
+    uri: &str,
+    mime: &str,
+) -> std::result::Result<String, JsonMetadataError> {
+    let mut metadata = match metadata_json {
+        Some(json) => match serde_json::from_str::<Value>(json) {
+            Ok(Value::Object(map)) => map,
+            _ => return Err(JsonMetadataError::NotJsonObject),
+        },
+        None => {
+            let mut map = Map::new();
+            map.insert("name".to_string(), Value::String(name.to_string()));
+            map
+        }
+    };
+
+    let category = category_for_mime(mime);
+    let field = if category == "image" { "image" } else { "animation_url" };
+    let previous_uri = metadata.get(field).and_then(Value::as_str).map(str::to_string);
+    metadata.insert(field.to_string(), Value::String(uri.to_string()));
+
+    let mut properties = match metadata.remove("properties") {
+        Some(Value::Object(properties)) => properties,
+        _ => Map::new(),
+    };
+
+    let mut files = match properties.remove("files") {
+        Some(Value::Array(files)) => files,
+        _ => Vec::new(),
+    };
+    files.retain(|file| {
+        let file_uri = file.get("uri").and_then(Value::as_str);
+        file_uri != Some(uri) && file_uri != previous_uri.as_deref()
+    });
+    files.insert(0, json!({ "uri": uri, "type": mime }));
+
+    properties.insert("files".to_string(), Value::Array(files));
+    properties
+        .entry("category")
+        .or_insert_with(|| Value::String(category.to_string()));
+    metadata.insert("properties".to_string(), Value::Object(properties));
+
+    Ok(Value::Object(metadata).to_string())
+}
+
+/// Maps MIME type to the `properties.category` value of the Metaplex NFT standard.
+fn category_for_mime(mime: &str) -> &'static str {
+    match mime.split('/').next() {
+        Some("video") => "video",
+        Some("audio") => "audio",
+        Some("model") => "vr",
+        _ => "image",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAX_SIZE: usize = 10 * 1024;
+
+    #[test]
+    fn test_validate_metadata_json() {
+        let valid = r#"
+            {
+                "name": "name1",
+                "image": "http://host/image.png",
+                "attributes": [
+                    { "trait_type": "Background", "value": "Blue" },
+                    { "trait_type": "Level", "value": 5 }
+                ],
+                "properties": {
+                    "files": [{ "uri": "http://host/image.png", "type": "image/png" }],
+                    "category": "image"
+                }
+            }
+        "#;
+        assert_eq!(validate_metadata_json(valid, MAX_SIZE), Ok(()));
+        assert_eq!(validate_metadata_json(r#"{"name": "n", "image": "i"}"#, MAX_SIZE), Ok(()));
+
+        assert_eq!(
+            validate_metadata_json("not a json", MAX_SIZE),
+            Err(InvalidMetadataError(vec![JsonMetadataError::NotJsonObject]))
+        );
+        assert_eq!(
+            validate_metadata_json("[]", MAX_SIZE),
+            Err(InvalidMetadataError(vec![JsonMetadataError::NotJsonObject]))
+        );
+        assert_eq!(
+            validate_metadata_json(valid, 16),
+            Err(InvalidMetadataError(vec![JsonMetadataError::TooLarge { size: valid.len(), max_size: 16 }]))
+        );
+
+        let invalid = r#"
+            {
+                "name": "",
+                "attributes": [
+                    { "trait_type": "Background", "value": "Blue" },
+                    { "trait_type": "Level" },
+                    "Rare"
+                ],
+                "properties": {
+                    "files": [
+                        { "uri": "http://host/image.png" },
+                        { "uri": "not an url" },
+                        { "type": "image/png" }
+                    ]
+                }
+            }
+        "#;
+        assert_eq!(
+            validate_metadata_json(invalid, MAX_SIZE),
+            Err(InvalidMetadataError(vec![
+                JsonMetadataError::MissingField("name"),
+                JsonMetadataError::MissingField("image"),
+                JsonMetadataError::InvalidFileUri { index: 1, uri: "not an url".to_string() },
+                JsonMetadataError::InvalidFileUri { index: 2, uri: "".to_string() },
+                JsonMetadataError::InvalidAttribute { index: 1 },
+                JsonMetadataError::InvalidAttribute { index: 2 },
+            ]))
+        );
+
+        assert_eq!(
+            validate_metadata_json(r#"{"name": "n", "image": "i", "attributes": {}, "properties": []}"#, MAX_SIZE),
+            Err(InvalidMetadataError(vec![
+                JsonMetadataError::InvalidFieldType("properties"),
+                JsonMetadataError::InvalidFieldType("attributes"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_attach_binary_file() {
+        let metadata = r#"
+            {
+                "name": "name1",
+                "image": "http://host/old.png",
+                "properties": {
+                    "files": [
+                        { "uri": "http://host/old.png", "type": "image/png" },
+                        { "uri": "http://host/video.mp4", "type": "video/mp4" }
+                    ],
+                    "category": "image"
+                }
+            }
+        "#;
+
+        let updated = attach_binary_file(Some(metadata), "name1", "http://spell/asset/1/binary", "image/jpeg").unwrap();
+        let updated: Value = serde_json::from_str(&updated).unwrap();
+
+        assert_eq!(updated["name"], "name1");
+        assert_eq!(updated["image"], "http://spell/asset/1/binary");
+        assert_eq!(
+            updated["properties"]["files"],
+            json!([
+                { "uri": "http://spell/asset/1/binary", "type": "image/jpeg" },
+                { "uri": "http://host/video.mp4", "type": "video/mp4" }
+            ])
+        );
+        assert_eq!(updated["properties"]["category"], "image");
+
+        // video keeps the image as a preview
+        let updated = attach_binary_file(Some(metadata), "name1", "http://spell/asset/1/binary", "video/webm").unwrap();
+        let updated: Value = serde_json::from_str(&updated).unwrap();
+
+        assert_eq!(updated["image"], "http://host/old.png");
+        assert_eq!(updated["animation_url"], "http://spell/asset/1/binary");
+        assert_eq!(
+            updated["properties"]["files"],
+            json!([
+                { "uri": "http://spell/asset/1/binary", "type": "video/webm" },
+                { "uri": "http://host/old.png", "type": "image/png" },
+                { "uri": "http://host/video.mp4", "type": "video/mp4" }
+            ])
+        );
+
+        let created = attach_binary_file(None, "name2", "http://spell/asset/2/binary", "audio/mpeg").unwrap();
+        let created: Value = serde_json::from_str(&created).unwrap();
+
+        assert_eq!(created["name"], "name2");
+        assert_eq!(created["animation_url"], "http://spell/asset/2/binary");
+        assert!(created.get("image").is_none());
+        assert_eq!(
+            created["properties"]["files"],
+            json!([{ "uri": "http://spell/asset/2/binary", "type": "audio/mpeg" }])
+        );
+        assert_eq!(created["properties"]["category"], "audio");
+
+        assert!(attach_binary_file(Some("[1, 2]"), "name3", "http://spell/asset/3/binary", "image/png").is_err());
+    }
 }
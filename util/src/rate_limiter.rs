@@ -0,0 +1,184 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Interval of dropping the full buckets, so one-off clients don't bloat the memory
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Token bucket limit: up to `burst` requests at once, refilled at `requests_per_second`
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub requests_per_second: f64,
+    pub burst: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    /// Limit the bucket was last acquired with, the keys may have different limits
+    limit: RateLimit,
+}
+
+impl Bucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.limit.requests_per_second).min(self.limit.burst as f64);
+        self.updated_at = now;
+    }
+
+    fn is_full(&self) -> bool {
+        self.tokens >= self.limit.burst as f64
+    }
+}
+
+#[derive(Debug, Default)]
+struct Buckets {
+    by_key: HashMap<String, Bucket>,
+    cleaned_up_at: Option<Instant>,
+}
+
+/// In-memory token bucket rate limiter, with a separate bucket per key (e.g. user or client IP)
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    /// Takes a token from the bucket of the key.
+    /// Returns the delay until a token is available if the bucket is empty.
+    pub fn try_acquire(&self, key: &str, limit: RateLimit) -> Result<(), Duration> {
+        self.try_acquire_at(key, limit, Instant::now())
+    }
+
+    fn try_acquire_at(&self, key: &str, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().expect("Rate limiter lock is poisoned");
+
+        let cleanup_due = match buckets.cleaned_up_at {
+            Some(cleaned_up_at) => now.saturating_duration_since(cleaned_up_at) >= CLEANUP_INTERVAL,
+            None => true,
+        };
+        if cleanup_due {
+            // A full bucket is the same as a missing one
+            buckets.by_key.retain(|_, bucket| {
+                bucket.refill(now);
+                !bucket.is_full()
+            });
+            buckets.cleaned_up_at = Some(now);
+        }
+
+        let bucket = buckets.by_key.entry(key.to_string()).or_insert(Bucket {
+            tokens: limit.burst as f64,
+            updated_at: now,
+            limit,
+        });
+        bucket.limit = limit;
+        bucket.refill(now);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else if limit.requests_per_second > 0.0 {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / limit.requests_per_second))
+        } else {
+            Err(Duration::MAX)
+        }
+    }
+}
+
+/// Value of the `Retry-After` header, it's in whole seconds,
+/// so the delay is rounded up for the retry not to be throttled again
+pub fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after
+        .as_secs()
+        .saturating_add(u64::from(retry_after.subsec_nanos() > 0))
+}
+
+/// Identifies the client of the request to throttle it by the IP.
+/// `X-Forwarded-For` is only taken into account if the peer is a trusted proxy,
+/// or the peer address is unknown, as the server is deployed behind a proxy then.
+/// The right-most entry that is not a trusted proxy is taken, the entries to the left of it may be forged by the client.
+pub fn client_ip(peer: Option<IpAddr>, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    if let Some(peer) = peer.filter(|peer| !trusted_proxies.contains(peer)) {
+        return Some(peer);
+    }
+
+    forwarded_for
+        .and_then(|forwarded_for| {
+            forwarded_for
+                .rsplit(',')
+                .map(|entry| entry.trim().parse::<IpAddr>().ok())
+                .find(|ip| !matches!(ip, Some(ip) if trusted_proxies.contains(ip)))
+                .flatten()
+        })
+        .or(peer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::default();
+        let limit = RateLimit { requests_per_second: 2.0, burst: 3 };
+        let now = Instant::now();
+
+        // burst is available at once
+        for _ in 0..3 {
+            assert!(limiter.try_acquire_at("key1", limit, now).is_ok());
+        }
+        assert_eq!(limiter.try_acquire_at("key1", limit, now), Err(Duration::from_millis(500)));
+
+        // keys have separate buckets
+        assert!(limiter.try_acquire_at("key2", limit, now).is_ok());
+
+        // tokens are refilled over time
+        let now = now + Duration::from_millis(500);
+        assert!(limiter.try_acquire_at("key1", limit, now).is_ok());
+        assert!(limiter.try_acquire_at("key1", limit, now).is_err());
+    }
+
+    #[test]
+    fn test_full_buckets_cleanup() {
+        let limiter = RateLimiter::default();
+        let slow_limit = RateLimit { requests_per_second: 0.01, burst: 1 };
+        let fast_limit = RateLimit { requests_per_second: 100.0, burst: 1 };
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at("slow", slow_limit, now).is_ok());
+        assert!(limiter.try_acquire_at("fast", fast_limit, now).is_ok());
+
+        // buckets are refilled with their own limits, only the full one is dropped
+        let now = now + CLEANUP_INTERVAL;
+        assert!(limiter.try_acquire_at("other", fast_limit, now).is_ok());
+        let buckets = limiter.buckets.lock().unwrap();
+        assert!(buckets.by_key.contains_key("slow"));
+        assert!(!buckets.by_key.contains_key("fast"));
+    }
+
+    #[test]
+    fn test_retry_after_secs() {
+        assert_eq!(retry_after_secs(Duration::from_secs(2)), 2);
+        assert_eq!(retry_after_secs(Duration::from_millis(1500)), 2);
+        assert_eq!(retry_after_secs(Duration::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let proxy: IpAddr = "10.0.0.1".parse().unwrap();
+        let client: IpAddr = "1.1.1.1".parse().unwrap();
+        let forged = "2.2.2.2, 1.1.1.1";
+
+        // the headers of the untrusted peers are ignored
+        assert_eq!(client_ip(Some(client), Some("3.3.3.3"), &[proxy]), Some(client));
+        // the right-most entry added by the trusted proxy is taken
+        assert_eq!(client_ip(Some(proxy), Some(forged), &[proxy]), Some(client));
+        assert_eq!(client_ip(None, Some("2.2.2.2, 1.1.1.1, 10.0.0.1"), &[proxy]), Some(client));
+        assert_eq!(client_ip(None, Some(forged), &[]), Some(client));
+        assert_eq!(client_ip(Some(proxy), Some("garbage"), &[proxy]), Some(proxy));
+        assert_eq!(client_ip(None, None, &[proxy]), None);
+    }
+}
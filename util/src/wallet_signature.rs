@@ -0,0 +1,36 @@
+use sha2::{Digest, Sha256};
+
+/// Hex encoded SHA-256 of `{METHOD} {path}\n{body}`, e.g. `PUT /asset/{pubkey}\n{"name":"name2"}`.
+/// The body is taken as is, so the client signs exactly the bytes it sends.
+pub fn request_digest(method: &str, path: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.to_uppercase().as_bytes());
+    hasher.update(b" ");
+    hasher.update(path.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// Message the wallet signs to authorize an L2 mutation: `{request_digest}.{nonce}.{expiry}`,
+/// where the expiry is a unix timestamp in seconds.
+/// The nonce and the expiry are signed as well, so the server is able to reject replayed requests.
+pub fn wallet_signature_message(request_digest: &str, nonce: &str, expiry: i64) -> String {
+    format!("{request_digest}.{nonce}.{expiry}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wallet_signature_message() {
+        let digest = request_digest("put", "/asset/abc", br#"{"name":"name2"}"#);
+
+        assert_eq!(digest, "765572612b42b3631e8a0211d1647aed84866e87d1eb3e1385b81dbd87911bcd");
+        assert_eq!(digest, request_digest("PUT", "/asset/abc", br#"{"name":"name2"}"#));
+        assert_ne!(digest, request_digest("PUT", "/asset/abc", br#"{"name":"name3"}"#));
+        assert_ne!(digest, request_digest("DELETE", "/asset/abc", br#"{"name":"name2"}"#));
+        assert_eq!(wallet_signature_message(&digest, "nonce1", 1_700_000_000), format!("{digest}.nonce1.1700000000"));
+    }
+}
@@ -0,0 +1,57 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs webhook payload with the webhook secret of the API key user.
+/// The signature is hex encoded HMAC-SHA256 of `{timestamp}.{payload}`,
+/// the timestamp is signed as well, so the receiver is able to reject replayed webhooks.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, payload: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Derives the webhook secret of an API key issued via the admin endpoints from its hash,
+/// so the secret isn't stored and the stored hash alone isn't enough to sign webhooks.
+/// The secret is hex encoded HMAC-SHA256 of the key hash with the server-side `secret_key`.
+pub fn derive_webhook_secret(secret_key: &str, api_key_hash: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret_key.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(api_key_hash.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook_payload() {
+        let payload = r#"{"asset_pubkey":"abc"}"#;
+
+        assert_eq!(
+            sign_webhook_payload("secret1", 1_700_000_000, payload),
+            "98535a5647afe20c982ae0b2142d1d0a77de32ef6be1dac0bfeb4da702270afd"
+        );
+        assert_ne!(
+            sign_webhook_payload("secret1", 1_700_000_001, payload),
+            sign_webhook_payload("secret1", 1_700_000_000, payload)
+        );
+        assert_ne!(
+            sign_webhook_payload("secret2", 1_700_000_000, payload),
+            sign_webhook_payload("secret1", 1_700_000_000, payload)
+        );
+    }
+
+    #[test]
+    fn test_derive_webhook_secret() {
+        let secret = derive_webhook_secret("key1", "hash1");
+
+        assert_eq!(secret.len(), 64);
+        assert_eq!(secret, derive_webhook_secret("key1", "hash1"));
+        assert_ne!(secret, derive_webhook_secret("key1", "hash2"));
+        assert_ne!(secret, derive_webhook_secret("key2", "hash1"));
+    }
+}